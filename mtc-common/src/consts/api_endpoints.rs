@@ -26,4 +26,5 @@ pub static API_COURSE: &str = "course";
 pub static API_ADMINISTRATOR: &str = "administrator";
 pub static API_EDITOR: &str = "editor";
 pub static API_SIGN_IN: &str = "sign-in";
-pub static API_GATE_PASSES: &str = "gate-passes";
\ No newline at end of file
+pub static API_GATE_PASSES: &str = "gate-passes";
+pub static API_SECURITY_AUDIT: &str = "security-audit";
\ No newline at end of file
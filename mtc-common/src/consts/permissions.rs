@@ -28,6 +28,9 @@ pub static PERMISSION_USERS_READ: &str = "users::read";
 pub static PERMISSION_USERS_WRITE: &str = "users::write";
 pub static PERMISSION_USERS_DELETE: &str = "users::delete";
 
+pub static PERMISSION_SECURITY_AUDIT: &str = "security_audit";
+pub static PERMISSION_SECURITY_AUDIT_READ: &str = "security_audit::read";
+
 pub static PERMISSION_SCHEMAS: &str = "schemas";
 pub static PERMISSION_SCHEMAS_READ: &str = "schemas::read";
 pub static PERMISSION_SCHEMAS_WRITE: &str = "schemas::write";
@@ -44,3 +47,7 @@ pub static PERMISSION_GATE_PASS_DELETE: &str = "gate_passes::delete";
 pub static PERMISSION_GATE_PASS_SYNC: &str = "gate_passes::sync";
 pub static PERMISSION_GATE_PASS_FULL_SYNC: &str = "gate_passes::full_sync";
 pub static PERMISSION_GATE_PASS_VALIDATE: &str = "gate_passes::validate";
+pub static PERMISSION_GATE_PASS_POLICY: &str = "gate_passes::policy";
+pub static PERMISSION_GATE_PASS_SNAPSHOT: &str = "gate_passes::snapshot";
+pub static PERMISSION_GATE_PASS_DEVICES: &str = "gate_passes::devices";
+pub static PERMISSION_GATE_PASS_DICTIONARIES: &str = "gate_passes::dictionaries";
@@ -1,3 +1,8 @@
 pub static SESSION_AUTH_KEY: &str = "credentials";
 pub static SESSION_ACCESS_KEY: &str = "access";
+/// Header with the persistent id of the front-end device, set by the service worker.
+pub static DEVICE_ID_HEADER: &str = "session";
 pub static SESSION_NULL_ID: &str = "00000000-0000-0000-0000-000000000000";
+pub static SESSION_TWO_FACTOR_KEY: &str = "two_factor";
+pub static SESSION_TWO_FACTOR_SECRET_KEY: &str = "two_factor_secret";
+pub static SESSION_PASSWORD_CHANGE_KEY: &str = "password_change";
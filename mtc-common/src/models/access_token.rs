@@ -0,0 +1,37 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Personal access token of the user, sent by the scripts and integrations as
+/// `Authorization: Bearer <token>`.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AccessToken {
+    pub id: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    /// Permissions granted to the token, the ones the user has lost are not granted.
+    pub permissions: Vec<Cow<'static, str>>,
+    pub created_at: Cow<'static, str>,
+    pub expires_at: Cow<'static, str>,
+    pub last_used_at: Option<Cow<'static, str>>,
+    pub revoked: bool,
+    pub revoked_at: Option<Cow<'static, str>>,
+    /// Set when the token is neither revoked nor expired.
+    pub active: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct AccessTokenRequest {
+    #[validate(length(min = 1, max = 64, message = "error-access-token-name-invalid"))]
+    pub name: Cow<'static, str>,
+    #[validate(length(min = 1, message = "error-access-token-permissions-empty"))]
+    pub permissions: Vec<Cow<'static, str>>,
+    #[validate(range(min = 1, max = 3650, message = "error-access-token-expiry-invalid"))]
+    pub expires_in_days: i64,
+}
+
+/// Created token along with its secret value, the value is shown only once.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AccessTokenCreated {
+    pub access_token: AccessToken,
+    pub token: Cow<'static, str>,
+}
@@ -1,10 +1,21 @@
 use super::*;
-use crate::prelude::not_blank;
+use crate::prelude::{
+    NUMBER_PLATE_LETTERS, canonical_email, canonical_number_plate, canonical_phone,
+    canonical_vin_code, not_blank, valid_number_plate, valid_phone, valid_vin_code,
+};
 use chrono::{DateTime, Utc};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+pub static GATE_PASS_QR_CODE_PREFIX: &str = "MTC:GATE-PASS:";
+/// Response header with the id of the print to confirm once the gate passes are printed.
+pub static GATE_PASS_PRINT_ID_HEADER: &str = "x-gate-pass-print-id";
+/// Drift between the scanner and the server clocks the guard is warned about.
+pub static GATE_PASS_CLOCK_SKEW_THRESHOLD_SECONDS: i64 = 300;
+/// Age of the last sync after which the offline gate passes are considered outdated.
+pub static GATE_PASS_SYNC_MAX_AGE_HOURS: i64 = 24;
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct GatePass {
@@ -16,6 +27,12 @@ pub struct GatePass {
     pub allow_any_vehicle: bool,
     pub vehicles: Vec<GatePassVehicle>,
     pub block: Option<GatePassBlock>,
+    /// Lifted and expired blocks, the oldest first.
+    #[serde(default)]
+    pub block_history: Vec<GatePassBlockRecord>,
+    pub qr_token: Option<Cow<'static, str>>,
+    pub print_count: i64,
+    pub last_printed_at: Option<Cow<'static, str>>,
     pub created_at: Cow<'static, str>,
     pub updated_at: Cow<'static, str>,
     pub created_by: Cow<'static, str>,
@@ -47,6 +64,49 @@ impl GatePass {
     pub fn first_vehicle(&self) -> Option<&GatePassVehicle> {
         self.vehicles.first()
     }
+
+    pub fn qr_code(&self) -> GatePassQrCode {
+        GatePassQrCode {
+            id: self.id.clone(),
+            qr_token: self.qr_token.clone(),
+        }
+    }
+}
+
+/// Content of the QR code printed on a gate pass card.
+///
+/// Passes that were never reissued have no QR token, so cards printed before the
+/// first reissue keep the `MTC:GATE-PASS:{id}` format.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassQrCode {
+    pub id: Cow<'static, str>,
+    pub qr_token: Option<Cow<'static, str>>,
+}
+
+impl GatePassQrCode {
+    pub fn parse(text: &str) -> Option<Self> {
+        let content = text.strip_prefix(GATE_PASS_QR_CODE_PREFIX)?;
+        let (id, qr_token) = match content.split_once(':') {
+            Some((id, qr_token)) => (id, Some(Cow::Owned(qr_token.to_string()))),
+            None => (content, None),
+        };
+        if id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            id: Cow::Owned(id.to_string()),
+            qr_token,
+        })
+    }
+}
+
+impl Display for GatePassQrCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.qr_token {
+            Some(qr_token) => write!(f, "{GATE_PASS_QR_CODE_PREFIX}{}:{qr_token}", self.id),
+            None => write!(f, "{GATE_PASS_QR_CODE_PREFIX}{}", self.id),
+        }
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
@@ -70,12 +130,28 @@ impl CreateGatePassRequest {
         self.owner.first_name = normalize(&self.owner.first_name);
         self.owner.middle_name = normalize(&self.owner.middle_name);
         self.owner.unit = uppercase(&self.owner.unit);
+        self.owner.phone = self
+            .owner
+            .phone
+            .as_ref()
+            .map(|phone| canonical_phone(phone))
+            .filter(|phone| !phone.is_empty())
+            .map(Cow::Owned);
+        self.owner.email = self
+            .owner
+            .email
+            .as_ref()
+            .map(|email| canonical_email(email))
+            .filter(|email| !email.is_empty())
+            .map(Cow::Owned);
         self.vehicles.iter_mut().for_each(|vehicle| {
             vehicle.number_plate = normalize_number_plate(&vehicle.number_plate);
             vehicle.vin_code = vehicle
                 .vin_code
                 .as_ref()
-                .map(|vin_code| uppercase(&vin_code));
+                .map(|vin_code| canonical_vin_code(vin_code))
+                .filter(|vin_code| !vin_code.is_empty())
+                .map(Cow::Owned);
             vehicle.manufacturer = normalize(&vehicle.manufacturer);
             vehicle.model = vehicle.model.as_ref().map(|model| normalize(&model));
         });
@@ -107,61 +183,36 @@ pub struct GatePassOwner {
     pub title: GatePassOwnerTitle,
     #[validate(custom(function = "not_blank"))]
     pub unit: Cow<'static, str>,
+    #[serde(default)]
+    #[validate(custom(function = "valid_phone"))]
+    pub phone: Option<Cow<'static, str>>,
+    #[serde(default)]
+    #[validate(email(message = "error-gate-pass-owner-email-invalid"))]
+    pub email: Option<Cow<'static, str>>,
+}
+
+impl GatePassOwner {
+    pub fn has_contact(&self) -> bool {
+        [&self.phone, &self.email]
+            .into_iter()
+            .any(|contact| contact.as_ref().is_some_and(|contact| !contact.is_empty()))
+    }
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub enum GatePassOwnerTitle {
-    #[default]
-    Soldier,
-    SeniorSoldier,
-    JuniorSergeant,
-    Sergeant,
-    SeniorSergeant,
-    ChiefSergeant,
-    StaffSergeant,
-    MasterSergeant,
-    SeniorMasterSergeant,
-    ChiefMasterSergeant,
-    JuniorLieutenant,
-    Lieutenant,
-    SeniorLieutenant,
-    Captain,
-    Major,
-    LieutenantColonel,
-    Colonel,
-    BrigadierGeneral,
-    MajorGeneral,
-    LieutenantGeneral,
-    General,
-    GeneralOfTheArmyOfUkraine,
+/// Owner rank, holds the code of an owner title dictionary entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct GatePassOwnerTitle(pub Cow<'static, str>);
+
+impl Default for GatePassOwnerTitle {
+    fn default() -> Self {
+        Self(Cow::Borrowed("Soldier"))
+    }
 }
 
 impl GatePassOwnerTitle {
-    pub fn values() -> Vec<GatePassOwnerTitle> {
-        vec![
-            GatePassOwnerTitle::Soldier,
-            GatePassOwnerTitle::SeniorSoldier,
-            GatePassOwnerTitle::JuniorSergeant,
-            GatePassOwnerTitle::Sergeant,
-            GatePassOwnerTitle::SeniorSergeant,
-            GatePassOwnerTitle::ChiefSergeant,
-            GatePassOwnerTitle::StaffSergeant,
-            GatePassOwnerTitle::MasterSergeant,
-            GatePassOwnerTitle::SeniorMasterSergeant,
-            GatePassOwnerTitle::ChiefMasterSergeant,
-            GatePassOwnerTitle::JuniorLieutenant,
-            GatePassOwnerTitle::Lieutenant,
-            GatePassOwnerTitle::SeniorLieutenant,
-            GatePassOwnerTitle::Captain,
-            GatePassOwnerTitle::Major,
-            GatePassOwnerTitle::LieutenantColonel,
-            GatePassOwnerTitle::Colonel,
-            GatePassOwnerTitle::BrigadierGeneral,
-            GatePassOwnerTitle::MajorGeneral,
-            GatePassOwnerTitle::LieutenantGeneral,
-            GatePassOwnerTitle::General,
-            GatePassOwnerTitle::GeneralOfTheArmyOfUkraine,
-        ]
+    pub fn code(&self) -> &str {
+        &self.0
     }
 }
 
@@ -174,8 +225,9 @@ pub struct UpdateGatePassOwnerRequest {
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
 pub struct GatePassVehicle {
-    #[validate(custom(function = "not_blank"))]
+    #[validate(custom(function = "valid_number_plate"))]
     pub number_plate: Cow<'static, str>,
+    #[validate(custom(function = "valid_vin_code"))]
     pub vin_code: Option<Cow<'static, str>>,
     #[validate(custom(function = "not_blank"))]
     pub manufacturer: Cow<'static, str>,
@@ -186,11 +238,15 @@ pub struct GatePassVehicle {
 
 impl GatePassVehicle {
     pub fn number_plate_type(&self) -> GatePassVehicleNumberPlateType {
-        let number_plate = self.number_plate.replace(" ", "");
+        let number_plate = canonical_number_plate(&self.number_plate);
         // 1234 А5
-        let military_format = Regex::new(r"^\d{4}\D\d$").unwrap();
+        let military_format =
+            Regex::new(&format!(r"^\d{{4}}[{NUMBER_PLATE_LETTERS}]\d$")).unwrap();
         // АН 1234 АС
-        let civil_format = Regex::new(r"^\D{2}\d{4}\D{2}$").unwrap();
+        let civil_format = Regex::new(&format!(
+            r"^[{NUMBER_PLATE_LETTERS}]{{2}}\d{{4}}[{NUMBER_PLATE_LETTERS}]{{2}}$"
+        ))
+        .unwrap();
         if military_format.is_match(&number_plate) {
             let numbers = number_plate.chars().take(4).collect();
             let letters = number_plate.chars().skip(4).take(2).collect();
@@ -217,100 +273,121 @@ pub enum GatePassVehicleNumberPlateType {
     CIVIL(Cow<'static, str>, Cow<'static, str>, Cow<'static, str>),
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
 pub struct UpdateGatePassBlockRequest {
     pub id: Option<Cow<'static, str>>,
+    #[validate(nested)]
     pub block: Option<GatePassBlock>,
     pub updated_by: Option<Cow<'static, str>>,
 }
 
+impl UpdateGatePassBlockRequest {
+    pub fn normalize(&mut self) {
+        if let Some(block) = self.block.as_mut() {
+            block.normalize();
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+#[validate(schema(function = "validate_gate_pass_block"))]
 pub struct GatePassBlock {
     #[validate(custom(function = "not_blank"))]
     pub expired_at: Cow<'static, str>,
-    #[validate(custom(function = "not_blank"))]
+    /// Code of a block reason dictionary entry.
+    #[serde(default)]
+    pub reason_code: Option<Cow<'static, str>>,
+    /// Free text, required when no reason code is given.
+    #[serde(default)]
     pub reason: Cow<'static, str>,
+    #[serde(default)]
+    pub blocked_at: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub blocked_by: Option<Cow<'static, str>>,
 }
 
 impl GatePassBlock {
     pub fn expired(&self) -> bool {
         expired(&self.expired_at)
     }
+
+    pub fn expired_by(&self, now: DateTime<Utc>) -> bool {
+        expired_by(&self.expired_at, now)
+    }
+
+    pub fn normalize(&mut self) {
+        self.reason_code = self
+            .reason_code
+            .take()
+            .map(|reason_code| reason_code.trim().to_string())
+            .filter(|reason_code| !reason_code.is_empty())
+            .map(Cow::Owned);
+        self.reason = Cow::Owned(self.reason.trim().to_string());
+    }
+}
+
+fn validate_gate_pass_block(block: &GatePassBlock) -> Result<(), ValidationError> {
+    if block.reason_code.is_none() && block.reason.trim().is_empty() {
+        return Err(ValidationError::new("error-gate-pass-block-reason-missing"));
+    }
+    Ok(())
 }
 
+/// Block moved out of the gate pass once lifted or expired.
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub enum VehicleBodyType {
-    #[default]
-    Sedan,
-    Hatchback,
-    Wagon,
-    Pickup,
-    SUV,
-    Coupe,
-    Truck,
-    Bus,
-    Minibus,
-    Motorbike,
-    Scooter,
+pub struct GatePassBlockRecord {
+    pub expired_at: Cow<'static, str>,
+    pub reason_code: Option<Cow<'static, str>>,
+    pub reason: Cow<'static, str>,
+    pub blocked_at: Option<Cow<'static, str>>,
+    pub blocked_by: Option<Cow<'static, str>>,
+    pub unblocked_at: Cow<'static, str>,
+    /// Empty when the block was archived after its expiry.
+    pub unblocked_by: Option<Cow<'static, str>>,
+}
+
+/// Currently blocked gate pass, listed in the blocked gate passes report.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BlockedGatePass {
+    pub id: Cow<'static, str>,
+    pub number: Cow<'static, str>,
+    pub expired_at: Cow<'static, str>,
+    pub owner: GatePassOwner,
+    pub vehicles: Vec<GatePassVehicle>,
+    pub block: GatePassBlock,
+}
+
+/// Vehicle body type, holds the code of a body type dictionary entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct VehicleBodyType(pub Cow<'static, str>);
+
+impl Default for VehicleBodyType {
+    fn default() -> Self {
+        Self(Cow::Borrowed("Sedan"))
+    }
 }
 
 impl VehicleBodyType {
-    pub fn values() -> Vec<VehicleBodyType> {
-        vec![
-            VehicleBodyType::Sedan,
-            VehicleBodyType::Hatchback,
-            VehicleBodyType::Wagon,
-            VehicleBodyType::Pickup,
-            VehicleBodyType::SUV,
-            VehicleBodyType::Coupe,
-            VehicleBodyType::Truck,
-            VehicleBodyType::Bus,
-            VehicleBodyType::Minibus,
-            VehicleBodyType::Motorbike,
-            VehicleBodyType::Scooter,
-        ]
+    pub fn code(&self) -> &str {
+        &self.0
     }
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub enum VehicleColor {
-    #[default]
-    White,
-    Beige,
-    Grey,
-    DarkGrey,
-    Blue,
-    DarkBlue,
-    Purple,
-    Red,
-    DarkRed,
-    Green,
-    DarkGreen,
-    Yellow,
-    Orange,
-    Brown,
-    Black,
+/// Vehicle color, holds the code of a color dictionary entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct VehicleColor(pub Cow<'static, str>);
+
+impl Default for VehicleColor {
+    fn default() -> Self {
+        Self(Cow::Borrowed("White"))
+    }
 }
 
 impl VehicleColor {
-    pub fn values() -> Vec<VehicleColor> {
-        vec![
-            VehicleColor::White,
-            VehicleColor::Beige,
-            VehicleColor::Grey,
-            VehicleColor::DarkGrey,
-            VehicleColor::Blue,
-            VehicleColor::DarkBlue,
-            VehicleColor::Purple,
-            VehicleColor::Red,
-            VehicleColor::DarkRed,
-            VehicleColor::Green,
-            VehicleColor::DarkGreen,
-            VehicleColor::Yellow,
-            VehicleColor::Orange,
-            VehicleColor::Brown,
-            VehicleColor::Black,
-        ]
+    pub fn code(&self) -> &str {
+        &self.0
     }
 }
 
@@ -319,6 +396,8 @@ pub struct SearchGatePassRequest {
     pub ids: Option<Vec<Cow<'static, str>>>,
     pub last_names: Option<Vec<Cow<'static, str>>>,
     pub number_plates: Option<Vec<Cow<'static, str>>>,
+    pub units: Option<Vec<Cow<'static, str>>>,
+    pub never_printed: Option<bool>,
     pub page_request: Option<PageRequest>,
 }
 
@@ -332,10 +411,19 @@ impl SearchGatePassRequest {
             ids,
             last_names,
             number_plates,
+            units: None,
+            never_printed: None,
             page_request: Some(PageRequest::all()),
         }
     }
 
+    pub fn from_units(units: Vec<Cow<'static, str>>) -> Self {
+        Self {
+            units: Some(units),
+            ..Default::default()
+        }
+    }
+
     pub fn from_number_plates(number_plates: Vec<Cow<'static, str>>) -> Self {
         Self {
             number_plates: Some(number_plates),
@@ -367,6 +455,20 @@ impl SearchGatePassRequest {
         self.last_names = normalized_last_names;
 
         self.number_plates = self.number_plates.as_ref().map(normalize_number_plates);
+
+        self.units = self.units.as_ref().map(|units| {
+            units
+                .iter()
+                .map(|unit| uppercase(unit))
+                .collect::<Vec<_>>()
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        [&self.ids, &self.last_names, &self.number_plates, &self.units]
+            .into_iter()
+            .all(|values| values.as_ref().is_none_or(|values| values.is_empty()))
+            && !self.never_printed.unwrap_or_default()
     }
 }
 
@@ -378,9 +480,33 @@ pub struct SyncGatePassRequest {
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SyncGatePassResponse {
     pub last_synced_at: Cow<'static, str>,
+    /// Server time the response was built at, scanners use it to correct their clock.
+    pub server_time: Cow<'static, str>,
     pub gate_passes: Vec<SyncGatePass>,
 }
 
+/// Key that the scanner encrypts the gate passes stored offline with, it is released by
+/// the server per device and the device keeps it wrapped by a non-extractable key of its
+/// own. The snapshots exported for the device are encrypted with a key derived from it.
+/// The key is dropped when the device is revoked.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassOfflineKey {
+    pub key: Cow<'static, str>,
+}
+
+/// Linked device that holds gate pass sync data.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassDevice {
+    pub id: Cow<'static, str>,
+    pub login: Cow<'static, str>,
+    pub os: Cow<'static, str>,
+    pub device: Cow<'static, str>,
+    pub last_synced_at: Option<Cow<'static, str>>,
+    pub revoked: bool,
+    pub revoked_at: Option<Cow<'static, str>>,
+    pub revoked_by: Option<Cow<'static, str>>,
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SyncGatePass {
     pub id: Cow<'static, str>,
@@ -391,9 +517,36 @@ pub struct SyncGatePass {
     pub allow_any_vehicle: bool,
     pub vehicles: Vec<GatePassVehicle>,
     pub block: Option<GatePassBlock>,
+    pub qr_token: Option<Cow<'static, str>>,
 }
 
 impl SyncGatePass {
+    pub fn validate_qr_code(&self, qr_code: &GatePassQrCode) -> GatePassValidationResult {
+        self.validate_qr_code_by(qr_code, Utc::now())
+    }
+
+    /// Validates the QR code against the given time instead of the device clock, the
+    /// scanner passes the server-adjusted time so a wrong device date is not trusted.
+    pub fn validate_qr_code_by(
+        &self,
+        qr_code: &GatePassQrCode,
+        now: DateTime<Utc>,
+    ) -> GatePassValidationResult {
+        if self.deleted {
+            return GatePassValidationResult::Deleted;
+        }
+        if self.qr_token != qr_code.qr_token {
+            return GatePassValidationResult::Revoked;
+        }
+        if self.expired_by(now) {
+            return GatePassValidationResult::Expired;
+        }
+        if let Some(block) = self.block.as_ref().filter(|block| !block.expired_by(now)) {
+            return GatePassValidationResult::Blocked(block.clone());
+        }
+        GatePassValidationResult::Valid
+    }
+
     pub fn blocked(&self) -> bool {
         self.block
             .as_ref()
@@ -401,25 +554,67 @@ impl SyncGatePass {
             .is_some()
     }
 
+    pub fn blocked_by(&self, now: DateTime<Utc>) -> bool {
+        self.block
+            .as_ref()
+            .filter(|block| !block.expired_by(now))
+            .is_some()
+    }
+
     pub fn expired(&self) -> bool {
         expired(&self.expired_at)
     }
+
+    pub fn expired_by(&self, now: DateTime<Utc>) -> bool {
+        expired_by(&self.expired_at, now)
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum GatePassValidationResult {
     #[default]
     Valid,
-    Blocked(Cow<'static, str>),
+    Blocked(GatePassBlock),
     Expired,
     Deleted,
+    Revoked,
     NotFound,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
 pub struct SendGatePassEmailRequest {
+    /// Falls back to the owner email when empty.
     #[validate(email)]
-    pub recipient_email: Cow<'static, str>,
+    pub recipient_email: Option<Cow<'static, str>>,
+}
+
+impl SendGatePassEmailRequest {
+    pub fn recipient_email_for(
+        &self,
+        gate_pass: &GatePass,
+    ) -> Result<Cow<'static, str>, ValidationErrors> {
+        gate_pass_recipient_email(self.recipient_email.as_ref(), gate_pass).ok_or_else(|| {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "recipient_email",
+                ValidationError::new("error-gate-pass-owner-email-missing"),
+            );
+            errors
+        })
+    }
+}
+
+/// Returns the requested recipient email or the owner email when it was not requested.
+pub fn gate_pass_recipient_email(
+    recipient_email: Option<&Cow<'static, str>>,
+    gate_pass: &GatePass,
+) -> Option<Cow<'static, str>> {
+    recipient_email
+        .map(|email| canonical_email(email))
+        .filter(|email| !email.is_empty())
+        .map(Cow::Owned)
+        .or_else(|| gate_pass.owner.email.clone())
+        .filter(|email| !email.is_empty())
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
@@ -427,6 +622,28 @@ pub struct PrintGatePassRequest {
     pub ids: Option<Vec<Cow<'static, str>>>,
     pub number_plates: Option<Vec<Cow<'static, str>>>,
     pub two_side_print_mode: TwoSidePrintMode,
+    pub never_printed: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassPrint {
+    pub id: Cow<'static, str>,
+    pub gate_pass_ids: Vec<Cow<'static, str>>,
+    pub number_of_gate_passes: usize,
+    pub two_side_print_mode: TwoSidePrintMode,
+    pub created_by: Cow<'static, str>,
+    pub created_at: Cow<'static, str>,
+    /// Set once the user confirms the gate passes are printed, only then they are counted.
+    #[serde(default)]
+    pub confirmed_by: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub confirmed_at: Option<Cow<'static, str>>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SearchGatePassPrintRequest {
+    pub gate_pass_id: Option<Cow<'static, str>>,
+    pub page_request: Option<PageRequest>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -460,6 +677,140 @@ impl RenewGatePassRequest {
     }
 }
 
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+#[validate(schema(function = "validate_bulk_gate_pass_request"))]
+pub struct BulkGatePassRequest {
+    pub ids: Option<Vec<Cow<'static, str>>>,
+    pub filter: Option<SearchGatePassRequest>,
+    /// Id of the saved [`GatePassFilter`], it replaces the `filter`.
+    pub filter_id: Option<Cow<'static, str>>,
+    pub operation: BulkGatePassOperation,
+}
+
+impl BulkGatePassRequest {
+    pub fn normalize(&mut self) {
+        if let Some(filter) = self.filter.as_mut() {
+            filter.normalize();
+        }
+        match &mut self.operation {
+            BulkGatePassOperation::ChangeUnit(unit) => *unit = uppercase(unit),
+            BulkGatePassOperation::Block(block) => block.normalize(),
+            _ => {}
+        }
+    }
+}
+
+fn validate_bulk_gate_pass_request(
+    request: &BulkGatePassRequest,
+) -> Result<(), validator::ValidationError> {
+    let has_ids = request.ids.as_ref().is_some_and(|ids| !ids.is_empty());
+    let has_filter = request.filter.as_ref().is_some_and(|filter| !filter.is_empty())
+        || request.filter_id.as_ref().is_some_and(|filter_id| !filter_id.is_empty());
+    if !has_ids && !has_filter {
+        return Err(validator::ValidationError::new("selection cannot be empty"));
+    }
+    match &request.operation {
+        BulkGatePassOperation::Block(block) => block
+            .validate()
+            .map_err(|_| validator::ValidationError::new("block cannot be blank")),
+        BulkGatePassOperation::ChangeUnit(value)
+        | BulkGatePassOperation::ChangeExpiry(value) => not_blank(value),
+        BulkGatePassOperation::SendEmail(recipient_email) => SendGatePassEmailRequest {
+            recipient_email: recipient_email.clone().filter(|email| !email.trim().is_empty()),
+        }
+        .validate()
+        .map_err(|_| validator::ValidationError::new("recipient email is invalid")),
+        BulkGatePassOperation::Unblock | BulkGatePassOperation::Export => Ok(()),
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum BulkGatePassOperation {
+    #[default]
+    Export,
+    Block(GatePassBlock),
+    Unblock,
+    ChangeUnit(Cow<'static, str>),
+    ChangeExpiry(Cow<'static, str>),
+    /// Sends to the owner emails when no recipient is given.
+    SendEmail(Option<Cow<'static, str>>),
+}
+
+impl BulkGatePassOperation {
+    /// Checks the operation can be applied to the gate pass, the error is the i18n key of
+    /// the reason reported for the gate pass.
+    pub fn check(
+        &self,
+        gate_pass: &GatePass,
+        policy: &GatePassPolicy,
+    ) -> Result<(), Cow<'static, str>> {
+        match self {
+            BulkGatePassOperation::Block(_) if gate_pass.blocked() => {
+                Err(Cow::Borrowed("error-gate-pass-already-blocked"))
+            }
+            BulkGatePassOperation::Unblock if gate_pass.block.is_none() => {
+                Err(Cow::Borrowed("error-gate-pass-not-blocked"))
+            }
+            BulkGatePassOperation::ChangeExpiry(expired_at) => policy
+                .check_renewal(expired_at, std::slice::from_ref(gate_pass))
+                .map_err(|errors| {
+                    errors
+                        .field_errors()
+                        .into_values()
+                        .flatten()
+                        .next()
+                        .map(|error| error.code.clone())
+                        .unwrap_or(Cow::Borrowed("error-gate-pass-policy-max-validity"))
+                }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BulkGatePassResponse {
+    pub results: Vec<BulkGatePassResult>,
+    pub gate_passes: Vec<GatePass>,
+}
+
+impl BulkGatePassResponse {
+    pub fn number_of_failures(&self) -> usize {
+        self.results.iter().filter(|result| !result.success).count()
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BulkGatePassResult {
+    pub id: Cow<'static, str>,
+    pub number: Option<Cow<'static, str>>,
+    pub success: bool,
+    pub error: Option<Cow<'static, str>>,
+}
+
+impl BulkGatePassResult {
+    pub fn success(id: Cow<'static, str>, number: Option<Cow<'static, str>>) -> Self {
+        Self {
+            id,
+            number,
+            success: true,
+            error: None,
+        }
+    }
+
+    pub fn failure(
+        id: Cow<'static, str>,
+        number: Option<Cow<'static, str>>,
+        error: impl ToString,
+    ) -> Self {
+        Self {
+            id,
+            number,
+            success: false,
+            error: Some(Cow::Owned(error.to_string())),
+        }
+    }
+}
+
 fn normalize_number_plates(number_plates: &Vec<Cow<'static, str>>) -> Vec<Cow<'static, str>> {
     number_plates
         .iter()
@@ -468,7 +819,7 @@ fn normalize_number_plates(number_plates: &Vec<Cow<'static, str>>) -> Vec<Cow<'s
 }
 
 fn normalize_number_plate(number_plate: &Cow<'static, str>) -> Cow<'static, str> {
-    Cow::Owned(uppercase(number_plate).replace(" ", ""))
+    Cow::Owned(canonical_number_plate(number_plate))
 }
 
 fn uppercase(string: &Cow<'static, str>) -> Cow<'static, str> {
@@ -484,10 +835,59 @@ fn normalize(string: &Cow<'static, str>) -> Cow<'static, str> {
     }
 }
 
-fn expired(expired_at: &Cow<'static, str>) -> bool {
+fn expired(expired_at: &str) -> bool {
+    expired_by(expired_at, Utc::now())
+}
+
+fn expired_by(expired_at: &str, now: DateTime<Utc>) -> bool {
     expired_at
         .parse::<DateTime<Utc>>()
         .ok()
-        .map(|expired_at| expired_at <= Utc::now())
+        .map(|expired_at| expired_at <= now)
         .unwrap_or(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bulk_operation_is_checked_per_gate_pass() {
+        let policy = GatePassPolicy {
+            max_validity_days: Some(30),
+            ..Default::default()
+        };
+        let blocked_gate_pass = GatePass {
+            block: Some(GatePassBlock {
+                expired_at: "2999-01-01T00:00:00Z".into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let gate_pass = GatePass::default();
+
+        let block = BulkGatePassOperation::Block(GatePassBlock::default());
+        assert_eq!(
+            block.check(&blocked_gate_pass, &policy),
+            Err(Cow::Borrowed("error-gate-pass-already-blocked"))
+        );
+        assert_eq!(block.check(&gate_pass, &policy), Ok(()));
+
+        let unblock = BulkGatePassOperation::Unblock;
+        assert_eq!(unblock.check(&blocked_gate_pass, &policy), Ok(()));
+        assert_eq!(
+            unblock.check(&gate_pass, &policy),
+            Err(Cow::Borrowed("error-gate-pass-not-blocked"))
+        );
+
+        let change_expiry = BulkGatePassOperation::ChangeExpiry("2999-01-01".into());
+        assert_eq!(
+            change_expiry.check(&gate_pass, &policy),
+            Err(Cow::Borrowed("error-gate-pass-policy-max-validity"))
+        );
+        assert_eq!(
+            BulkGatePassOperation::Export.check(&blocked_gate_pass, &policy),
+            Ok(())
+        );
+    }
+}
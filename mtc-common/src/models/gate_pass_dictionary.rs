@@ -0,0 +1,166 @@
+use super::*;
+use crate::prelude::{dictionary_code, not_blank};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GatePassDictionaryKind {
+    #[default]
+    OwnerTitle,
+    VehicleColor,
+    VehicleBodyType,
+    BlockReason,
+}
+
+impl GatePassDictionaryKind {
+    pub fn values() -> Vec<GatePassDictionaryKind> {
+        vec![
+            GatePassDictionaryKind::OwnerTitle,
+            GatePassDictionaryKind::VehicleColor,
+            GatePassDictionaryKind::VehicleBodyType,
+            GatePassDictionaryKind::BlockReason,
+        ]
+    }
+}
+
+/// Entry of an admin-editable gate pass dictionary, gate passes store the `code`.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassDictionaryEntry {
+    pub id: Cow<'static, str>,
+    pub kind: GatePassDictionaryKind,
+    pub code: Cow<'static, str>,
+    /// Display names by locale, e.g. `uk-UA`.
+    pub translations: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    /// Name printed on the gate pass card.
+    pub print_name: Cow<'static, str>,
+    pub sort_order: i64,
+    pub created_at: Cow<'static, str>,
+    pub updated_at: Cow<'static, str>,
+    pub created_by: Cow<'static, str>,
+    pub updated_by: Cow<'static, str>,
+}
+
+impl GatePassDictionaryEntry {
+    pub fn name(&self, locale: &str) -> Cow<'static, str> {
+        self.translations
+            .get(locale)
+            .or_else(|| self.translations.values().next())
+            .cloned()
+            .unwrap_or_else(|| self.code.clone())
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct GatePassDictionaryEntryRequest {
+    pub kind: GatePassDictionaryKind,
+    #[validate(custom(function = "dictionary_code"))]
+    pub code: Cow<'static, str>,
+    pub translations: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    #[validate(custom(function = "not_blank"))]
+    pub print_name: Cow<'static, str>,
+    pub sort_order: i64,
+    pub created_by: Option<Cow<'static, str>>,
+    pub updated_by: Option<Cow<'static, str>>,
+}
+
+/// All gate pass dictionary entries ordered by kind and sort order.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassDictionaries {
+    pub entries: Vec<GatePassDictionaryEntry>,
+}
+
+impl GatePassDictionaries {
+    pub fn entries(
+        &self,
+        kind: GatePassDictionaryKind,
+    ) -> impl Iterator<Item = &GatePassDictionaryEntry> {
+        self.entries.iter().filter(move |entry| entry.kind == kind)
+    }
+
+    pub fn find(
+        &self,
+        kind: GatePassDictionaryKind,
+        code: &str,
+    ) -> Option<&GatePassDictionaryEntry> {
+        self.entries(kind).find(|entry| entry.code == code)
+    }
+
+    /// Returns the display name, falling back to the code for unknown entries.
+    pub fn name(
+        &self,
+        kind: GatePassDictionaryKind,
+        code: &str,
+        locale: &str,
+    ) -> Cow<'static, str> {
+        self.find(kind, code)
+            .map(|entry| entry.name(locale))
+            .unwrap_or_else(|| Cow::Owned(code.to_string()))
+    }
+
+    pub fn print_name(&self, kind: GatePassDictionaryKind, code: &str) -> Cow<'static, str> {
+        self.find(kind, code)
+            .map(|entry| entry.print_name.clone())
+            .unwrap_or_else(|| Cow::Owned(code.to_string()))
+    }
+
+    pub fn check_create_request(
+        &self,
+        request: &CreateGatePassRequest,
+    ) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self
+            .find(
+                GatePassDictionaryKind::OwnerTitle,
+                request.owner.title.code(),
+            )
+            .is_none()
+        {
+            errors.add("owner", unknown_entry_error(request.owner.title.code()));
+        }
+        for vehicle in request.vehicles.iter() {
+            if self
+                .find(GatePassDictionaryKind::VehicleColor, vehicle.color.code())
+                .is_none()
+            {
+                errors.add("vehicles", unknown_entry_error(vehicle.color.code()));
+            }
+            if self
+                .find(
+                    GatePassDictionaryKind::VehicleBodyType,
+                    vehicle.body_type.code(),
+                )
+                .is_none()
+            {
+                errors.add("vehicles", unknown_entry_error(vehicle.body_type.code()));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn check_block(&self, block: &GatePassBlock) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(reason_code) = block.reason_code.as_ref().filter(|reason_code| {
+            self.find(GatePassDictionaryKind::BlockReason, reason_code)
+                .is_none()
+        }) {
+            errors.add("block", unknown_entry_error(reason_code));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn unknown_entry_error(code: &str) -> ValidationError {
+    let mut error = ValidationError::new("error-gate-pass-dictionary-unknown-entry");
+    error.add_param(Cow::Borrowed("code"), &code);
+    error
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::prelude::not_blank;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use validator::{Validate, ValidationError};
+
+/// Named gate pass search the bulk operations can select the gate passes by, e.g. all gate
+/// passes of a unit.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassFilter {
+    pub id: Cow<'static, str>,
+    pub title: Cow<'static, str>,
+    pub filter: SearchGatePassRequest,
+    pub created_at: Cow<'static, str>,
+    pub updated_at: Cow<'static, str>,
+    pub created_by: Cow<'static, str>,
+    pub updated_by: Cow<'static, str>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct GatePassFilterRequest {
+    #[validate(custom(function = "not_blank"))]
+    pub title: Cow<'static, str>,
+    #[validate(custom(function = "not_empty_filter"))]
+    pub filter: SearchGatePassRequest,
+    pub created_by: Option<Cow<'static, str>>,
+    pub updated_by: Option<Cow<'static, str>>,
+}
+
+impl GatePassFilterRequest {
+    pub fn normalize(&mut self) {
+        self.title = Cow::Owned(self.title.trim().to_string());
+        self.filter.normalize();
+        // the saved filter selects every matching gate pass, the paging is up to the search
+        self.filter.page_request = None;
+    }
+}
+
+fn not_empty_filter(filter: &SearchGatePassRequest) -> Result<(), ValidationError> {
+    if filter.is_empty() {
+        return Err(ValidationError::new("filter cannot be empty"));
+    }
+    Ok(())
+}
@@ -0,0 +1,411 @@
+use super::*;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct GatePassPolicy {
+    #[validate(range(min = 0, message = "error-gate-pass-policy-validity-negative"))]
+    pub max_validity_days: Option<i64>,
+    #[validate(nested)]
+    pub title_rules: Vec<GatePassTitleValidityRule>,
+    #[validate(nested)]
+    pub unit_rules: Vec<GatePassUnitValidityRule>,
+    pub vin_code_required_body_types: Vec<VehicleBodyType>,
+    pub forbid_unknown_number_plates: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct GatePassTitleValidityRule {
+    pub title: GatePassOwnerTitle,
+    #[validate(range(min = 0, message = "error-gate-pass-policy-validity-negative"))]
+    pub max_validity_days: i64,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct GatePassUnitValidityRule {
+    pub unit: Cow<'static, str>,
+    #[validate(range(min = 0, message = "error-gate-pass-policy-validity-negative"))]
+    pub max_validity_days: i64,
+}
+
+impl GatePassPolicy {
+    pub fn normalize(&mut self) {
+        self.unit_rules.iter_mut().for_each(|rule| {
+            rule.unit = Cow::Owned(rule.unit.trim().to_uppercase());
+        });
+    }
+
+    /// Returns the strictest maximum validity in days that applies to the owner,
+    /// or `None` when the policy does not limit the validity for this owner.
+    pub fn max_validity_days(&self, owner: &GatePassOwner) -> Option<i64> {
+        let title_limits = self
+            .title_rules
+            .iter()
+            .filter(|rule| rule.title == owner.title)
+            .map(|rule| rule.max_validity_days);
+        let unit_limits = self
+            .unit_rules
+            .iter()
+            .filter(|rule| rule.unit.eq_ignore_ascii_case(&owner.unit))
+            .map(|rule| rule.max_validity_days);
+        self.max_validity_days
+            .into_iter()
+            .chain(title_limits)
+            .chain(unit_limits)
+            .min()
+    }
+
+    pub fn check_create_request(
+        &self,
+        request: &CreateGatePassRequest,
+    ) -> Result<(), ValidationErrors> {
+        self.check_create_request_by(request, Utc::now())
+    }
+
+    /// Checks the request against the policy, the validity is counted from the given time.
+    pub fn check_create_request_by(
+        &self,
+        request: &CreateGatePassRequest,
+        now: DateTime<Utc>,
+    ) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Err(error) = self.check_expired_at(&request.expired_at, &request.owner, now) {
+            errors.add("expired_at", error);
+        }
+        for (index, vehicle) in request.vehicles.iter().enumerate() {
+            if self.vin_code_required_body_types.contains(&vehicle.body_type)
+                && vehicle
+                    .vin_code
+                    .as_ref()
+                    .is_none_or(|vin_code| vin_code.trim().is_empty())
+            {
+                errors.add(
+                    "vehicles",
+                    vehicle_error("error-gate-pass-policy-vin-code-required", index),
+                );
+            }
+            if self.forbid_unknown_number_plates
+                && matches!(
+                    vehicle.number_plate_type(),
+                    GatePassVehicleNumberPlateType::UNKNOWN(_)
+                )
+            {
+                errors.add(
+                    "vehicles",
+                    vehicle_error("error-gate-pass-policy-unknown-number-plate", index),
+                );
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn check_renewal(
+        &self,
+        expired_at: &str,
+        gate_passes: &[GatePass],
+    ) -> Result<(), ValidationErrors> {
+        self.check_renewal_by(expired_at, gate_passes, Utc::now())
+    }
+
+    /// Checks the new expiry of the gate passes, the validity is counted from the given time.
+    pub fn check_renewal_by(
+        &self,
+        expired_at: &str,
+        gate_passes: &[GatePass],
+        now: DateTime<Utc>,
+    ) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        for gate_pass in gate_passes.iter() {
+            if let Err(mut error) = self.check_expired_at(expired_at, &gate_pass.owner, now) {
+                error.add_param(Cow::Borrowed("id"), &gate_pass.id);
+                error.add_param(Cow::Borrowed("number"), &gate_pass.number);
+                errors.add("expired_at", error);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the owner titles and units the expiry exceeds the validity limit of, so that
+    /// the database can skip their gate passes the way [`GatePassPolicy::check_renewal_by`]
+    /// rejects them. `None` when the expiry exceeds the limit of every owner.
+    pub fn renewal_exclusions_by(
+        &self,
+        expired_at: &str,
+        now: DateTime<Utc>,
+    ) -> Option<(Vec<GatePassOwnerTitle>, Vec<Cow<'static, str>>)> {
+        let validity_days =
+            parse_date(expired_at).map(|expired_date| (expired_date - now.date_naive()).num_days());
+        let exceeds = |max_validity_days: i64| {
+            validity_days.is_none_or(|validity_days| validity_days > max_validity_days)
+        };
+        if self.max_validity_days.is_some_and(exceeds) {
+            return None;
+        }
+
+        let titles = self
+            .title_rules
+            .iter()
+            .filter(|rule| exceeds(rule.max_validity_days))
+            .map(|rule| rule.title.clone())
+            .collect();
+        let units = self
+            .unit_rules
+            .iter()
+            .filter(|rule| exceeds(rule.max_validity_days))
+            .map(|rule| rule.unit.clone())
+            .collect();
+        Some((titles, units))
+    }
+
+    fn check_expired_at(
+        &self,
+        expired_at: &str,
+        owner: &GatePassOwner,
+        now: DateTime<Utc>,
+    ) -> Result<(), ValidationError> {
+        let Some(max_validity_days) = self.max_validity_days(owner) else {
+            return Ok(());
+        };
+        let Some(expired_date) = parse_date(expired_at) else {
+            return Err(ValidationError::new("error-gate-pass-policy-invalid-date"));
+        };
+        let validity_days = (expired_date - now.date_naive()).num_days();
+        if validity_days > max_validity_days {
+            let mut error = ValidationError::new("error-gate-pass-policy-max-validity");
+            error.add_param(Cow::Borrowed("max_validity_days"), &max_validity_days);
+            error.add_param(Cow::Borrowed("validity_days"), &validity_days);
+            return Err(error);
+        }
+        Ok(())
+    }
+}
+
+fn vehicle_error(code: &'static str, index: usize) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.add_param(Cow::Borrowed("index"), &index);
+    error
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map(|date_time| date_time.date_naive())
+        .or_else(|_| value.parse::<NaiveDate>())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        "2025-03-01T10:00:00Z".parse().unwrap()
+    }
+
+    fn owner(title: &'static str, unit: &'static str) -> GatePassOwner {
+        GatePassOwner {
+            title: GatePassOwnerTitle(title.into()),
+            unit: unit.into(),
+            ..Default::default()
+        }
+    }
+
+    fn policy() -> GatePassPolicy {
+        GatePassPolicy {
+            max_validity_days: Some(90),
+            title_rules: vec![GatePassTitleValidityRule {
+                title: GatePassOwnerTitle("Civilian".into()),
+                max_validity_days: 30,
+            }],
+            unit_rules: vec![GatePassUnitValidityRule {
+                unit: "A1234".into(),
+                max_validity_days: 60,
+            }],
+            vin_code_required_body_types: vec![VehicleBodyType("Truck".into())],
+            forbid_unknown_number_plates: true,
+        }
+    }
+
+    fn vehicle(number_plate: &'static str, body_type: &'static str) -> GatePassVehicle {
+        GatePassVehicle {
+            number_plate: number_plate.into(),
+            body_type: VehicleBodyType(body_type.into()),
+            ..Default::default()
+        }
+    }
+
+    fn error_codes(errors: ValidationErrors, field: &str) -> Vec<Cow<'static, str>> {
+        errors
+            .field_errors()
+            .get(field)
+            .map(|errors| errors.iter().map(|error| error.code.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn max_validity_days_is_the_strictest_limit() {
+        let policy = policy();
+
+        assert_eq!(policy.max_validity_days(&owner("Soldier", "B1")), Some(90));
+        assert_eq!(
+            policy.max_validity_days(&owner("Soldier", "a1234")),
+            Some(60)
+        );
+        assert_eq!(
+            policy.max_validity_days(&owner("Civilian", "A1234")),
+            Some(30)
+        );
+        assert_eq!(
+            GatePassPolicy {
+                max_validity_days: None,
+                ..policy.clone()
+            }
+            .max_validity_days(&owner("Soldier", "B1")),
+            None
+        );
+    }
+
+    #[test]
+    fn check_create_request_checks_expiry_and_vehicles() {
+        let policy = policy();
+        let request = CreateGatePassRequest {
+            expired_at: "2025-05-30".into(),
+            owner: owner("Soldier", "B1"),
+            vehicles: vec![vehicle("1234А5", "Truck"), vehicle("АА1234ВВ", "Sedan")],
+            ..Default::default()
+        };
+        assert_eq!(
+            error_codes(
+                policy.check_create_request_by(&request, now()).unwrap_err(),
+                "vehicles"
+            ),
+            vec![Cow::Borrowed("error-gate-pass-policy-vin-code-required")]
+        );
+
+        let request = CreateGatePassRequest {
+            expired_at: "2025-05-31".into(),
+            vehicles: vec![
+                GatePassVehicle {
+                    vin_code: Some("WVWZZZ1JZXW000001".into()),
+                    ..vehicle("1234А5", "Truck")
+                },
+                vehicle("AB-123", "Sedan"),
+            ],
+            ..request
+        };
+        let errors = policy.check_create_request_by(&request, now()).unwrap_err();
+        assert_eq!(
+            error_codes(errors.clone(), "expired_at"),
+            vec![Cow::Borrowed("error-gate-pass-policy-max-validity")]
+        );
+        assert_eq!(
+            error_codes(errors, "vehicles"),
+            vec![Cow::Borrowed("error-gate-pass-policy-unknown-number-plate")]
+        );
+
+        let request = CreateGatePassRequest {
+            expired_at: "2025-05-30".into(),
+            vehicles: vec![vehicle("АА1234ВВ", "Sedan")],
+            ..request
+        };
+        assert_eq!(policy.check_create_request_by(&request, now()), Ok(()));
+    }
+
+    #[test]
+    fn check_renewal_reports_each_gate_pass() {
+        let policy = policy();
+        let gate_passes = vec![
+            GatePass {
+                id: "soldier".into(),
+                owner: owner("Soldier", "B1"),
+                ..Default::default()
+            },
+            GatePass {
+                id: "civilian".into(),
+                owner: owner("Civilian", "B1"),
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(
+            policy.check_renewal_by("2025-03-31T00:00:00Z", &gate_passes, now()),
+            Ok(())
+        );
+        let errors = policy
+            .check_renewal_by("2025-04-01T00:00:00Z", &gate_passes, now())
+            .unwrap_err();
+        let errors = errors.field_errors();
+        assert_eq!(errors["expired_at"].len(), 1);
+        assert_eq!(errors["expired_at"][0].params["id"], "civilian");
+        assert_eq!(
+            error_codes(
+                policy
+                    .check_renewal_by("not a date", &gate_passes, now())
+                    .unwrap_err(),
+                "expired_at"
+            ),
+            vec![Cow::Borrowed("error-gate-pass-policy-invalid-date"); 2]
+        );
+    }
+
+    #[test]
+    fn renewal_exclusions_match_the_renewal_check() {
+        let policy = policy();
+
+        assert_eq!(
+            policy.renewal_exclusions_by("2025-03-31", now()),
+            Some((vec![], vec![]))
+        );
+        assert_eq!(
+            policy.renewal_exclusions_by("2025-04-01", now()),
+            Some((vec![GatePassOwnerTitle("Civilian".into())], vec![]))
+        );
+        assert_eq!(
+            policy.renewal_exclusions_by("2025-05-01", now()),
+            Some((
+                vec![GatePassOwnerTitle("Civilian".into())],
+                vec![Cow::Borrowed("A1234")]
+            ))
+        );
+        assert_eq!(policy.renewal_exclusions_by("2025-05-31", now()), None);
+        assert_eq!(policy.renewal_exclusions_by("not a date", now()), None);
+        assert_eq!(
+            GatePassPolicy::default().renewal_exclusions_by("not a date", now()),
+            Some((vec![], vec![]))
+        );
+    }
+
+    #[test]
+    fn negative_validity_is_rejected() {
+        assert!(policy().validate().is_ok());
+        assert!(
+            GatePassPolicy {
+                max_validity_days: Some(-1),
+                ..Default::default()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            GatePassPolicy {
+                unit_rules: vec![GatePassUnitValidityRule {
+                    unit: "A1234".into(),
+                    max_validity_days: -1,
+                }],
+                ..Default::default()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
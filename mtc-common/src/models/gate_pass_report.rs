@@ -0,0 +1,111 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use validator::Validate;
+
+/// Default look-ahead of the expiring gate passes report.
+pub static GATE_PASS_REPORT_EXPIRING_DAYS: i64 = 30;
+
+/// I18n keys of the report texts, the front-end sends their translations with the request.
+pub static GATE_PASS_REPORT_I18N_KEYS: [&str; 22] = [
+    "gate-pass-report-kind-register",
+    "gate-pass-report-kind-byunit",
+    "gate-pass-report-title-expiring",
+    "gate-pass-report-kind-blocked",
+    "gate-pass-report-column-row-number",
+    "gate-pass-report-column-number",
+    "gate-pass-report-column-owner-title",
+    "gate-pass-report-column-owner-name",
+    "gate-pass-report-column-unit",
+    "gate-pass-report-column-number-plate",
+    "gate-pass-report-column-vehicle",
+    "gate-pass-report-column-expired-at",
+    "gate-pass-report-column-days-left",
+    "gate-pass-report-column-block-reason",
+    "gate-pass-report-column-block-expired-at",
+    "gate-pass-report-column-blocked-by",
+    "gate-pass-report-section-unit",
+    "gate-pass-report-generated-at",
+    "gate-pass-report-sheet",
+    "gate-pass-report-signature-chief",
+    "gate-pass-report-signature-executor",
+    "gate-pass-report-signature-hint",
+];
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum GatePassReportKind {
+    /// All gate passes matching the filter.
+    #[default]
+    Register,
+    /// Gate passes grouped by the owner unit.
+    ByUnit,
+    /// Active gate passes expiring within the given number of days.
+    Expiring,
+    /// Currently blocked gate passes.
+    Blocked,
+}
+
+impl GatePassReportKind {
+    pub fn values() -> Vec<GatePassReportKind> {
+        vec![
+            GatePassReportKind::Register,
+            GatePassReportKind::ByUnit,
+            GatePassReportKind::Expiring,
+            GatePassReportKind::Blocked,
+        ]
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum GatePassReportFormat {
+    #[default]
+    Xlsx,
+    Pdf,
+}
+
+impl GatePassReportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            GatePassReportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            GatePassReportFormat::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            GatePassReportFormat::Xlsx => "xlsx",
+            GatePassReportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct GatePassReportRequest {
+    pub kind: GatePassReportKind,
+    pub format: GatePassReportFormat,
+    /// Same filter as the gate pass search, the paging is ignored.
+    pub filter: Option<SearchGatePassRequest>,
+    #[validate(range(min = 1, max = 366))]
+    pub expiring_within_days: Option<i64>,
+    /// Translations of the [`GATE_PASS_REPORT_I18N_KEYS`] in the language of the user.
+    #[serde(default)]
+    pub i18n: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+}
+
+impl GatePassReportRequest {
+    /// Returns the translation of the report text, the key itself when it is missing.
+    pub fn t(&self, key: &str) -> String {
+        self.i18n
+            .get(key)
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn expiring_within_days(&self) -> i64 {
+        self.expiring_within_days
+            .unwrap_or(GATE_PASS_REPORT_EXPIRING_DAYS)
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub const GATE_PASS_SNAPSHOT_VERSION: u8 = 2;
+/// Prefix that separates the snapshot key from the offline key it is derived from.
+const GATE_PASS_SNAPSHOT_KEY_CONTEXT: &str = "gate-pass-snapshot:";
+
+/// Derives the key of the snapshots exported for a device from the offline key of the
+/// device, so only that device reads them and a revoked device gets no new ones.
+pub fn gate_pass_snapshot_key(offline_key: &str) -> String {
+    format!("{GATE_PASS_SNAPSHOT_KEY_CONTEXT}{offline_key}")
+}
+
+/// Gate passes for checkpoints without network access, it carries the same data
+/// as the sync endpoint delivers together with the sync cursor.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassSnapshot {
+    pub created_at: Cow<'static, str>,
+    pub created_by: Cow<'static, str>,
+    pub since: Option<Cow<'static, str>>,
+    pub last_synced_at: Cow<'static, str>,
+    pub gate_passes: Vec<SyncGatePass>,
+}
+
+impl GatePassSnapshot {
+    pub fn age(&self) -> Option<Duration> {
+        self.created_at
+            .parse::<DateTime<Utc>>()
+            .ok()
+            .map(|created_at| Utc::now() - created_at)
+    }
+
+    /// Checks whether the snapshot can be applied on top of the local data synced up
+    /// to `last_synced_at`: it should neither leave a gap nor roll the data back.
+    pub fn applicable(&self, last_synced_at: Option<&str>) -> bool {
+        let Some(last_synced_at) =
+            last_synced_at.and_then(|value| value.parse::<DateTime<Utc>>().ok())
+        else {
+            return self.since.is_none();
+        };
+        let since_ok = self
+            .since
+            .as_ref()
+            .and_then(|since| since.parse::<DateTime<Utc>>().ok())
+            .is_none_or(|since| since <= last_synced_at);
+        let cursor_ok = self
+            .last_synced_at
+            .parse::<DateTime<Utc>>()
+            .is_ok_and(|snapshot_synced_at| snapshot_synced_at >= last_synced_at);
+        since_ok && cursor_ok
+    }
+}
+
+/// Snapshot file: `payload` is the encrypted [`GatePassSnapshot`] in base64 and
+/// `signature` is the Ed25519 signature of the `payload` in base64.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassSnapshotFile {
+    pub version: u8,
+    pub payload: Cow<'static, str>,
+    pub signature: Cow<'static, str>,
+}
@@ -6,8 +6,202 @@ pub struct Group {
     pub id: Cow<'static, str>,
     pub slug: Cow<'static, str>,
     pub title: Cow<'static, str>,
+    /// Id of the parent group, empty for the top level group.
+    #[serde(default)]
+    pub parent: Cow<'static, str>,
     pub created_at: Cow<'static, str>,
     pub updated_at: Cow<'static, str>,
     pub created_by: Cow<'static, str>,
     pub updated_by: Cow<'static, str>,
 }
+
+/// Place of the group in the hierarchy, carried as the `variant` of the group list entry.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GroupNode {
+    pub parent: Cow<'static, str>,
+    pub depth: usize,
+    pub leaf: bool,
+}
+
+impl GroupNode {
+    /// Reads the node of the group list entry.
+    pub fn of(entry: &Entry) -> GroupNode {
+        entry
+            .variant
+            .clone()
+            .and_then(|variant| serde_json::from_value(variant).ok())
+            .unwrap_or_default()
+    }
+
+    /// Orders the groups depth first, the children follow their parent in the given order.
+    /// The `variant` of the given entries holds the parent id and is replaced with the node,
+    /// the groups with an unknown parent or caught in a loop are put at the top level.
+    pub fn tree(groups: Vec<Entry>) -> Vec<Entry> {
+        let parents = groups
+            .iter()
+            .map(|group| {
+                let parent = group
+                    .variant
+                    .as_ref()
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                groups
+                    .iter()
+                    .position(|candidate| candidate.id == parent && !parent.is_empty())
+            })
+            .collect::<Vec<Option<usize>>>();
+
+        let mut placed = vec![false; groups.len()];
+        let mut order = Vec::with_capacity(groups.len());
+        let mut stack = (0..groups.len())
+            .rev()
+            .filter(|&index| parents[index].is_none())
+            .map(|index| (index, 0))
+            .collect::<Vec<(usize, usize)>>();
+        loop {
+            while let Some((index, depth)) = stack.pop() {
+                if placed[index] {
+                    continue;
+                }
+                placed[index] = true;
+                order.push((index, depth));
+                stack.extend(
+                    (0..groups.len())
+                        .rev()
+                        .filter(|&child| !placed[child] && parents[child] == Some(index))
+                        .map(|child| (child, depth + 1)),
+                );
+            }
+            // a loop is unreachable from the top level, it is cut at its first group
+            match placed.iter().position(|placed| !placed) {
+                Some(index) => stack.push((index, 0)),
+                None => break,
+            }
+        }
+
+        let ids = groups
+            .iter()
+            .map(|group| group.id.clone())
+            .collect::<Vec<_>>();
+        let mut groups = groups.into_iter().map(Some).collect::<Vec<Option<Entry>>>();
+        order
+            .iter()
+            .enumerate()
+            .filter_map(|(position, &(index, depth))| {
+                let mut group = groups[index].take()?;
+                let node = GroupNode {
+                    parent: match (depth, parents[index]) {
+                        (1.., Some(parent)) => ids[parent].clone(),
+                        _ => Cow::Borrowed(""),
+                    },
+                    depth,
+                    leaf: order
+                        .get(position + 1)
+                        .is_none_or(|&(_, next_depth)| next_depth <= depth),
+                };
+                group.variant = serde_json::to_value(node).ok();
+                Some(group)
+            })
+            .collect()
+    }
+
+    /// Returns the id of the group followed by the ids of all its descendants, `tree` is
+    /// ordered by [`GroupNode::tree`].
+    pub fn subtree(tree: &[Entry], id: &str) -> Vec<Cow<'static, str>> {
+        let Some(position) = tree.iter().position(|group| group.id == id) else {
+            return vec![];
+        };
+        let depth = GroupNode::of(&tree[position]).depth;
+
+        tree[position..]
+            .iter()
+            .enumerate()
+            .take_while(|(offset, group)| *offset == 0 || GroupNode::of(group).depth > depth)
+            .map(|(_, group)| group.id.clone())
+            .collect()
+    }
+
+    /// Returns the groups without children titled with the path from the top level, e.g.
+    /// `1 Battalion / 2 Company / 3 Platoon`, the users are assigned to these groups.
+    pub fn leaves(tree: &[Entry]) -> Vec<Entry> {
+        let mut path: Vec<Cow<'static, str>> = vec![];
+        let mut leaves = vec![];
+        for group in tree {
+            let node = GroupNode::of(group);
+            path.truncate(node.depth);
+            path.push(group.title.clone());
+            if node.leaf {
+                leaves.push(Entry {
+                    title: Cow::Owned(path.join(" / ")),
+                    ..group.clone()
+                });
+            }
+        }
+        leaves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(id: &'static str, parent: &'static str) -> Entry {
+        Entry {
+            id: id.into(),
+            slug: id.into(),
+            title: id.to_uppercase().into(),
+            variant: Some(Value::from(parent)),
+        }
+    }
+
+    #[test]
+    fn tree_orders_groups_depth_first() {
+        let tree = GroupNode::tree(vec![
+            group("company", "battalion"),
+            group("battalion", ""),
+            group("platoon", "company"),
+            group("staff", "battalion"),
+            group("orphan", "missing"),
+            group("loop-a", "loop-b"),
+            group("loop-b", "loop-a"),
+        ]);
+
+        let ids = tree
+            .iter()
+            .map(|group| group.id.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                "battalion",
+                "company",
+                "platoon",
+                "staff",
+                "orphan",
+                "loop-a",
+                "loop-b"
+            ]
+        );
+        assert_eq!(
+            GroupNode::of(&tree[2]),
+            GroupNode {
+                parent: "company".into(),
+                depth: 2,
+                leaf: true
+            }
+        );
+        assert!(!GroupNode::of(&tree[0]).leaf);
+        assert_eq!(GroupNode::of(&tree[5]).depth, 0);
+        assert_eq!(GroupNode::of(&tree[6]).parent, "loop-a");
+
+        assert_eq!(
+            GroupNode::subtree(&tree, "company"),
+            vec![Cow::Borrowed("company"), Cow::Borrowed("platoon")]
+        );
+        assert_eq!(GroupNode::subtree(&tree, "battalion").len(), 4);
+
+        let leaves = GroupNode::leaves(&tree);
+        assert_eq!(leaves[0].title, "BATTALION / COMPANY / PLATOON");
+        assert_eq!(leaves[1].title, "BATTALION / STAFF");
+    }
+}
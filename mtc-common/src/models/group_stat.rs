@@ -4,6 +4,9 @@ use super::*;
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct GroupStat {
     pub title: Cow<'static, str>,
+    /// Depth of the group in the hierarchy, the counts include the descendant groups.
+    #[serde(default)]
+    pub depth: usize,
     pub online: i64,
     pub total: i64,
 }
@@ -25,6 +25,18 @@ mod query_uuid;
 mod file_entry;
 mod group_stat;
 mod gate_pass;
+mod gate_pass_policy;
+mod gate_pass_dictionary;
+mod gate_pass_filter;
+mod gate_pass_snapshot;
+mod gate_pass_report;
+mod two_factor;
+mod sign_in_lockout;
+mod password;
+mod user_device;
+mod user_session;
+mod security_audit;
+mod access_token;
 mod pagination;
 
 pub mod prelude {
@@ -54,6 +66,18 @@ pub mod prelude {
         file_entry::*,
         group_stat::*,
         gate_pass::*,
+        gate_pass_policy::*,
+        gate_pass_dictionary::*,
+        gate_pass_filter::*,
+        gate_pass_snapshot::*,
+        gate_pass_report::*,
+        two_factor::*,
+        sign_in_lockout::*,
+        password::*,
+        user_device::*,
+        user_session::*,
+        security_audit::*,
+        access_token::*,
         pagination::*,
     };
 }
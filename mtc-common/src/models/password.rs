@@ -0,0 +1,82 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Rules for the passwords chosen by the users.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub uppercase: bool,
+    pub lowercase: bool,
+    pub digit: bool,
+    pub symbol: bool,
+    /// Days a password stays valid, `0` when the passwords never expire.
+    pub expiration_days: i64,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            uppercase: true,
+            lowercase: true,
+            digit: true,
+            symbol: false,
+            expiration_days: 0,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Returns the i18n keys of the rules the password breaks.
+    pub fn violations(&self, password: &str) -> Vec<&'static str> {
+        let mut violations = vec![];
+        if password.chars().count() < self.min_length {
+            violations.push("error-password-too-short");
+        }
+        if self.uppercase && !password.chars().any(char::is_uppercase) {
+            violations.push("error-password-no-uppercase");
+        }
+        if self.lowercase && !password.chars().any(char::is_lowercase) {
+            violations.push("error-password-no-lowercase");
+        }
+        if self.digit && !password.chars().any(|char| char.is_ascii_digit()) {
+            violations.push("error-password-no-digit");
+        }
+        if self.symbol && password.chars().all(char::is_alphanumeric) {
+            violations.push("error-password-no-symbol");
+        }
+        violations
+    }
+}
+
+/// Password generated by the administrator reset, valid for one sign-in only.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OneTimePassword {
+    pub password: Cow<'static, str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn violations_follow_policy() {
+        let policy = PasswordPolicy::default();
+        assert!(policy.violations("Secret42").is_empty());
+        assert_eq!(
+            policy.violations("secret42"),
+            vec!["error-password-no-uppercase"]
+        );
+        assert_eq!(policy.violations("Sec4"), vec!["error-password-too-short"]);
+
+        let policy = PasswordPolicy {
+            symbol: true,
+            ..PasswordPolicy::default()
+        };
+        assert_eq!(
+            policy.violations("Secret42"),
+            vec!["error-password-no-symbol"]
+        );
+        assert!(policy.violations("Secret-42").is_empty());
+    }
+}
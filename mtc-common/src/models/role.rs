@@ -8,6 +8,8 @@ pub struct Role {
     pub title: Cow<'static, str>,
     pub user_access_level: i32,
     pub user_access_all: bool,
+    #[serde(default)]
+    pub two_factor_required: bool,
     pub permissions: Vec<Cow<'static, str>>,
     pub created_at: Cow<'static, str>,
     pub updated_at: Cow<'static, str>,
@@ -23,6 +25,7 @@ impl Default for Role {
             title: "".into(),
             user_access_level: 999,
             user_access_all: false,
+            two_factor_required: false,
             permissions: vec![],
             created_at: Default::default(),
             updated_at: Default::default(),
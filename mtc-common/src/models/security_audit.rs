@@ -0,0 +1,134 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Authentication or authorization event recorded into the security audit log.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityAuditKind {
+    #[default]
+    SignIn,
+    SignInFailure,
+    SignOut,
+    PasswordChange,
+    PasswordReset,
+    DeviceLink,
+    DeviceRevoke,
+    SessionRevoke,
+    SignInUnlock,
+    TwoFactorEnable,
+    TwoFactorDisable,
+    TwoFactorReset,
+    PermissionDenied,
+    UserUpdate,
+    UserDelete,
+    RoleUpdate,
+    RoleDelete,
+    PermissionCreate,
+    PermissionDelete,
+    GroupUpdate,
+    GroupDelete,
+    AccessTokenCreate,
+    AccessTokenRevoke,
+    DirectorySync,
+}
+
+impl SecurityAuditKind {
+    pub const ALL: [SecurityAuditKind; 24] = [
+        SecurityAuditKind::SignIn,
+        SecurityAuditKind::SignInFailure,
+        SecurityAuditKind::SignOut,
+        SecurityAuditKind::PasswordChange,
+        SecurityAuditKind::PasswordReset,
+        SecurityAuditKind::DeviceLink,
+        SecurityAuditKind::DeviceRevoke,
+        SecurityAuditKind::SessionRevoke,
+        SecurityAuditKind::SignInUnlock,
+        SecurityAuditKind::TwoFactorEnable,
+        SecurityAuditKind::TwoFactorDisable,
+        SecurityAuditKind::TwoFactorReset,
+        SecurityAuditKind::PermissionDenied,
+        SecurityAuditKind::UserUpdate,
+        SecurityAuditKind::UserDelete,
+        SecurityAuditKind::RoleUpdate,
+        SecurityAuditKind::RoleDelete,
+        SecurityAuditKind::PermissionCreate,
+        SecurityAuditKind::PermissionDelete,
+        SecurityAuditKind::GroupUpdate,
+        SecurityAuditKind::GroupDelete,
+        SecurityAuditKind::AccessTokenCreate,
+        SecurityAuditKind::AccessTokenRevoke,
+        SecurityAuditKind::DirectorySync,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecurityAuditKind::SignIn => "SignIn",
+            SecurityAuditKind::SignInFailure => "SignInFailure",
+            SecurityAuditKind::SignOut => "SignOut",
+            SecurityAuditKind::PasswordChange => "PasswordChange",
+            SecurityAuditKind::PasswordReset => "PasswordReset",
+            SecurityAuditKind::DeviceLink => "DeviceLink",
+            SecurityAuditKind::DeviceRevoke => "DeviceRevoke",
+            SecurityAuditKind::SessionRevoke => "SessionRevoke",
+            SecurityAuditKind::SignInUnlock => "SignInUnlock",
+            SecurityAuditKind::TwoFactorEnable => "TwoFactorEnable",
+            SecurityAuditKind::TwoFactorDisable => "TwoFactorDisable",
+            SecurityAuditKind::TwoFactorReset => "TwoFactorReset",
+            SecurityAuditKind::PermissionDenied => "PermissionDenied",
+            SecurityAuditKind::UserUpdate => "UserUpdate",
+            SecurityAuditKind::UserDelete => "UserDelete",
+            SecurityAuditKind::RoleUpdate => "RoleUpdate",
+            SecurityAuditKind::RoleDelete => "RoleDelete",
+            SecurityAuditKind::PermissionCreate => "PermissionCreate",
+            SecurityAuditKind::PermissionDelete => "PermissionDelete",
+            SecurityAuditKind::GroupUpdate => "GroupUpdate",
+            SecurityAuditKind::GroupDelete => "GroupDelete",
+            SecurityAuditKind::AccessTokenCreate => "AccessTokenCreate",
+            SecurityAuditKind::AccessTokenRevoke => "AccessTokenRevoke",
+            SecurityAuditKind::DirectorySync => "DirectorySync",
+        }
+    }
+}
+
+/// Entry of the security audit log.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SecurityAuditEvent {
+    pub id: Cow<'static, str>,
+    pub kind: SecurityAuditKind,
+    /// Login of the user who did the action, the attempted login for the failed sign-ins.
+    pub actor: Cow<'static, str>,
+    /// Login, role, group or permission the action was done to.
+    pub target: Cow<'static, str>,
+    pub ip: Cow<'static, str>,
+    pub user_agent: Cow<'static, str>,
+    pub details: Option<Value>,
+    pub created_at: Cow<'static, str>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SearchSecurityAuditRequest {
+    pub kinds: Option<Vec<SecurityAuditKind>>,
+    pub actor: Option<Cow<'static, str>>,
+    pub target: Option<Cow<'static, str>>,
+    pub ip: Option<Cow<'static, str>>,
+    /// Dates of the period, `YYYY-MM-DD`, both inclusive.
+    pub date_from: Option<Cow<'static, str>>,
+    pub date_to: Option<Cow<'static, str>>,
+    pub page_request: Option<PageRequest>,
+}
+
+impl SearchSecurityAuditRequest {
+    pub fn normalize(&mut self) {
+        let trim = |value: &mut Option<Cow<'static, str>>| {
+            *value = value
+                .take()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .map(Cow::Owned);
+        };
+        trim(&mut self.actor);
+        trim(&mut self.target);
+        trim(&mut self.ip);
+        trim(&mut self.date_from);
+        trim(&mut self.date_to);
+    }
+}
@@ -0,0 +1,28 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Subject of the failed sign-in counting.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SignInThrottleKind {
+    #[default]
+    Login,
+    Ip,
+}
+
+/// Temporary sign-in lockout of a login or a client IP.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SignInLockout {
+    pub id: Cow<'static, str>,
+    pub kind: SignInThrottleKind,
+    /// Locked login or client IP.
+    pub key: Cow<'static, str>,
+    /// Client IP of the failure that caused the lockout.
+    pub ip: Cow<'static, str>,
+    pub failures: i64,
+    pub locked_at: Cow<'static, str>,
+    pub locked_until: Cow<'static, str>,
+    /// Set while the lockout is neither expired nor unlocked.
+    pub active: bool,
+    pub unlocked_at: Option<Cow<'static, str>>,
+    pub unlocked_by: Option<Cow<'static, str>>,
+}
@@ -0,0 +1,69 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Number of the recovery codes issued on the two-factor enrollment.
+pub static TWO_FACTOR_RECOVERY_CODES: usize = 10;
+
+/// Second sign-in step the client has to pass after the password check.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TwoFactorStep {
+    /// The user has to enter a TOTP or a recovery code.
+    Verify,
+    /// One of the user roles requires two-factor, the user has to enroll first.
+    Enroll,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SignInResponse {
+    /// `None` when the user is signed in.
+    pub two_factor: Option<TwoFactorStep>,
+    /// Set when the user has to change the password to complete the sign-in.
+    #[serde(default)]
+    pub password_change_required: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    /// Set when one of the user roles requires two-factor.
+    pub required: bool,
+    pub recovery_codes_left: usize,
+}
+
+/// Pending TOTP secret, confirmed with the first generated code.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TwoFactorSetup {
+    pub secret: Cow<'static, str>,
+    pub otpauth_url: Cow<'static, str>,
+    /// SVG image of the `otpauth_url`.
+    pub qr_code: Cow<'static, str>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct TwoFactorCodeRequest {
+    /// TOTP code or, on the sign-in, one of the recovery codes.
+    #[validate(length(min = 6, max = 32, message = "error-two-factor-code-invalid"))]
+    pub code: Cow<'static, str>,
+}
+
+impl TwoFactorCodeRequest {
+    pub fn normalize(&mut self) {
+        self.code = Cow::Owned(
+            self.code
+                .chars()
+                .filter(|char| !char.is_whitespace())
+                .collect::<String>()
+                .to_uppercase(),
+        );
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TwoFactorRecoveryCodes {
+    /// Shown once, only the hashes are stored.
+    pub recovery_codes: Vec<Cow<'static, str>>,
+    /// Set when the enrollment completed the sign-in but the password has to be changed.
+    #[serde(default)]
+    pub password_change_required: bool,
+}
@@ -9,6 +9,10 @@ pub struct User {
     pub password: Cow<'static, str>,
     pub group: Cow<'static, str>,
     pub blocked: bool,
+    #[serde(default)]
+    pub two_factor_enabled: bool,
+    #[serde(default)]
+    pub password_change_required: bool,
     pub access_count: i32,
     pub access_level: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -29,6 +33,8 @@ impl Default for User {
             password: "".into(),
             group: "".into(),
             blocked: false,
+            two_factor_enabled: false,
+            password_change_required: false,
             access_level: 999,
             access_count: 0,
             last_access: Default::default(),
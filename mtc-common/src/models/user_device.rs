@@ -0,0 +1,29 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Device linked to the user account with the QR sign-in.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserDevice {
+    pub id: Cow<'static, str>,
+    pub os: Cow<'static, str>,
+    pub device: Cow<'static, str>,
+    pub created_at: Cow<'static, str>,
+    pub last_used_at: Option<Cow<'static, str>>,
+    pub expires_at: Option<Cow<'static, str>>,
+    pub revoked: bool,
+    pub revoked_at: Option<Cow<'static, str>>,
+    pub revoked_by: Option<Cow<'static, str>>,
+    /// Set when the device is neither revoked nor expired.
+    pub active: bool,
+    /// Set for the device of the requesting session.
+    #[serde(default)]
+    pub current: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
+pub struct UserDeviceExpiryRequest {
+    /// Days from now the device key stays valid, `None` when it never expires.
+    #[validate(range(min = 1, max = 3650, message = "error-user-device-expiry-invalid"))]
+    pub expires_in_days: Option<i64>,
+}
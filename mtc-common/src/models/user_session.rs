@@ -0,0 +1,22 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Signed-in session of the user.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserSession {
+    pub id: Cow<'static, str>,
+    pub login: Cow<'static, str>,
+    pub ip: Cow<'static, str>,
+    pub user_agent: Cow<'static, str>,
+    pub created_at: Cow<'static, str>,
+    pub last_seen_at: Cow<'static, str>,
+    pub expires_at: Cow<'static, str>,
+    pub revoked: bool,
+    pub revoked_at: Option<Cow<'static, str>>,
+    pub revoked_by: Option<Cow<'static, str>>,
+    /// Set when the session is neither revoked nor expired.
+    pub active: bool,
+    /// Set for the session of the requesting user.
+    #[serde(default)]
+    pub current: bool,
+}
@@ -0,0 +1,54 @@
+use validator::ValidationError;
+
+const PHONE_MIN_DIGITS: usize = 10;
+const PHONE_MAX_DIGITS: usize = 15;
+
+/// Removes spaces, dashes, dots and brackets from the phone number,
+/// e.g. `+38 (050) 123-45-67` becomes `+380501234567`.
+pub fn canonical_phone(phone: &str) -> String {
+    phone
+        .trim()
+        .chars()
+        .filter(|char| !char.is_whitespace() && !matches!(char, '-' | '.' | '(' | ')'))
+        .collect()
+}
+
+/// Lowercases and trims the email address.
+pub fn canonical_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Validates the phone number: an optional leading `+` followed by 10 to 15 digits.
+pub fn valid_phone(phone: &str) -> Result<(), ValidationError> {
+    let phone = canonical_phone(phone);
+    if phone.is_empty() {
+        return Ok(());
+    }
+    let digits = phone.strip_prefix('+').unwrap_or(&phone);
+    if !digits.chars().all(|char| char.is_ascii_digit())
+        || !(PHONE_MIN_DIGITS..=PHONE_MAX_DIGITS).contains(&digits.len())
+    {
+        return Err(ValidationError::new("error-gate-pass-owner-phone-invalid"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_phone_removes_separators() {
+        assert_eq!(canonical_phone(" +38 (050) 123-45-67 "), "+380501234567");
+    }
+
+    #[test]
+    fn valid_phone_checks_digits() {
+        assert!(valid_phone("+38 (050) 123-45-67").is_ok());
+        assert!(valid_phone("0501234567").is_ok());
+        assert!(valid_phone("").is_ok());
+        assert!(valid_phone("050123456").is_err());
+        assert!(valid_phone("+38050123456a").is_err());
+        assert!(valid_phone("++380501234567").is_err());
+    }
+}
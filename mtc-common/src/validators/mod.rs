@@ -1,5 +1,9 @@
 mod string_validators;
+mod vehicle_validators;
+mod contact_validators;
 
 pub mod prelude {
     pub use super::string_validators::*;
+    pub use super::vehicle_validators::*;
+    pub use super::contact_validators::*;
 }
\ No newline at end of file
@@ -5,4 +5,17 @@ pub fn not_blank(string: &str) -> Result<(), ValidationError> {
         return Err(ValidationError::new("cannot be blank"));
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Dictionary codes are stored in the gate passes, so they are limited to
+/// Latin letters and digits starting with a letter, like the seeded `DarkGrey`.
+pub fn dictionary_code(string: &str) -> Result<(), ValidationError> {
+    let mut chars = string.chars();
+    let valid = chars.next().is_some_and(|char| char.is_ascii_alphabetic())
+        && chars.all(|char| char.is_ascii_alphanumeric())
+        && string.len() <= 64;
+    if !valid {
+        return Err(ValidationError::new("error-gate-pass-dictionary-code-invalid"));
+    }
+    Ok(())
+}
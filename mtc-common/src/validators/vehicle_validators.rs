@@ -0,0 +1,195 @@
+use validator::ValidationError;
+
+/// Letters that are allowed on Ukrainian number plates, they all have a Latin look-alike.
+pub const NUMBER_PLATE_LETTERS: &str = "АВЕІКМНОРСТХ";
+
+const LATIN_TO_CYRILLIC: [(char, char); 12] = [
+    ('A', 'А'),
+    ('B', 'В'),
+    ('E', 'Е'),
+    ('I', 'І'),
+    ('K', 'К'),
+    ('M', 'М'),
+    ('H', 'Н'),
+    ('O', 'О'),
+    ('P', 'Р'),
+    ('C', 'С'),
+    ('T', 'Т'),
+    ('X', 'Х'),
+];
+
+const VIN_CODE_LENGTH: usize = 17;
+const VIN_CODE_CHECK_DIGIT_INDEX: usize = 8;
+const VIN_CODE_WEIGHTS: [u32; VIN_CODE_LENGTH] =
+    [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Uppercases the number plate, removes separators and replaces Latin look-alike
+/// letters with the Cyrillic ones, e.g. `aa 1234 bb` becomes `АА1234ВВ`.
+pub fn canonical_number_plate(number_plate: &str) -> String {
+    number_plate
+        .trim()
+        .to_uppercase()
+        .chars()
+        .filter(|char| !char.is_whitespace() && *char != '-')
+        .map(|char| {
+            LATIN_TO_CYRILLIC
+                .iter()
+                .find(|(latin, _)| *latin == char)
+                .map(|(_, cyrillic)| *cyrillic)
+                .unwrap_or(char)
+        })
+        .collect()
+}
+
+/// Uppercases the VIN code, removes separators and replaces Cyrillic look-alike
+/// letters with the Latin ones.
+pub fn canonical_vin_code(vin_code: &str) -> String {
+    vin_code
+        .trim()
+        .to_uppercase()
+        .chars()
+        .filter(|char| !char.is_whitespace() && *char != '-')
+        .map(|char| {
+            LATIN_TO_CYRILLIC
+                .iter()
+                .find(|(_, cyrillic)| *cyrillic == char)
+                .map(|(latin, _)| *latin)
+                .unwrap_or(char)
+        })
+        .collect()
+}
+
+/// Shapes of the Ukrainian number plates, `L` stands for a letter and `D` for a digit:
+/// the civil `АН1234АС`, the military `1234А5` and the pre-2004 civil `12345АВ` plates.
+const UKRAINIAN_NUMBER_PLATE_SHAPES: [&str; 3] = ["LLDDDDLL", "DDDDLD", "DDDDDLL"];
+const FOREIGN_NUMBER_PLATE_MIN_LENGTH: usize = 4;
+const FOREIGN_NUMBER_PLATE_MAX_LENGTH: usize = 10;
+
+/// Validates the number plate. A plate of a Ukrainian shape may only use the letters of
+/// the Ukrainian plates, any other plate is taken for a foreign one: 4 to 10 Latin letters
+/// and digits with at least one of each.
+pub fn valid_number_plate(number_plate: &str) -> Result<(), ValidationError> {
+    let number_plate = canonical_number_plate(number_plate);
+    let shape = number_plate
+        .chars()
+        .map(|char| if char.is_ascii_digit() { 'D' } else { 'L' })
+        .collect::<String>();
+    let letters_valid = |valid: fn(char) -> bool| {
+        number_plate
+            .chars()
+            .filter(|char| !char.is_ascii_digit())
+            .all(valid)
+    };
+
+    if UKRAINIAN_NUMBER_PLATE_SHAPES.contains(&shape.as_str()) {
+        if !letters_valid(|char| NUMBER_PLATE_LETTERS.contains(char)) {
+            return Err(ValidationError::new("error-gate-pass-number-plate-invalid-letters"));
+        }
+        return Ok(());
+    }
+
+    let length = shape.len();
+    if !(FOREIGN_NUMBER_PLATE_MIN_LENGTH..=FOREIGN_NUMBER_PLATE_MAX_LENGTH).contains(&length)
+        || !shape.contains('D')
+        || !shape.contains('L')
+    {
+        return Err(ValidationError::new("error-gate-pass-number-plate-invalid"));
+    }
+    // the Latin look-alikes are already replaced with the Cyrillic letters
+    if !letters_valid(|char| char.is_ascii_uppercase() || NUMBER_PLATE_LETTERS.contains(char)) {
+        return Err(ValidationError::new("error-gate-pass-number-plate-invalid-letters"));
+    }
+    Ok(())
+}
+
+/// Validates the VIN code according to ISO 3779: 17 characters without `I`, `O` and `Q`,
+/// and the check digit in the 9th position. A blank VIN code is treated as absent.
+pub fn valid_vin_code(vin_code: &str) -> Result<(), ValidationError> {
+    let vin_code = canonical_vin_code(vin_code);
+    if vin_code.is_empty() {
+        return Ok(());
+    }
+    if vin_code.chars().count() != VIN_CODE_LENGTH {
+        return Err(ValidationError::new("error-gate-pass-vin-code-invalid-length"));
+    }
+    let Some(values) = vin_code
+        .chars()
+        .map(vin_code_char_value)
+        .collect::<Option<Vec<u32>>>()
+    else {
+        return Err(ValidationError::new("error-gate-pass-vin-code-invalid-characters"));
+    };
+    let remainder = values
+        .iter()
+        .zip(VIN_CODE_WEIGHTS.iter())
+        .map(|(value, weight)| value * weight)
+        .sum::<u32>()
+        % 11;
+    let check_digit = if remainder == 10 {
+        'X'
+    } else {
+        char::from_digit(remainder, 10).unwrap_or_default()
+    };
+    if vin_code.chars().nth(VIN_CODE_CHECK_DIGIT_INDEX) != Some(check_digit) {
+        return Err(ValidationError::new("error-gate-pass-vin-code-invalid-check-digit"));
+    }
+    Ok(())
+}
+
+fn vin_code_char_value(char: char) -> Option<u32> {
+    match char {
+        '0'..='9' => char.to_digit(10),
+        'A' | 'J' => Some(1),
+        'B' | 'K' | 'S' => Some(2),
+        'C' | 'L' | 'T' => Some(3),
+        'D' | 'M' | 'U' => Some(4),
+        'E' | 'N' | 'V' => Some(5),
+        'F' | 'W' => Some(6),
+        'G' | 'P' | 'X' => Some(7),
+        'H' | 'Y' => Some(8),
+        'R' | 'Z' => Some(9),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_number_plate_replaces_latin_letters() {
+        assert_eq!(canonical_number_plate("aa 1234-bb"), "АА1234ВВ");
+        assert_eq!(canonical_number_plate("1234 a5"), "1234А5");
+    }
+
+    #[test]
+    fn valid_number_plate_checks_ukrainian_letters() {
+        assert!(valid_number_plate("AA1234BB").is_ok());
+        assert!(valid_number_plate("1234 А5").is_ok());
+        assert!(valid_number_plate("ZZ1234ZZ").is_err());
+        assert!(valid_number_plate("A1").is_err());
+        assert!(valid_number_plate("AB 12*4").is_err());
+        assert!(valid_number_plate("W123ABC").is_ok());
+        assert!(valid_number_plate("12345 ab").is_ok());
+    }
+
+    #[test]
+    fn valid_number_plate_rejects_other_strings() {
+        assert!(valid_number_plate("ABCDEF").is_err());
+        assert!(valid_number_plate("123456").is_err());
+        assert!(valid_number_plate("W12").is_err());
+        assert!(valid_number_plate("W1234567890").is_err());
+        assert!(valid_number_plate("ЖЖ1234").is_err());
+        assert!(valid_number_plate("ÄB1234").is_err());
+    }
+
+    #[test]
+    fn valid_vin_code_checks_length_characters_and_check_digit() {
+        assert!(valid_vin_code("1M8GDM9AXKP042788").is_ok());
+        assert!(valid_vin_code("1m8gdm9axkp042788").is_ok());
+        assert!(valid_vin_code("").is_ok());
+        assert!(valid_vin_code("1M8GDM9AXKP04278").is_err());
+        assert!(valid_vin_code("1M8GDM9AXKP04278O").is_err());
+        assert!(valid_vin_code("1M8GDM9A1KP042788").is_err());
+    }
+}
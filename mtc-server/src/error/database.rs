@@ -8,6 +8,8 @@ pub enum DatabaseError {
     EntryAlreadyExists,
     #[error("error-db-not-found")]
     EntryNotFound,
+    #[error("error-db-in-use")]
+    EntryInUse,
 }
 
 impl IntoResponse for DatabaseError {
@@ -15,7 +17,8 @@ impl IntoResponse for DatabaseError {
         let status_code = match self {
             DatabaseError::SomethingWentWrong => StatusCode::INTERNAL_SERVER_ERROR,
             DatabaseError::EntryAlreadyExists
-            | DatabaseError::EntryNotFound => StatusCode::CONFLICT,
+            | DatabaseError::EntryNotFound
+            | DatabaseError::EntryInUse => StatusCode::CONFLICT,
         };
 
         (status_code, Json(json!({ "message": self.to_string() }))).into_response()
@@ -10,13 +10,21 @@ pub enum GenericError {
     BadRequest,
     #[error("error-unsupported-media-type")]
     UnsupportedMediaType,
+    #[error("error-report-font-missing")]
+    ReportFontMissing,
+    #[error("error-gate-pass-device-key-missing")]
+    GatePassDeviceKeyMissing,
 }
 
 impl IntoResponse for GenericError {
     fn into_response(self) -> Response {
         let status_code = match self {
-            GenericError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
-            GenericError::ConflictError => StatusCode::CONFLICT,
+            GenericError::InternalError | GenericError::ReportFontMissing => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            GenericError::ConflictError | GenericError::GatePassDeviceKeyMissing => {
+                StatusCode::CONFLICT
+            }
             GenericError::BadRequest => StatusCode::BAD_REQUEST,
             GenericError::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
         };
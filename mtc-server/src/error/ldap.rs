@@ -0,0 +1,7 @@
+#[derive(thiserror::Error, Debug)]
+pub enum LdapError {
+    #[error("LDAP error: {0}")]
+    DirectoryError(#[from] ldap3::LdapError),
+    #[error("LDAP directory is not configured")]
+    NotConfigured,
+}
@@ -5,6 +5,7 @@ mod generic;
 mod database;
 mod session;
 mod smtp;
+mod ldap;
 
 pub(crate) mod prelude {
     pub(crate) use super::{
@@ -12,6 +13,7 @@ pub(crate) mod prelude {
         generic::*,
         session::*,
         smtp::*,
+        ldap::*,
         Error,
     };
 }
@@ -29,6 +31,8 @@ pub enum Error {
     SurrealDbError(#[from] surrealdb::Error),
     #[error[transparent]]
     SmtpError(#[from] SmtpError),
+    #[error[transparent]]
+    LdapError(#[from] LdapError),
     #[error(transparent)]
     ValidationError(#[from] ValidationErrors),
     #[error[transparent]]
@@ -55,7 +59,11 @@ impl IntoResponse for Error {
                 error!("{error}");
                 (
                     StatusCode::BAD_REQUEST,
-                    Json(json!({ "message": "error-bad-request", "errors": error.to_string() })),
+                    Json(json!({
+                        "message": "error-bad-request",
+                        "errors": error.to_string(),
+                        "field_errors": error
+                    })),
                 ).into_response()
             }
             Error::MultipartError(..)
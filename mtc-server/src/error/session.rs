@@ -10,15 +10,30 @@ pub enum SessionError {
     UserBlocked,
     #[error("error-password-hash")]
     PasswordHash,
+    #[error("error-device-revoked")]
+    DeviceRevoked,
+    #[error("error-two-factor-code-invalid")]
+    TwoFactorCodeInvalid,
+    #[error("error-two-factor-enforced")]
+    TwoFactorEnforced,
+    #[error("error-sign-in-locked")]
+    SignInLocked,
+    #[error("error-password-reused")]
+    PasswordReused,
 }
 
 impl IntoResponse for SessionError {
     fn into_response(self) -> Response {
         let status_code = match self {
-            SessionError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            SessionError::InvalidCredentials
+            | SessionError::TwoFactorCodeInvalid => StatusCode::UNAUTHORIZED,
             SessionError::PasswordHash => StatusCode::INTERNAL_SERVER_ERROR,
             SessionError::UserBlocked
-            | SessionError::AccessForbidden => StatusCode::FORBIDDEN,
+            | SessionError::AccessForbidden
+            | SessionError::TwoFactorEnforced => StatusCode::FORBIDDEN,
+            SessionError::DeviceRevoked => StatusCode::GONE,
+            SessionError::SignInLocked => StatusCode::TOO_MANY_REQUESTS,
+            SessionError::PasswordReused => StatusCode::BAD_REQUEST,
         };
 
         (status_code, Json(json!({ "message": self.to_string() }))).into_response()
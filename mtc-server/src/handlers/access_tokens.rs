@@ -0,0 +1,80 @@
+use super::*;
+use validator::Validate;
+
+#[handler]
+pub async fn find_own_access_tokens_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    state.repository.find_access_tokens(auth_state.id).await.map(Json)
+}
+
+/// Creates the personal access token, the token value is returned only once.
+#[handler]
+pub async fn create_own_access_token_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(request): Payload<AccessTokenRequest>,
+) {
+    request.validate()?;
+
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    if request.expires_in_days > state.config.security.access_token_max_expiration {
+        Err(GenericError::BadRequest)?
+    }
+    if !request.permissions.iter().all(|permission| auth_state.has_permission(permission)) {
+        Err(GenericError::BadRequest)?
+    }
+
+    let (secret, secret_digest) = access_token_secret();
+    let access_token = state.repository.create_access_token(
+        auth_state.id,
+        request.name,
+        secret_digest,
+        request.permissions,
+        request.expires_in_days,
+    ).await?;
+
+    security_audit(
+        &state,
+        SecurityAuditKind::AccessTokenCreate,
+        &auth_state.login,
+        &access_token.name,
+        Some(json!({
+            "id": access_token.id,
+            "permissions": access_token.permissions,
+            "expires_at": access_token.expires_at,
+        })),
+    ).await;
+
+    Ok(Json(AccessTokenCreated {
+        token: access_token_value(&access_token.id, &secret).into(),
+        access_token,
+    }))
+}
+
+#[handler]
+pub async fn revoke_own_access_token_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(id): Path<Cow<'static, str>>,
+) {
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    state.repository.revoke_access_tokens(auth_state.id, Some(id.clone())).await?;
+    security_audit(
+        &state,
+        SecurityAuditKind::AccessTokenRevoke,
+        &auth_state.login,
+        &auth_state.login,
+        Some(json!({"id": id})),
+    ).await;
+
+    Ok(())
+}
@@ -1,15 +1,20 @@
 use super::*;
+use validator::Validate;
 
-#[handler(result)]
+#[handler]
 pub async fn sign_in_handler(
     state: State<Arc<AppState>>,
     session: Session,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Payload(payload): Payload<Value>,
 ) {
-    let api_key = payload.key_str("api_key").unwrap_or_default();
-    if !api_key.is_empty() {
+    let ip = client_ip(&state.config, &headers, &address);
+
+    let qr_token = payload.key_str("qr_token").unwrap_or_default();
+    if !qr_token.is_empty() {
         let re = regex::Regex::new(UUID_PATTERN).unwrap();
-        if !re.is_match(&api_key) {
+        if !re.is_match(&qr_token) {
             Err(GenericError::BadRequest)?
         }
     }
@@ -20,7 +25,28 @@ pub async fn sign_in_handler(
 
     if !login.is_empty() & password.is_empty() { Err(GenericError::BadRequest)? }
 
-    let Ok(user) = (if !login.is_empty() {
+    if state.repository.is_sign_in_locked(login.clone(), ip.clone()).await? {
+        Err(SessionError::SignInLocked)?
+    }
+
+    // local accounts are always checked locally, the others go to the directory when set
+    let directory_user = !login.is_empty()
+        && state.config.ldap.is_enabled()
+        && state
+            .repository
+            .find_user_ldap_dn(login.clone())
+            .await?
+            .is_none_or(|ldap_dn| !ldap_dn.is_empty());
+
+    let Ok(user) = (if directory_user {
+        match ldap_sign_in(&state, &login, &password).await? {
+            Some(user_id) => state
+                .repository
+                .find_user(user_id, Access::administrator())
+                .await,
+            None => Err(DatabaseError::EntryNotFound.into()),
+        }
+    } else if !login.is_empty() {
         state
             .repository
             .find_user_by_login(
@@ -28,65 +54,151 @@ pub async fn sign_in_handler(
                 Access::administrator(),
             ).await
     } else {
-        state
-            .repository
-            .find_user_by_api_key(
-                api_key.clone()
-            ).await
-    }) else { Err(SessionError::InvalidCredentials)? };
+        match state.repository.consume_qr_sign_in_token(qr_token.clone()).await? {
+            Some(user_id) => state
+                .repository
+                .find_user(user_id, Access::administrator())
+                .await,
+            None => Err(DatabaseError::EntryNotFound.into()),
+        }
+    }) else {
+        sign_in_failure(&state, &login, &ip).await;
+        Err(SessionError::InvalidCredentials)?
+    };
 
     if user.blocked { Err(SessionError::UserBlocked)? }
 
-    if !password.is_empty() {
-        let argon2 = Argon2::default();
-
-        let Ok(password_hash) = PasswordHash::new(&user.password) else {
-            Err(SessionError::PasswordHash)?
-        };
+    if !password.is_empty() && !directory_user {
+        match password_verify(&state.config, &password, &user.password)? {
+            PasswordCheck::Invalid => {
+                sign_in_failure(&state, &login, &ip).await;
+                Err(SessionError::InvalidCredentials)?
+            }
+            PasswordCheck::ValidOutdated => {
+                let password_hash = password_hash(&state.config, &password)?;
+                state.repository.set_user_password(user.id.clone(), password_hash).await?;
+                info!("User password rehashed: login={login}");
+            }
+            PasswordCheck::Valid => {}
+        }
 
-        if argon2
-            .verify_password(password.as_bytes(), &password_hash)
-            .is_err() { Err(SessionError::InvalidCredentials)? }
+        // the one-time password set by the administrator signs in once, the change follows
+        if !state.repository.use_one_time_password(user.id.clone()).await? {
+            warn!("Used one-time password rejected: login={login}");
+            sign_in_failure(&state, &login, &ip).await;
+            Err(SessionError::InvalidCredentials)?
+        }
     }
 
-    let user_state = state.repository.find_user_state(user.id.clone()).await?;
+    session.remove(SESSION_TWO_FACTOR_KEY);
+    session.remove(SESSION_TWO_FACTOR_SECRET_KEY);
+    session.remove(SESSION_PASSWORD_CHANGE_KEY);
 
-    let auth_state = AuthState::from(user_state.clone());
+    if !login.is_empty() {
+        let two_factor_step = match state.repository.find_user_two_factor(user.id.clone()).await? {
+            Some(_) => Some(TwoFactorStep::Verify),
+            None => state
+                .repository
+                .is_user_two_factor_required(user.id.clone())
+                .await?
+                .then_some(TwoFactorStep::Enroll),
+        };
 
-    let access = auth_state.is_admin()
-        .then(|| Access::administrator())
-        .unwrap_or(Access::from(user_state));
+        if let Some(step) = two_factor_step {
+            session.set(SESSION_TWO_FACTOR_KEY, TwoFactorPending {
+                user_id: user.id,
+                login,
+                step,
+            });
+            return Ok(Json(SignInResponse { two_factor: Some(step), ..Default::default() }));
+        }
+    }
 
-    state.repository.increment_user_access_count(login).await?;
-    session.set_state(&auth_state, &access).await?;
+    let response = sign_in_user(&state, &session, user.id.clone(), login).await?;
 
-    (!api_key.is_empty()).then(|| async {
-        state.repository.update_user_api_key(
+    if !qr_token.is_empty() {
+        state.repository.create_user_device(
             user.id,
-            api_key,
             session.get_session_id().to_string(),
+            device_id(&headers).ok(),
             payload.key_str("os").unwrap_or_default(),
             payload.key_str("device").unwrap_or_default(),
-        ).await.ok()
-    });
+            state.config.security.device_key_expiration,
+        ).await?;
+        security_audit(
+            &state,
+            SecurityAuditKind::DeviceLink,
+            &user.login,
+            &user.login,
+            Some(json!({
+                "os": payload.key_str("os").unwrap_or_default(),
+                "device": payload.key_str("device").unwrap_or_default(),
+            })),
+        ).await;
+    }
+
+    Ok(Json(response))
+}
+
+/// Puts the user state into the session once all the sign-in steps are passed,
+/// the user whose password has to be changed is kept pending until the change.
+async fn sign_in_user(
+    state: &AppState,
+    session: &Session,
+    user_id: Cow<'static, str>,
+    login: Cow<'static, str>,
+) -> Result<SignInResponse> {
+    if !login.is_empty() {
+        state.repository.reset_sign_in_failures(login.clone()).await?;
+
+        if state
+            .repository
+            .is_user_password_change_required(
+                user_id.clone(),
+                state.config.security.password_expiration,
+            ).await? {
+            session.set(SESSION_PASSWORD_CHANGE_KEY, PasswordChangePending { user_id, login });
+            return Ok(SignInResponse { password_change_required: true, ..Default::default() });
+        }
+    }
+
+    let (auth_state, access) = session_user_state(state, user_id.clone()).await?;
+
+    state.repository.increment_user_access_count(login).await?;
+    session.set_state(&auth_state, &access).await?;
+    security_audit(state, SecurityAuditKind::SignIn, &auth_state.login, &auth_state.login, None)
+        .await;
+    state.repository.create_user_session(
+        session.get_session_id().to_string(),
+        user_id,
+        auth_state.login,
+        state.config.security.session_expiration,
+    ).await?;
+
+    Ok(SignInResponse::default())
 }
 
 #[handler(result)]
 pub async fn sign_out_handler(
+    state: State<Arc<AppState>>,
     session: Session,
 ) -> Result<impl IntoResponse> {
+    let login = session.get_auth_login().await?;
+    state.repository.delete_user_session(session.get_session_id().to_string()).await?;
+    if !login.is_empty() {
+        security_audit(&state, SecurityAuditKind::SignOut, &login, &login, None).await;
+    }
     session.clear();
 }
 
+/// Changes the password of the signed-in user or of the user whose sign-in waits
+/// for the password change, the latter is signed in afterwards.
 #[handler]
 pub async fn change_password_handler(
     state: State<Arc<AppState>>,
     session: Session,
     Payload(payload): Payload<Value>,
 ) {
-    let auth_state = session.get_auth_state().await?;
-    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
-
     let (Some(current_password), Some(new_password)) = (
         payload.key_str("current_password"),
         payload.key_str("new_password"),
@@ -94,30 +206,45 @@ pub async fn change_password_handler(
         Err(GenericError::BadRequest)?
     };
 
+    let auth_state = session.get_auth_state().await?;
+    let pending = session.get::<PasswordChangePending>(SESSION_PASSWORD_CHANGE_KEY);
+    let (user_id, login) = match (auth_state.is_authenticated(), pending) {
+        (true, _) => (auth_state.id.clone(), auth_state.login.clone()),
+        (false, Some(pending)) => (pending.user_id, pending.login),
+        (false, None) => Err(SessionError::AccessForbidden)?,
+    };
+
     let Ok(user) = state
         .repository
-        .find_user(auth_state.id, Access{ level: -1, full: true })
+        .find_user(user_id, Access{ level: -1, full: true })
         .await else { Err(SessionError::InvalidCredentials)? };
 
     if user.blocked { Err(SessionError::UserBlocked)? }
 
-    let argon2 = Argon2::default();
-
-    let Ok(password_hash) = PasswordHash::new(&current_password) else {
-        Err(SessionError::PasswordHash)?
-    };
-    if argon2.verify_password(current_password.as_bytes(), &password_hash).is_err() {
+    if password_verify(&state.config, &current_password, &user.password)? == PasswordCheck::Invalid {
         Err(SessionError::InvalidCredentials)?
     }
+    if current_password == new_password { Err(SessionError::PasswordReused)? }
+    password_check_policy(&state.config, &new_password)?;
 
-    let Ok(salt) = SaltString::from_b64(&state.config.security.password_salt) else {
-        Err(SessionError::PasswordHash)?
-    };
-    let Ok(password_hash) = argon2.hash_password(new_password.as_bytes(), &salt) else {
-        Err(SessionError::PasswordHash)?
-    };
+    let password_hash = password_hash(&state.config, &new_password)?;
+    state.repository.change_user_password(user.id.clone(), password_hash, false).await?;
+    info!("User password changed: login={login}");
+    security_audit(&state, SecurityAuditKind::PasswordChange, &login, &login, None).await;
+
+    if !auth_state.is_authenticated() {
+        session.remove(SESSION_PASSWORD_CHANGE_KEY);
+        return sign_in_user(&state, &session, user.id, login).await.map(Json);
+    }
 
-    state.repository.set_user_password(user.id, password_hash.to_string()).await
+    Ok(Json(SignInResponse::default()))
+}
+
+#[handler]
+pub async fn find_password_policy_handler(
+    state: State<Arc<AppState>>,
+) {
+    Ok(Json(password_policy(&state.config)))
 }
 
 #[handler]
@@ -128,15 +255,21 @@ pub async fn sign_in_qr_code_handler(
     let auth_state = session.get_auth_state().await?;
     if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? };
 
-    let mcrypt = new_magic_crypt!(env!("CRYPT_KEY"), 256);
-    let api_key = state
+    let qr_token = uuid::Uuid::new_v4().to_string();
+    state
         .repository
-        .find_api_key_by_user_id(session.get_auth_id().await?)
+        .create_qr_sign_in_token(
+            session.get_auth_id().await?,
+            qr_token.clone(),
+            state.config.security.qr_sign_in_token_expiration,
+        )
         .await?;
-    let encrypted_api_key = mcrypt.encrypt_str_to_base64(api_key);
+
+    let mcrypt = new_magic_crypt!(env!("CRYPT_KEY"), 256);
+    let encrypted_qr_token = mcrypt.encrypt_str_to_base64(qr_token);
     let qr_str = format!(
         "MTC:000:{}",
-        encrypted_api_key
+        encrypted_qr_token
     );
 
     let svg = qrcode_generator::to_svg_to_string(
@@ -146,3 +279,250 @@ pub async fn sign_in_qr_code_handler(
 
     Ok(([("content-type", "image/svg+xml")], svg))
 }
+
+/// Second sign-in step, accepts a TOTP or a recovery code of the pending sign-in.
+#[handler]
+pub async fn verify_two_factor_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Payload(mut request): Payload<TwoFactorCodeRequest>,
+) {
+    request.validate()?;
+    request.normalize();
+
+    let Some(pending) = session
+        .get::<TwoFactorPending>(SESSION_TWO_FACTOR_KEY)
+        .filter(|pending| pending.step == TwoFactorStep::Verify) else {
+        Err(SessionError::AccessForbidden)?
+    };
+
+    let ip = client_ip(&state.config, &headers, &address);
+    if state.repository.is_sign_in_locked(pending.login.clone(), ip.clone()).await? {
+        session.remove(SESSION_TWO_FACTOR_KEY);
+        Err(SessionError::SignInLocked)?
+    }
+
+    let Some(mut two_factor) = state
+        .repository
+        .find_user_two_factor(pending.user_id.clone())
+        .await? else { Err(SessionError::AccessForbidden)? };
+
+    // the wrong codes count towards the lockout of the login, whichever session they come from
+    if !two_factor_verify(&state.config, &mut two_factor, &pending.login, &request.code)? {
+        warn!("Invalid two-factor code: login={}", pending.login);
+        sign_in_failure(&state, &pending.login, &ip).await;
+
+        if state.repository.is_sign_in_locked(pending.login.clone(), ip).await? {
+            session.remove(SESSION_TWO_FACTOR_KEY);
+            Err(SessionError::SignInLocked)?
+        }
+        Err(SessionError::TwoFactorCodeInvalid)?
+    }
+
+    session.remove(SESSION_TWO_FACTOR_KEY);
+    state.repository.update_user_two_factor(pending.user_id.clone(), two_factor).await?;
+    sign_in_user(&state, &session, pending.user_id, pending.login).await.map(Json)
+}
+
+/// Returns the user enrolling the two-factor: the signed-in user or the one whose
+/// sign-in waits for the enrollment required by the roles, the latter is flagged.
+async fn two_factor_subject(
+    session: &Session,
+) -> Result<(Cow<'static, str>, Cow<'static, str>, bool)> {
+    let auth_state = session.get_auth_state().await?;
+    if auth_state.is_authenticated() {
+        return Ok((auth_state.id, auth_state.login, false));
+    }
+
+    match session
+        .get::<TwoFactorPending>(SESSION_TWO_FACTOR_KEY)
+        .filter(|pending| pending.step == TwoFactorStep::Enroll) {
+        Some(pending) => Ok((pending.user_id, pending.login, true)),
+        None => Err(SessionError::AccessForbidden)?,
+    }
+}
+
+#[handler]
+pub async fn find_two_factor_status_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    let two_factor = state.repository.find_user_two_factor(auth_state.id.clone()).await?;
+    let required = state.repository.is_user_two_factor_required(auth_state.id).await?;
+
+    Ok(Json(TwoFactorStatus {
+        enabled: two_factor.is_some(),
+        required,
+        recovery_codes_left: two_factor
+            .map(|two_factor| two_factor.recovery_codes.len())
+            .unwrap_or_default(),
+    }))
+}
+
+/// Generates a new TOTP secret, it is kept in the session until confirmed.
+#[handler]
+pub async fn create_two_factor_setup_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let (user_id, login, _) = two_factor_subject(&session).await?;
+
+    if state.repository.find_user_two_factor(user_id).await?.is_some() {
+        Err(GenericError::ConflictError)?
+    }
+
+    let setup = two_factor_setup(&login)?;
+    session.set(SESSION_TWO_FACTOR_SECRET_KEY, &setup.secret);
+
+    Ok(Json(setup))
+}
+
+/// Enables the two-factor with the pending secret once the first code matches,
+/// the recovery codes are returned only here.
+#[handler]
+pub async fn confirm_two_factor_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<TwoFactorCodeRequest>,
+) {
+    request.validate()?;
+    request.normalize();
+
+    let (user_id, login, enrolling) = two_factor_subject(&session).await?;
+
+    if state.repository.find_user_two_factor(user_id.clone()).await?.is_some() {
+        Err(GenericError::ConflictError)?
+    }
+
+    let Some(secret) = session.get::<String>(SESSION_TWO_FACTOR_SECRET_KEY) else {
+        Err(GenericError::BadRequest)?
+    };
+
+    let Some(last_step) = two_factor_check_code(&secret, &login, &request.code, 0)? else {
+        Err(SessionError::TwoFactorCodeInvalid)?
+    };
+
+    let (recovery_codes, recovery_code_hashes) = two_factor_recovery_codes(&state.config)?;
+
+    state.repository.update_user_two_factor(
+        user_id.clone(),
+        UserTwoFactor {
+            secret: two_factor_encrypt_secret(&state.config, &secret)?,
+            recovery_codes: recovery_code_hashes,
+            last_step,
+        },
+    ).await?;
+    session.remove(SESSION_TWO_FACTOR_SECRET_KEY);
+    info!("User two-factor enabled: login={login}");
+    security_audit(&state, SecurityAuditKind::TwoFactorEnable, &login, &login, None).await;
+
+    let mut password_change_required = false;
+    if enrolling {
+        session.remove(SESSION_TWO_FACTOR_KEY);
+        password_change_required = sign_in_user(&state, &session, user_id, login)
+            .await?
+            .password_change_required;
+    }
+
+    Ok(Json(TwoFactorRecoveryCodes { recovery_codes, password_change_required }))
+}
+
+/// Replaces the recovery codes of the signed-in user.
+#[handler]
+pub async fn create_two_factor_recovery_codes_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<TwoFactorCodeRequest>,
+) {
+    request.validate()?;
+    request.normalize();
+
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    let Some(mut two_factor) = state
+        .repository
+        .find_user_two_factor(auth_state.id.clone())
+        .await? else { Err(GenericError::BadRequest)? };
+
+    if !two_factor_verify(&state.config, &mut two_factor, &auth_state.login, &request.code)? {
+        Err(SessionError::TwoFactorCodeInvalid)?
+    }
+
+    let (recovery_codes, recovery_code_hashes) = two_factor_recovery_codes(&state.config)?;
+    two_factor.recovery_codes = recovery_code_hashes;
+    state.repository.update_user_two_factor(auth_state.id, two_factor).await?;
+
+    Ok(Json(TwoFactorRecoveryCodes { recovery_codes, ..Default::default() }))
+}
+
+/// Disables the two-factor of the signed-in user unless the roles require it.
+#[handler]
+pub async fn delete_two_factor_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<TwoFactorCodeRequest>,
+) {
+    request.validate()?;
+    request.normalize();
+
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    if state.repository.is_user_two_factor_required(auth_state.id.clone()).await? {
+        Err(SessionError::TwoFactorEnforced)?
+    }
+
+    let Some(mut two_factor) = state
+        .repository
+        .find_user_two_factor(auth_state.id.clone())
+        .await? else { Err(GenericError::BadRequest)? };
+
+    if !two_factor_verify(&state.config, &mut two_factor, &auth_state.login, &request.code)? {
+        Err(SessionError::TwoFactorCodeInvalid)?
+    }
+
+    state.repository.delete_user_two_factor(auth_state.id, auth_state.login.clone()).await?;
+    security_audit(
+        &state,
+        SecurityAuditKind::TwoFactorDisable,
+        &auth_state.login,
+        &auth_state.login,
+        None,
+    ).await;
+
+    Ok(())
+}
+
+#[handler(permission = "users::read")]
+pub async fn find_sign_in_lockouts_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    state.repository.find_sign_in_lockouts().await.map(Json)
+}
+
+/// Lifts the lockout of the login or the client IP before it expires.
+#[handler(permission = "users::write")]
+pub async fn unlock_sign_in_lockout_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(lockout_id): Path<Cow<'static, str>>,
+) {
+    let by = session.get_auth_login().await?;
+    let lockout = state.repository.unlock_sign_in_lockout(lockout_id, by.clone()).await?;
+    security_audit(
+        &state,
+        SecurityAuditKind::SignInUnlock,
+        &by,
+        &lockout.key,
+        Some(json!({ "kind": lockout.kind })),
+    ).await;
+
+    Ok(Json(lockout))
+}
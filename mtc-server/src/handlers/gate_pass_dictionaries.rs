@@ -0,0 +1,66 @@
+use super::*;
+use server_macros::handler;
+use validator::Validate;
+
+#[handler(permission = "gate_passes::read")]
+pub async fn find_gate_pass_dictionaries_handler(state: State<Arc<AppState>>, session: Session) {
+    info!("Received get Gate Pass dictionaries request");
+    state
+        .repository
+        .find_gate_pass_dictionaries()
+        .await
+        .map(Json)
+}
+
+#[handler(permission = "gate_passes::dictionaries")]
+pub async fn create_gate_pass_dictionary_entry_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<GatePassDictionaryEntryRequest>,
+) {
+    info!(
+        "Received create Gate Pass dictionary entry request: kind={:?}, code={}",
+        request.kind, request.code
+    );
+    let user_login = session.get_auth_login().await?;
+    request.validate()?;
+    request.created_by = Some(user_login.clone());
+    request.updated_by = Some(user_login);
+    state
+        .repository
+        .create_gate_pass_dictionary_entry(request)
+        .await
+        .map(Json)
+}
+
+#[handler(permission = "gate_passes::dictionaries")]
+pub async fn update_gate_pass_dictionary_entry_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(entry_id): Path<Cow<'static, str>>,
+    Payload(mut request): Payload<GatePassDictionaryEntryRequest>,
+) {
+    info!("Received update Gate Pass dictionary entry request: entry_id={entry_id}");
+    let user_login = session.get_auth_login().await?;
+    request.validate()?;
+    request.updated_by = Some(user_login);
+    state
+        .repository
+        .update_gate_pass_dictionary_entry(entry_id, request)
+        .await
+        .map(Json)
+}
+
+#[handler(permission = "gate_passes::dictionaries")]
+pub async fn delete_gate_pass_dictionary_entry_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(entry_id): Path<Cow<'static, str>>,
+) {
+    info!("Received delete Gate Pass dictionary entry request: entry_id={entry_id}");
+    state
+        .repository
+        .delete_gate_pass_dictionary_entry(entry_id)
+        .await
+        .map(Json)
+}
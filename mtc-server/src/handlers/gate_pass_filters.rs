@@ -0,0 +1,45 @@
+use super::*;
+use server_macros::handler;
+use validator::Validate;
+
+#[handler(permission = "gate_passes::read")]
+pub async fn find_gate_pass_filters_handler(state: State<Arc<AppState>>, session: Session) {
+    info!("Received get Gate Pass filters request");
+    state.repository.find_gate_pass_filters().await.map(Json)
+}
+
+#[handler(permission = "gate_passes::write")]
+pub async fn create_gate_pass_filter_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<GatePassFilterRequest>,
+) {
+    info!(
+        "Received create Gate Pass filter request: title={}",
+        request.title
+    );
+    let user_login = session.get_auth_login().await?;
+    request.normalize();
+    request.validate()?;
+    request.created_by = Some(user_login.clone());
+    request.updated_by = Some(user_login);
+    state
+        .repository
+        .create_gate_pass_filter(request)
+        .await
+        .map(Json)
+}
+
+#[handler(permission = "gate_passes::write")]
+pub async fn delete_gate_pass_filter_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(filter_id): Path<Cow<'static, str>>,
+) {
+    info!("Received delete Gate Pass filter request: filter_id={filter_id}");
+    state
+        .repository
+        .delete_gate_pass_filter(filter_id)
+        .await
+        .map(Json)
+}
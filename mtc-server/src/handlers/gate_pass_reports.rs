@@ -0,0 +1,46 @@
+use super::*;
+use axum::body::Body;
+use axum::http::header::CONTENT_DISPOSITION;
+use chrono::Utc;
+use server_macros::handler;
+use validator::Validate;
+
+#[handler(permission = "gate_passes::read")]
+pub async fn create_gate_pass_report_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(request): Payload<GatePassReportRequest>,
+) {
+    info!(
+        "Received Gate Pass report request: kind={:?}, format={:?}",
+        request.kind, request.format
+    );
+    request.validate()?;
+    let mut search_request = request.filter.clone().unwrap_or_default();
+    search_request.page_request = Some(PageRequest::all());
+    search_request.normalize();
+    let gate_passes = state
+        .repository
+        .search_gate_passes(search_request)
+        .await?
+        .page_rows;
+    let dictionaries = state.repository.find_gate_pass_dictionaries().await?;
+    let report = gate_pass_report(&request, gate_passes, &dictionaries, Utc::now());
+    let content = match request.format {
+        GatePassReportFormat::Xlsx => gate_pass_report_xlsx(&report)?,
+        GatePassReportFormat::Pdf => {
+            gate_pass_report_pdf(&report, &state.config.paths.report_font_path)?
+        }
+    };
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, request.format.content_type())
+        .header(
+            CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"gate_passes.{}\"",
+                request.format.extension()
+            ),
+        )
+        .body(Body::from(content))
+        .unwrap())
+}
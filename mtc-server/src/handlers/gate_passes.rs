@@ -1,5 +1,6 @@
 use super::*;
 use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
 use server_macros::handler;
 use validator::Validate;
 
@@ -18,6 +19,16 @@ pub async fn create_gate_pass_handler(
     let user_login = session.get_auth_login().await?;
     request.validate()?;
     request.normalize();
+    state
+        .repository
+        .find_gate_pass_policy()
+        .await?
+        .check_create_request(&request)?;
+    state
+        .repository
+        .find_gate_pass_dictionaries()
+        .await?
+        .check_create_request(&request)?;
     request.created_by = Some(user_login.clone());
     request.updated_by = Some(user_login);
     state.repository.create_gate_pass(request).await.map(Json)
@@ -34,9 +45,13 @@ pub async fn create_gate_passes_handler(
         request.requests.len()
     );
     let user_login = session.get_auth_login().await?;
+    let policy = state.repository.find_gate_pass_policy().await?;
+    let dictionaries = state.repository.find_gate_pass_dictionaries().await?;
     for gate_passes_request in request.requests.iter_mut() {
         gate_passes_request.validate()?;
         gate_passes_request.normalize();
+        policy.check_create_request(gate_passes_request)?;
+        dictionaries.check_create_request(gate_passes_request)?;
         gate_passes_request.created_by = Some(user_login.clone());
         gate_passes_request.updated_by = Some(user_login.clone());
     }
@@ -54,6 +69,16 @@ pub async fn update_gate_pass_handler(
     let user_login = session.get_auth_login().await?;
     request.validate()?;
     request.normalize();
+    state
+        .repository
+        .find_gate_pass_policy()
+        .await?
+        .check_create_request(&request)?;
+    state
+        .repository
+        .find_gate_pass_dictionaries()
+        .await?
+        .check_create_request(&request)?;
     request.id = Some(gate_pass_id);
     request.updated_by = Some(user_login);
     state.repository.update_gate_pass(request).await.map(Json)
@@ -72,6 +97,15 @@ pub async fn update_gate_pass_block_handler(
         info!("Received delete Gate Pass block request: gate_pass_id={gate_pass_id}");
     }
     let user_login = session.get_auth_login().await?;
+    request.normalize();
+    request.validate()?;
+    if let Some(block) = request.block.as_ref() {
+        state
+            .repository
+            .find_gate_pass_dictionaries()
+            .await?
+            .check_block(block)?;
+    }
     request.id = Some(gate_pass_id);
     request.updated_by = Some(user_login);
     state
@@ -81,6 +115,21 @@ pub async fn update_gate_pass_block_handler(
         .map(Json)
 }
 
+#[handler(permission = "gate_passes::write")]
+pub async fn reissue_gate_pass_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(gate_pass_id): Path<Cow<'static, str>>,
+) {
+    info!("Received reissue Gate Pass request: gate_pass_id={gate_pass_id}");
+    let user_login = session.get_auth_login().await?;
+    state
+        .repository
+        .reissue_gate_pass(gate_pass_id, user_login)
+        .await
+        .map(Json)
+}
+
 #[handler(permission = "gate_passes::delete")]
 pub async fn delete_gate_pass_handler(
     state: State<Arc<AppState>>,
@@ -136,9 +185,11 @@ pub async fn search_gate_passes_handler(
 pub async fn find_validation_gate_pass_handler(
     state: State<Arc<AppState>>,
     session: Session,
+    headers: HeaderMap,
     Path(gate_pass_id): Path<Cow<'static, str>>,
 ) {
     info!("Received validate Gate Pass request: gate_pass_id={gate_pass_id}");
+    check_gate_pass_device(&state, &session, &headers).await?;
     state
         .repository
         .find_gate_pass(gate_pass_id)
@@ -152,6 +203,7 @@ pub async fn find_validation_gate_pass_handler(
             vehicles: gate_pass.vehicles,
             allow_any_vehicle: gate_pass.allow_any_vehicle,
             block: gate_pass.block,
+            qr_token: gate_pass.qr_token,
         })
         .map(Json)
 }
@@ -160,31 +212,159 @@ pub async fn find_validation_gate_pass_handler(
 pub async fn find_sync_gate_passes_handler(
     state: State<Arc<AppState>>,
     session: Session,
+    headers: HeaderMap,
     Payload(request): Payload<SyncGatePassRequest>,
 ) {
     info!(
         "Received sync Gate Pass request: last_synced_at={:?}",
         request.last_synced_at
     );
+    check_gate_pass_device(&state, &session, &headers).await?;
     let full_sync = session
         .has_permission(PERMISSION_GATE_PASS_FULL_SYNC)
         .await
         .is_ok();
+    let mut sync_gate_pass_response = state.repository.find_sync_gate_passes(request).await?;
     state
         .repository
-        .find_sync_gate_passes(request)
+        .update_gate_pass_device_synced_at(session.get_session_id().to_string())
+        .await?;
+    if !full_sync {
+        sync_gate_pass_response
+            .gate_passes
+            .iter_mut()
+            .for_each(|gate_pass| {
+                erase_sensitive_data(gate_pass);
+            });
+    }
+    Ok(Json(sync_gate_pass_response))
+}
+
+#[handler(permission = "gate_passes::snapshot")]
+pub async fn export_gate_pass_snapshot_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(device_id): Path<Cow<'static, str>>,
+    Payload(request): Payload<SyncGatePassRequest>,
+) {
+    info!(
+        "Received export Gate Pass snapshot request: device_id={device_id}, last_synced_at={:?}",
+        request.last_synced_at
+    );
+    let user_login = session.get_auth_login().await?;
+    // the snapshot is readable by the linked device it is exported for only
+    let Some(offline_key) = state.repository.find_gate_pass_device_key(device_id).await? else {
+        Err(GenericError::GatePassDeviceKeyMissing)?
+    };
+    let full_sync = session
+        .has_permission(PERMISSION_GATE_PASS_FULL_SYNC)
+        .await
+        .is_ok();
+    let now = Utc::now();
+    let since = request.last_synced_at.clone();
+    let mut last_synced_at = request.last_synced_at;
+    let mut gate_passes = vec![];
+    loop {
+        let sync_gate_pass_response = state
+            .repository
+            .find_sync_gate_passes(SyncGatePassRequest {
+                last_synced_at: last_synced_at.clone(),
+            })
+            .await?;
+        gate_passes.extend(sync_gate_pass_response.gate_passes);
+        let cursor = sync_gate_pass_response.last_synced_at;
+        let finished = last_synced_at.as_ref() == Some(&cursor)
+            || cursor
+                .parse::<DateTime<Utc>>()
+                .is_none_or(|cursor| cursor >= now);
+        last_synced_at = Some(cursor);
+        if finished {
+            break;
+        }
+    }
+    if !full_sync {
+        gate_passes.iter_mut().for_each(erase_sensitive_data);
+    }
+    let snapshot = GatePassSnapshot {
+        created_at: Cow::Owned(now.to_rfc3339()),
+        created_by: user_login,
+        since,
+        last_synced_at: last_synced_at.unwrap_or_default(),
+        gate_passes,
+    };
+    info!(
+        "Gate Pass snapshot exported: number_of_gate_passes={}, last_synced_at={}",
+        snapshot.gate_passes.len(),
+        snapshot.last_synced_at
+    );
+    gate_pass_snapshot_file(&snapshot, &offline_key, &state.config).map(Json)
+}
+
+#[handler(permission = "gate_passes::sync")]
+pub async fn find_gate_pass_offline_key_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    headers: HeaderMap,
+) {
+    let user_login = session.get_auth_login().await?;
+    let device_id = device_id(&headers)?;
+    info!("Received get Gate Pass offline key request: login={user_login}, device_id={device_id}");
+    check_gate_pass_device(&state, &session, &headers).await?;
+    state
+        .repository
+        .find_gate_pass_offline_key(device_id, user_login)
+        .await
+        .map(Json)
+}
+
+/// Denies the gate pass data to a revoked device, the client purges its offline
+/// gate passes when it receives the error. The device is matched by the session and
+/// by the persistent device id, which survives a new sign-in on the device.
+async fn check_gate_pass_device(
+    state: &State<Arc<AppState>>,
+    session: &Session,
+    headers: &HeaderMap,
+) -> Result<()> {
+    let session_id = session.get_session_id().to_string();
+    let device_id_opt = device_id(headers).ok();
+    let gate_pass_device_opt = state
+        .repository
+        .find_revoked_gate_pass_device(&session_id, device_id_opt.clone())
+        .await?;
+    if let Some(gate_pass_device) = gate_pass_device_opt {
+        warn!(
+            "Revoked GatePass device denied: id={}, session_id={session_id}, device_id={:?}",
+            gate_pass_device.id, device_id_opt
+        );
+        Err(SessionError::DeviceRevoked)?
+    }
+    Ok(())
+}
+
+#[handler(permission = "gate_passes::read")]
+pub async fn find_blocked_gate_passes_handler(state: State<Arc<AppState>>, session: Session) {
+    info!("Received get blocked Gate Passes request");
+    state.repository.find_blocked_gate_passes().await.map(Json)
+}
+
+#[handler(permission = "gate_passes::devices")]
+pub async fn find_gate_pass_devices_handler(state: State<Arc<AppState>>, session: Session) {
+    info!("Received get Gate Pass devices request");
+    state.repository.find_gate_pass_devices().await.map(Json)
+}
+
+#[handler(permission = "gate_passes::devices")]
+pub async fn revoke_gate_pass_device_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(device_id): Path<Cow<'static, str>>,
+) {
+    info!("Received revoke Gate Pass device request: device_id={device_id}");
+    let user_login = session.get_auth_login().await?;
+    state
+        .repository
+        .revoke_gate_pass_device(device_id, user_login)
         .await
-        .map(|mut sync_gate_pass_response| {
-            if !full_sync {
-                sync_gate_pass_response
-                    .gate_passes
-                    .iter_mut()
-                    .for_each(|gate_pass| {
-                        erase_sensitive_data(gate_pass);
-                    });
-            }
-            sync_gate_pass_response
-        })
         .map(Json)
 }
 
@@ -206,16 +386,26 @@ pub async fn send_gate_pass_email_handler(
     info!("Received send Gate Pass email request: gate_pass_id={gate_pass_id}");
     request.validate()?;
     let gate_pass = state.repository.find_gate_pass(gate_pass_id).await?;
+    let recipient_email = request.recipient_email_for(&gate_pass)?;
+    send_gate_pass_email(&state, &gate_pass, &recipient_email).await?;
+    Ok(())
+}
+
+async fn send_gate_pass_email(
+    state: &State<Arc<AppState>>,
+    gate_pass: &GatePass,
+    recipient_email: &Cow<'static, str>,
+) -> Result<()> {
     let logo_bytes = tokio::fs::read(format!(
         "{}/assets/favicon.ico",
         state.config.paths.www_path
     ))
     .await?;
-    let qr_code_bytes = generate_qr_code_png(&gate_pass.id).unwrap_or_default();
-    let gate_pass_email_html = gate_pass_email_html(&gate_pass)?;
+    let qr_code_bytes = generate_qr_code_png(gate_pass).unwrap_or_default();
+    let gate_pass_email_html = gate_pass_email_html(gate_pass)?;
     let mail_request = GatePassSendMailRequest {
         sender: state.config.smtp.sender.clone(),
-        recipient: request.recipient_email.clone(),
+        recipient: recipient_email.clone(),
         gate_pass_email_html: Cow::Owned(gate_pass_email_html),
         logo_bytes,
         qr_code_bytes,
@@ -228,57 +418,102 @@ pub async fn send_gate_pass_email_handler(
 pub async fn print_gate_pass_handler(
     state: State<Arc<AppState>>,
     session: Session,
-    Payload(request): Payload<PrintGatePassRequest>,
+    Payload(mut request): Payload<PrintGatePassRequest>,
 ) {
     info!(
-        "Received print Gate Pass request: number_of_ids={}, number_of_number_plates={}",
+        "Received print Gate Pass request: number_of_ids={}, number_of_number_plates={}, never_printed={}",
         request.ids.as_ref().map(|vector| vector.len()).unwrap_or(0),
         request
             .number_plates
             .as_ref()
             .map(|vector| vector.len())
             .unwrap_or(0),
+        request.never_printed,
     );
-    let gate_pass_print_html = gate_pass_print_html(
-        request.two_side_print_mode.is_manual(),
-        gate_pass_fronts(request, state).await?,
-        gate_pass_back_html()?,
-    );
-    Ok(Response::builder()
-        .header(CONTENT_TYPE, "text/html")
-        .body(gate_pass_print_html?)
-        .unwrap())
-}
-
-async fn gate_pass_fronts(
-    mut request: PrintGatePassRequest,
-    state: State<Arc<AppState>>,
-) -> Result<Vec<String>> {
+    let user_login = session.get_auth_login().await?;
     let mut search_request = SearchGatePassRequest::all_gate_passes(
         request.ids.take(),
         None,
         request.number_plates.take(),
     );
+    search_request.never_printed = Some(request.never_printed);
     search_request.normalize();
-    let gate_pass_fronts = state
+    let gate_passes = state
         .repository
         .search_gate_passes(search_request)
         .await?
-        .page_rows
-        .into_iter()
+        .page_rows;
+    let dictionaries = state.repository.find_gate_pass_dictionaries().await?;
+    let gate_pass_print_html = gate_pass_print_html(
+        request.two_side_print_mode.is_manual(),
+        gate_pass_fronts(&gate_passes, &dictionaries)?,
+        gate_pass_back_html()?,
+    )?;
+    let mut response = Response::builder().header(CONTENT_TYPE, "text/html");
+    if !gate_passes.is_empty() {
+        let gate_pass_ids = gate_passes
+            .into_iter()
+            .map(|gate_pass| gate_pass.id)
+            .collect::<Vec<_>>();
+        let gate_pass_print = state
+            .repository
+            .record_gate_pass_print(gate_pass_ids, request.two_side_print_mode, user_login)
+            .await?;
+        response = response.header(GATE_PASS_PRINT_ID_HEADER, gate_pass_print.id.as_ref());
+    }
+    Ok(response.body(gate_pass_print_html).unwrap())
+}
+
+#[handler(permission = "gate_passes::write")]
+pub async fn confirm_gate_pass_print_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(print_id): Path<Cow<'static, str>>,
+) {
+    info!("Received confirm Gate Pass print request: print_id={print_id}");
+    let user_login = session.get_auth_login().await?;
+    state
+        .repository
+        .confirm_gate_pass_print(print_id, user_login)
+        .await
+        .map(Json)
+}
+
+fn gate_pass_fronts(
+    gate_passes: &[GatePass],
+    dictionaries: &GatePassDictionaries,
+) -> Result<Vec<String>> {
+    gate_passes
+        .iter()
         .map(|gate_pass| {
-            let qr_code_png_base64 = generate_qr_code_png(&gate_pass.id)
+            let qr_code_png_base64 = generate_qr_code_png(gate_pass)
                 .map(|qr_code| general_purpose::STANDARD.encode(qr_code.as_slice()))
                 .unwrap_or_default();
-            gate_pass_front_html(&gate_pass, &qr_code_png_base64)
+            gate_pass_front_html(gate_pass, &qr_code_png_base64, dictionaries)
         })
-        .collect::<Result<Vec<_>>>()?;
-    Ok(gate_pass_fronts)
+        .collect::<Result<Vec<_>>>()
 }
 
-fn generate_qr_code_png(gate_pass_id: &Cow<'static, str>) -> Option<Vec<u8>> {
+#[handler(permission = "gate_passes::read")]
+pub async fn search_gate_pass_prints_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(request): Payload<SearchGatePassPrintRequest>,
+) {
+    info!(
+        "Received search Gate Pass prints request: gate_pass_id={:?}",
+        request.gate_pass_id
+    );
+    state
+        .repository
+        .search_gate_pass_prints(request)
+        .await
+        .map(Json)
+}
+
+fn generate_qr_code_png(gate_pass: &GatePass) -> Option<Vec<u8>> {
     qrcode_generator::to_png_to_vec(
-        format!("MTC:GATE-PASS:{}", gate_pass_id).as_bytes(),
+        gate_pass.qr_code().to_string().as_bytes(),
         qrcode_generator::QrCodeEcc::Low,
         512,
     )
@@ -300,7 +535,169 @@ pub async fn renew_gate_pass_handler(
             .map(|vector| vector.len())
             .unwrap_or(0),
     );
+    request.validate()?;
     request.normalize();
+    let search_request = SearchGatePassRequest::all_gate_passes(
+        request.ids.clone(),
+        None,
+        request.number_plates.clone(),
+    );
+    check_gate_pass_renewal_policy(&state, search_request, &request.expired_at).await?;
     state.repository.renew_gate_passes(request).await?;
     Ok(())
 }
+
+async fn check_gate_pass_renewal_policy(
+    state: &State<Arc<AppState>>,
+    search_request: SearchGatePassRequest,
+    expired_at: &str,
+) -> Result<()> {
+    let policy = state.repository.find_gate_pass_policy().await?;
+    let gate_passes = state
+        .repository
+        .search_gate_passes(search_request)
+        .await?
+        .page_rows;
+    policy.check_renewal(expired_at, &gate_passes)?;
+    Ok(())
+}
+
+#[handler(permission = "gate_passes::write")]
+pub async fn bulk_gate_pass_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<BulkGatePassRequest>,
+) {
+    info!(
+        "Received bulk Gate Pass request: operation={:?}, number_of_ids={}",
+        request.operation,
+        request.ids.as_ref().map(|vector| vector.len()).unwrap_or(0),
+    );
+    let user_login = session.get_auth_login().await?;
+    request.validate()?;
+    if let Some(filter_id) = request.filter_id.as_ref().filter(|id| !id.is_empty()) {
+        let gate_pass_filter = state.repository.find_gate_pass_filter(filter_id).await?;
+        request.filter = Some(gate_pass_filter.filter);
+    }
+    request.normalize();
+    if let BulkGatePassOperation::Block(block) = &request.operation {
+        state
+            .repository
+            .find_gate_pass_dictionaries()
+            .await?
+            .check_block(block)?;
+    }
+
+    let requested_ids = request.ids.clone().unwrap_or_default();
+    let mut search_request = request.filter.clone().unwrap_or_default();
+    if !requested_ids.is_empty() {
+        search_request.ids = Some(requested_ids.clone());
+    }
+    let policy = state.repository.find_gate_pass_policy().await?;
+    let operation = request.operation;
+    let (selected_gate_passes, gate_passes) = state
+        .repository
+        .bulk_update_gate_passes(search_request, &operation, &policy, user_login)
+        .await?;
+
+    let mut response = BulkGatePassResponse::default();
+    requested_ids
+        .iter()
+        .filter(|id| {
+            !selected_gate_passes
+                .iter()
+                .any(|gate_pass| gate_pass.id.eq(*id))
+        })
+        .for_each(|id| {
+            response.results.push(BulkGatePassResult::failure(
+                id.clone(),
+                None,
+                DatabaseError::EntryNotFound,
+            ))
+        });
+    // the gate passes are checked by the transaction, the check is repeated on the state it
+    // read to report the reason of the skipped ones
+    selected_gate_passes
+        .into_iter()
+        .filter(|gate_pass| {
+            !gate_passes
+                .iter()
+                .any(|updated_gate_pass| updated_gate_pass.id == gate_pass.id)
+        })
+        .for_each(|gate_pass| {
+            let error = operation
+                .check(&gate_pass, &policy)
+                .err()
+                .unwrap_or(Cow::Borrowed("error-gate-pass-bulk-skipped"));
+            response.results.push(BulkGatePassResult::failure(
+                gate_pass.id,
+                Some(gate_pass.number),
+                error,
+            ))
+        });
+    response.gate_passes = gate_passes;
+
+    for gate_pass in &response.gate_passes {
+        let result = match &operation {
+            BulkGatePassOperation::SendEmail(recipient_email) => {
+                send_bulk_gate_pass_email(&state, recipient_email.as_ref(), gate_pass).await
+            }
+            _ => BulkGatePassResult::success(gate_pass.id.clone(), Some(gate_pass.number.clone())),
+        };
+        response.results.push(result);
+    }
+    info!(
+        "Bulk Gate Pass request processed: number_of_failures={}",
+        response.number_of_failures()
+    );
+    Ok(Json(response))
+}
+
+async fn send_bulk_gate_pass_email(
+    state: &State<Arc<AppState>>,
+    recipient_email: Option<&Cow<'static, str>>,
+    gate_pass: &GatePass,
+) -> BulkGatePassResult {
+    let Some(gate_pass_recipient_email) = gate_pass_recipient_email(recipient_email, gate_pass)
+    else {
+        return BulkGatePassResult::failure(
+            gate_pass.id.clone(),
+            Some(gate_pass.number.clone()),
+            "error-gate-pass-owner-email-missing",
+        );
+    };
+    if let Err(error) = send_gate_pass_email(state, gate_pass, &gate_pass_recipient_email).await {
+        error!(
+            "Failed to send Gate Pass email: gate_pass_id={}, error={:?}",
+            gate_pass.id, error
+        );
+        return BulkGatePassResult::failure(
+            gate_pass.id.clone(),
+            Some(gate_pass.number.clone()),
+            error,
+        );
+    }
+    BulkGatePassResult::success(gate_pass.id.clone(), Some(gate_pass.number.clone()))
+}
+
+#[handler(permission = "gate_passes::read")]
+pub async fn find_gate_pass_policy_handler(state: State<Arc<AppState>>, session: Session) {
+    info!("Received get Gate Pass policy request");
+    state.repository.find_gate_pass_policy().await.map(Json)
+}
+
+#[handler(permission = "gate_passes::policy")]
+pub async fn update_gate_pass_policy_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<GatePassPolicy>,
+) {
+    info!("Received update Gate Pass policy request");
+    request.normalize();
+    request.validate()?;
+    state
+        .repository
+        .update_gate_pass_policy(request)
+        .await
+        .map(Json)
+}
@@ -1,4 +1,5 @@
 use super::*;
+use validator::{ValidationError, ValidationErrors};
 
 #[handler(session, permission = "groups::read")]
 pub async fn find_group_list_handler(
@@ -35,8 +36,35 @@ pub async fn update_group_handler(
     if payload.is_null() { Err(GenericError::BadRequest)? }
 
     let login = session.get_auth_login().await?;
+    let slug = payload.key_str("slug").unwrap_or_default();
 
-    state.repository.update_group(payload, login).await
+    // the parent can't be the group itself or one of its descendants
+    let parent = payload.key_str("parent").unwrap_or_default();
+    if !parent.is_empty() {
+        let id = payload.key_str("id").unwrap_or_default();
+        let groups = state.repository.find_group_list().await?;
+        let parent_found = groups.iter().any(|group| group.id == parent);
+        let parent_descendant =
+            !id.is_empty() && GroupNode::subtree(&groups, &id).contains(&parent);
+        if !parent_found || parent_descendant {
+            Err(GenericError::BadRequest)?
+        }
+
+        // the users are assigned to the leaf groups only
+        if state.repository.has_group_users(parent).await? {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "parent",
+                ValidationError::new("error-group-parent-has-users"),
+            );
+            Err(errors)?
+        }
+    }
+
+    state.repository.update_group(payload, login.clone()).await?;
+    security_audit(&state, SecurityAuditKind::GroupUpdate, &login, &slug, None).await;
+
+    Ok(())
 }
 
 #[handler(session, permission = "groups::delete")]
@@ -44,5 +72,15 @@ pub async fn delete_group_handler(
     Path(id): Path<Cow<'static, str>>,
     state: State<Arc<AppState>>,
 ) {
-    state.repository.delete_group(id).await
+    let by = session.get_auth_login().await?;
+    let slug = state.repository.find_group(id.clone()).await?.slug;
+
+    if !state.repository.is_group_leaf(id.clone()).await? {
+        Err(DatabaseError::EntryInUse)?
+    }
+
+    state.repository.delete_group(id).await?;
+    security_audit(&state, SecurityAuditKind::GroupDelete, &by, &slug, None).await;
+
+    Ok(())
 }
\ No newline at end of file
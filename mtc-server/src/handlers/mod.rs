@@ -6,12 +6,19 @@ mod permissions;
 mod groups;
 mod roles;
 mod users;
+mod user_devices;
+mod user_sessions;
+mod security_audit;
+mod access_tokens;
 mod schemas;
 mod content;
 mod storage;
 mod system;
 mod index_html;
 mod gate_passes;
+mod gate_pass_dictionaries;
+mod gate_pass_filters;
+mod gate_pass_reports;
 
 pub(crate) mod prelude {
     pub(crate) use super::{
@@ -21,11 +28,18 @@ pub(crate) mod prelude {
         groups::*,
         roles::*,
         users::*,
+        user_devices::*,
+        user_sessions::*,
+        security_audit::*,
+        access_tokens::*,
         schemas::*,
         content::*,
         storage::*,
         system::*,
         index_html::*,
         gate_passes::*,
+        gate_pass_dictionaries::*,
+        gate_pass_filters::*,
+        gate_pass_reports::*,
     };
 }
\ No newline at end of file
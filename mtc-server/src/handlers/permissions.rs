@@ -16,8 +16,12 @@ pub async fn create_custom_permission_handler(
     let auth_user = session.get_auth_login().await?;
 
     state.repository
-        .create_custom_permission(permission, auth_user)
-        .await
+        .create_custom_permission(permission.clone(), auth_user.clone())
+        .await?;
+    security_audit(&state, SecurityAuditKind::PermissionCreate, &auth_user, &permission, None)
+        .await;
+
+    Ok(())
 }
 
 #[handler(session, permission = "roles::delete")]
@@ -25,7 +29,11 @@ pub async fn delete_custom_permission_handler(
     Path(permission): Path<Cow<'static, str>>,
     state: State<Arc<AppState>>,
 ) {
+    let by = session.get_auth_login().await?;
     state.repository
-        .delete_custom_permission(permission)
-        .await
+        .delete_custom_permission(permission.clone())
+        .await?;
+    security_audit(&state, SecurityAuditKind::PermissionDelete, &by, &permission, None).await;
+
+    state.repository.refresh_role_sessions(None).await
 }
\ No newline at end of file
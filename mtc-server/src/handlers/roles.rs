@@ -43,8 +43,23 @@ pub async fn update_role_handler(
     }
 
     let by = session.get_auth_login().await?;
+    let id = payload.key_str("id").unwrap_or_default();
+    let slug = payload.key_str("slug").unwrap_or_default();
+    let details = json!({
+        "permissions": payload.get("permissions"),
+        "user_access_level": payload.get("user_access_level"),
+        "user_access_all": payload.get("user_access_all"),
+        "two_factor_required": payload.get("two_factor_required"),
+    });
 
-    state.repository.update_role(payload, by).await
+    state.repository.update_role(payload, by.clone()).await?;
+    security_audit(&state, SecurityAuditKind::RoleUpdate, &by, &slug, Some(details)).await;
+
+    if !id.is_empty() {
+        state.repository.refresh_role_sessions(Some(id)).await?;
+    }
+
+    Ok(())
 }
 
 #[handler(session, permission = "roles::delete")]
@@ -52,5 +67,16 @@ pub async fn delete_role_handler(
     Path(id): Path<Cow<'static, str>>,
     state: State<Arc<AppState>>,
 ) {
-    state.repository.delete_role(id).await
+    let by = session.get_auth_login().await?;
+    let slug = state
+        .repository
+        .find_role(id.clone())
+        .await
+        .map(|role| role.slug)
+        .unwrap_or_else(|_| id.clone());
+
+    state.repository.delete_role(id).await?;
+    security_audit(&state, SecurityAuditKind::RoleDelete, &by, &slug, None).await;
+
+    state.repository.refresh_role_sessions(None).await
 }
\ No newline at end of file
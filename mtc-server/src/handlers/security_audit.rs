@@ -0,0 +1,61 @@
+use super::*;
+use axum::body::Body;
+use axum::http::header::CONTENT_DISPOSITION;
+use chrono::NaiveDate;
+
+#[handler(permission = "security_audit::read")]
+pub async fn search_security_audit_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<SearchSecurityAuditRequest>,
+) {
+    request.normalize();
+    security_audit_check_dates(&request)?;
+
+    state
+        .repository
+        .search_security_audit_events(request)
+        .await
+        .map(Json)
+}
+
+/// Exports all the events matching the filter as CSV for the offline review.
+#[handler(permission = "security_audit::read")]
+pub async fn export_security_audit_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(mut request): Payload<SearchSecurityAuditRequest>,
+) {
+    request.normalize();
+    security_audit_check_dates(&request)?;
+    request.page_request = Some(PageRequest::all());
+
+    let events = state
+        .repository
+        .search_security_audit_events(request)
+        .await?
+        .page_rows;
+    info!(
+        "Security audit exported: number_of_events={}, by={}",
+        events.len(),
+        session.get_auth_login().await?
+    );
+
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "text/csv; charset=utf-8")
+        .header(
+            CONTENT_DISPOSITION,
+            "attachment; filename=\"security_audit.csv\"",
+        )
+        .body(Body::from(security_audit_csv(&events)))
+        .unwrap())
+}
+
+fn security_audit_check_dates(request: &SearchSecurityAuditRequest) -> Result<()> {
+    for date in [&request.date_from, &request.date_to].into_iter().flatten() {
+        if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+            Err(GenericError::BadRequest)?
+        }
+    }
+    Ok(())
+}
@@ -57,6 +57,12 @@ pub async fn sync_handler(
         response.insert("roles".to_string(), json!(roles));
     }
 
+    if auth_state.has_permission(PERMISSION_GATE_PASS_READ)
+        | auth_state.has_permission(PERMISSION_GATE_PASS_VALIDATE) {
+        let gate_pass_dictionaries = state.repository.find_gate_pass_dictionaries().await?;
+        response.insert("gate_pass_dictionaries".to_string(), json!(gate_pass_dictionaries));
+    }
+
     let search_idx = state
         .repository
         .find_search_idx(user_custom_permissions).await?;
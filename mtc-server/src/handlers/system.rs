@@ -8,12 +8,14 @@ pub async fn find_system_info_handler(
     let migrations = state.repository.find_migrations().await?;
     let sitemap = state.repository.get_system_value("sitemap").await?;
     let groups_stat = state.repository.get_groups_stat().await?;
+    let number_plate_collisions = state.repository.get_system_value("number_plate_collisions").await?;
 
     let mut response = Map::new();
     response.insert("info".to_string(), json!(system_info));
     response.insert("migrations".to_string(), json!(migrations));
     response.insert("sitemap".to_string(), json!(sitemap));
     response.insert("groups_stat".to_string(), json!(groups_stat));
+    response.insert("number_plate_collisions".to_string(), number_plate_collisions);
 
     Ok(Json(response))
 }
@@ -30,13 +32,7 @@ pub async fn migration_handler(
 
     if migrations.is_empty() {
         let pwd = payload.key_str("password").unwrap_or(ROLE_ADMINISTRATOR.into());
-        let salt = SaltString::from_b64(&state.config.security.password_salt).unwrap();
-
-        let argon2 = Argon2::default();
-        let Ok(password_hash) = argon2.hash_password(pwd.as_bytes(), &salt) else {
-            Err(SessionError::PasswordHash)?
-        };
-        password = Cow::Owned(password_hash.to_string());
+        password = password_hash(&state.config, &pwd)?;
         login = payload.key_str("login").unwrap_or(ROLE_ADMINISTRATOR.into());
     } else if !session.get_auth_state().await?.has_role(ROLE_ADMINISTRATOR) {
         Err(SessionError::AccessForbidden)?
@@ -58,6 +54,11 @@ pub async fn migration_handler(
 
         state.repository.update_migrations(migrations.clone()).await?;
     }
+
+    let number_plate_collisions = state.repository.get_system_value("number_plate_collisions").await?;
+    if !number_plate_collisions.is_null() {
+        warn!("Number plates are left as is, they collide once normalized: {number_plate_collisions}");
+    }
 }
 
 #[handler(session, permission = "schemas::write", result)]
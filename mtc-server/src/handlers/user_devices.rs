@@ -0,0 +1,130 @@
+use super::*;
+use validator::Validate;
+
+#[handler]
+pub async fn find_own_devices_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    state
+        .repository
+        .find_user_devices(auth_state.id, session.get_session_id().to_string())
+        .await
+        .map(Json)
+}
+
+#[handler]
+pub async fn revoke_own_devices_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    device_id: Option<Path<Cow<'static, str>>>,
+) {
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    let device_id = device_id.map(|Path(device_id)| device_id);
+    state
+        .repository
+        .revoke_user_devices(auth_state.id, device_id.clone(), auth_state.login.clone())
+        .await?;
+    security_audit(
+        &state,
+        SecurityAuditKind::DeviceRevoke,
+        &auth_state.login,
+        &auth_state.login,
+        Some(json!({"device_id": device_id})),
+    ).await;
+
+    Ok(())
+}
+
+#[handler]
+pub async fn update_own_device_expiry_handler(
+    state: State<Arc<AppState>>,
+    session: Session,
+    Path(device_id): Path<Cow<'static, str>>,
+    Payload(request): Payload<UserDeviceExpiryRequest>,
+) {
+    request.validate()?;
+
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() { Err(SessionError::AccessForbidden)? }
+
+    state
+        .repository
+        .update_user_device_expiry(auth_state.id, device_id, request.expires_in_days)
+        .await
+        .map(Json)
+}
+
+#[handler(permission = "users::read")]
+pub async fn find_user_devices_handler(
+    Path(id): Path<Cow<'static, str>>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state.repository.find_user_devices(user.id, "").await.map(Json)
+}
+
+#[handler(permission = "users::write")]
+pub async fn revoke_user_devices_handler(
+    Path(id): Path<Cow<'static, str>>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let by = session.get_auth_login().await?;
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state.repository.revoke_user_devices(user.id, None, by.clone()).await?;
+    security_audit(&state, SecurityAuditKind::DeviceRevoke, &by, &user.login, None).await;
+
+    Ok(())
+}
+
+#[handler(permission = "users::write")]
+pub async fn revoke_user_device_handler(
+    Path((id, device_id)): Path<(Cow<'static, str>, Cow<'static, str>)>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let by = session.get_auth_login().await?;
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state.repository.revoke_user_devices(user.id, Some(device_id.clone()), by.clone()).await?;
+    security_audit(
+        &state,
+        SecurityAuditKind::DeviceRevoke,
+        &by,
+        &user.login,
+        Some(json!({"device_id": device_id})),
+    ).await;
+
+    Ok(())
+}
+
+#[handler(permission = "users::write")]
+pub async fn update_user_device_expiry_handler(
+    Path((id, device_id)): Path<(Cow<'static, str>, Cow<'static, str>)>,
+    state: State<Arc<AppState>>,
+    session: Session,
+    Payload(request): Payload<UserDeviceExpiryRequest>,
+) {
+    request.validate()?;
+
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state
+        .repository
+        .update_user_device_expiry(user.id, device_id, request.expires_in_days)
+        .await
+        .map(Json)
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[handler(permission = "users::read")]
+pub async fn find_user_sessions_handler(
+    Path(id): Path<Cow<'static, str>>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state
+        .repository
+        .find_user_sessions(user.id, session.get_session_id().to_string())
+        .await
+        .map(Json)
+}
+
+#[handler(permission = "users::write")]
+pub async fn revoke_user_sessions_handler(
+    Path(id): Path<Cow<'static, str>>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let by = session.get_auth_login().await?;
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state.repository.revoke_user_sessions(user.id, None, by.clone()).await?;
+    security_audit(&state, SecurityAuditKind::SessionRevoke, &by, &user.login, None).await;
+
+    Ok(())
+}
+
+#[handler(permission = "users::write")]
+pub async fn revoke_user_session_handler(
+    Path((id, session_id)): Path<(Cow<'static, str>, Cow<'static, str>)>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let by = session.get_auth_login().await?;
+    let access = session.get_access_state().await?;
+    let user = state.repository.find_user(id, access).await?;
+
+    state.repository.revoke_user_sessions(user.id, Some(session_id.clone()), by.clone()).await?;
+    security_audit(
+        &state,
+        SecurityAuditKind::SessionRevoke,
+        &by,
+        &user.login,
+        Some(json!({"session_id": session_id})),
+    ).await;
+
+    Ok(())
+}
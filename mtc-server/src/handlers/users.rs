@@ -3,18 +3,27 @@ use super::*;
 #[handler(permission = "users::read")]
 pub async fn find_user_list_handler(
     path: Option<Path<(Cow<'static, str>, bool)>>,
+    Query(query): Query<UserListQuery>,
     state: State<Arc<AppState>>,
     session: Session,
 ) {
     let access = session.get_access_state().await?;
 
+    let groups = match query.group.filter(|group| !group.is_empty()) {
+        Some(group) => {
+            let subtree = GroupNode::subtree(&state.repository.find_group_list().await?, &group);
+            if subtree.is_empty() { vec![group] } else { subtree }
+        }
+        None => vec![],
+    };
+
     if let Some(Path((login, archive))) = path {
         state
             .repository
-            .find_user_list(access, Some(login.to_uppercase().into()), Some(archive))
+            .find_user_list(access, Some(login.to_uppercase().into()), Some(archive), groups)
             .await
     } else {
-        state.repository.find_user_list(access, None, None).await
+        state.repository.find_user_list(access, None, None, groups).await
     }
         .map(Json)
 }
@@ -53,7 +62,71 @@ pub async fn update_user_handler(
 ) {
     let by = session.get_auth_login().await?;
 
-    state.repository.update_user(payload, by).await
+    let password = payload.key_str("password").unwrap_or_default();
+    if !password.is_empty() {
+        password_check_policy(&state.config, &password)?;
+    }
+
+    let group = payload.key_str("group").unwrap_or_default();
+    if !group.is_empty() && !state.repository.is_group_leaf(group).await? {
+        Err(GenericError::BadRequest)?
+    }
+
+    let id = payload.key_str("id").unwrap_or_default();
+    let blocked = payload.key_bool("blocked").unwrap_or_default();
+    let login = payload.key_str("login").unwrap_or_default();
+    let details = json!({
+        "roles": payload.get("roles"),
+        "group": payload.get("group"),
+        "blocked": blocked,
+    });
+    state.repository.update_user(payload, by.clone()).await?;
+    security_audit(&state, SecurityAuditKind::UserUpdate, &by, &login, Some(details)).await;
+
+    if !id.is_empty() {
+        session_user_changed(&state, id, blocked, by).await?;
+    }
+
+    Ok(())
+}
+
+#[handler(permission = "users::write")]
+pub async fn reset_user_two_factor_handler(
+    Path(id): Path<Cow<'static, str>>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let by = session.get_auth_login().await?;
+    let access = session.get_access_state().await?;
+
+    let user = state.repository.find_user(id, access).await?;
+    state.repository.delete_user_two_factor(user.id, by.clone()).await?;
+    security_audit(&state, SecurityAuditKind::TwoFactorReset, &by, &user.login, None).await;
+
+    Ok(())
+}
+
+/// Replaces the user password with a generated one-time password, the user has to
+/// change it on the next sign-in. The password signs in only once, an abandoned change
+/// takes another reset.
+#[handler(permission = "users::write")]
+pub async fn reset_user_password_handler(
+    Path(id): Path<Cow<'static, str>>,
+    state: State<Arc<AppState>>,
+    session: Session,
+) {
+    let by = session.get_auth_login().await?;
+    let access = session.get_access_state().await?;
+
+    let user = state.repository.find_user(id, access).await?;
+    let password = password_generate(&state.config);
+    let password_hash = password_hash(&state.config, &password)?;
+
+    state.repository.change_user_password(user.id, password_hash, true).await?;
+    info!("User password reset: login={}, by={by}", user.login);
+    security_audit(&state, SecurityAuditKind::PasswordReset, &by, &user.login, None).await;
+
+    Ok(Json(OneTimePassword { password }))
 }
 
 #[handler(session, permission = "users::delete")]
@@ -61,7 +134,19 @@ pub async fn delete_user_handler(
     Path(id): Path<Cow<'static, str>>,
     state: State<Arc<AppState>>,
 ) {
-    state.repository.delete_user(id).await
+    let by = session.get_auth_login().await?;
+    let login = state
+        .repository
+        .find_user(id.clone(), Access::administrator())
+        .await
+        .map(|user| user.login)
+        .unwrap_or_else(|_| id.clone());
+    state.repository.revoke_user_sessions(id.clone(), None, by.clone()).await?;
+
+    state.repository.delete_user(id).await?;
+    security_audit(&state, SecurityAuditKind::UserDelete, &by, &login, None).await;
+
+    Ok(())
 }
 
 #[handler(permission = "users::read")]
@@ -94,6 +179,11 @@ pub async fn process_users_handler(
     let roles = payload
         .key_obj::<Vec<Cow<'static, str>>>("roles").unwrap_or_default();
 
+    // the users are assigned to the units without subunits
+    if !group.is_empty() && !state.repository.is_group_leaf(group.clone()).await? {
+        Err(GenericError::BadRequest)?
+    }
+
     let mut users_details = vec![];
 
     for login in logins {
@@ -124,10 +214,21 @@ pub async fn process_users_handler(
         payload.insert_value("roles", json!(user_roles));
         let mut password = Cow::Borrowed("".into());
         if recreate | user.id.is_empty() {
-            password = generate_password(8);
+            password = password_generate(&state.config);
             payload.insert_value("password", Value::String(password.clone().into()));
+            payload.insert_value("password_change_required", Value::Bool(true));
         }
+        let details = json!({
+            "roles": payload.get("roles"),
+            "group": payload.get("group"),
+            "blocked": block,
+        });
         if state.repository.update_user(payload, by.clone()).await.is_ok() {
+            security_audit(&state, SecurityAuditKind::UserUpdate, &by, &login, Some(details))
+                .await;
+            if !user.id.is_empty() {
+                session_user_changed(&state, user.id.clone(), block, by.clone()).await?;
+            }
             users_details.push(UserDetailsDto {
                 id: user.id,
                 login: login.clone(),
@@ -142,10 +243,3 @@ pub async fn process_users_handler(
 
     Ok(Json(users_details))
 }
-
-fn generate_password(len: usize) -> Cow<'static, str> {
-    const CHARSET: &[u8] = b"ABCDEFGHIJKLMNPQRSTUVWXYZabcdefghjkmnopqrstuvwxyz0123456789";
-    let mut rng = rand::rng();
-    let one_char = || CHARSET[rng.random_range(0..CHARSET.len())] as char;
-    std::iter::repeat_with(one_char).take(len).collect()
-}
\ No newline at end of file
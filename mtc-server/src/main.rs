@@ -27,15 +27,16 @@ pub(crate) mod prelude {
         axum::{
             extract::{
                 DefaultBodyLimit, rejection::{FormRejection, JsonRejection},
-                FromRequest, Request, State, Path, Multipart, Query,
+                FromRequest, Request, State, Path, Multipart, Query, ConnectInfo,
             },
             http::{
                 header::{
                     CACHE_CONTROL, STRICT_TRANSPORT_SECURITY, X_FRAME_OPTIONS,
                     X_CONTENT_TYPE_OPTIONS, CONTENT_TYPE, ACCEPT_ENCODING, CONTENT_SECURITY_POLICY,
+                    USER_AGENT, AUTHORIZATION,
                 },
-                HeaderValue, HeaderName, status::StatusCode},
-            middleware::{Next, from_fn}, Router, Form, Json,
+                HeaderValue, HeaderName, HeaderMap, status::StatusCode},
+            middleware::{Next, from_fn, from_fn_with_state}, Router, Form, Json,
             response::{Response, IntoResponse, Redirect},
             routing::{get, post, delete},
         },
@@ -88,6 +89,15 @@ async fn main() {
 
     info!("\x1b[38;5;11m🌟 MTC-CMS Server 🌟\x1b[0m");
 
+    if let Some(verifying_key) = gate_pass_snapshot_verifying_key(&config) {
+        info!("Gate pass snapshot verifying key: {verifying_key}");
+    } else {
+        warn!("SNAPSHOT_SIGNING_KEY is not set, gate pass snapshots are disabled");
+    }
+    if config.security.two_factor_encryption_key.is_none() {
+        warn!("TWO_FACTOR_ENCRYPTION_KEY is not set, two-factor sign-in is unavailable");
+    }
+
     //crypto provider init
     rustls::crypto::aws_lc_rs::default_provider()
         .install_default()
@@ -111,6 +121,9 @@ async fn main() {
 
     let state = Arc::new(AppState::init(config, db, smtp_client, template));
 
+    spawn_gate_pass_block_expiry(state.clone());
+    spawn_ldap_sync(state.clone());
+
     let compression_layer: CompressionLayer = CompressionLayer::new()
         .br(true)
         .gzip(true)
@@ -136,7 +149,7 @@ async fn main() {
 
     let cors_layer = CorsLayer::new()
         .allow_origin([state.config.server.front_end_url.parse().unwrap()])
-        .allow_headers([CONTENT_TYPE, ACCEPT_ENCODING,
+        .allow_headers([CONTENT_TYPE, ACCEPT_ENCODING, AUTHORIZATION,
             HeaderName::from_static("session"),
         ])
         .allow_methods([
@@ -198,7 +211,7 @@ async fn start_http_server(app: Router, state: &Arc<AppState>) {
     let http_host: SocketAddr = format!("{}:{}", state.config.server.host, state.config.server.http_port)
         .parse().expect("Unable to parse socket address");
     axum_server::bind(http_host)
-        .serve(app.into_make_service()).await.unwrap();
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>()).await.unwrap();
 }
 
 async fn start_https_server(app: Router, state: &Arc<AppState>) {
@@ -212,5 +225,5 @@ async fn start_https_server(app: Router, state: &Arc<AppState>) {
     let https_host: SocketAddr = format!("{}:{}", &state.config.server.host, &state.config.server.https_port)
         .parse().expect("Unable to parse socket address");
     axum_server::bind_rustls(https_host, tls_config)
-        .serve(app.into_make_service()).await.unwrap();
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>()).await.unwrap();
 }
\ No newline at end of file
@@ -0,0 +1,140 @@
+use super::*;
+use axum::extract::MatchedPath;
+use axum::http::Method;
+
+/// Routes available to the access tokens. The account, device, key, sync and system
+/// endpoints are left to the signed in sessions.
+const ACCESS_TOKEN_ROUTES: [(Method, &str); 39] = [
+    (Method::GET, "/storage/private/{path}"),
+    (Method::POST, "/storage/private/{path}"),
+    (Method::DELETE, "/storage/private/{path}/{file}"),
+    (Method::GET, "/storage/public/{path}"),
+    (Method::POST, "/storage/public/{path}"),
+    (Method::DELETE, "/storage/public/{path}/{file}"),
+    (Method::GET, "/contents/{slug}"),
+    (Method::GET, "/content/{schema}/{slug}"),
+    (Method::POST, "/content/{schema}/{slug}"),
+    (Method::DELETE, "/content/{schema}/{slug}"),
+    (Method::GET, "/schemas"),
+    (Method::GET, "/schema/{id}"),
+    (Method::POST, "/personnel"),
+    (Method::GET, "/users"),
+    (Method::GET, "/users/{login}/{archive}"),
+    (Method::GET, "/user/{id}"),
+    (Method::GET, "/groups"),
+    (Method::GET, "/group/{id}"),
+    (Method::POST, "/search"),
+    (Method::GET, "/health"),
+    (Method::POST, "/gate-passes"),
+    (Method::POST, "/gate-passes/imports"),
+    (Method::POST, "/gate-passes/exports"),
+    (Method::POST, "/gate-passes/searches"),
+    (Method::GET, "/gate-passes/blocks"),
+    (Method::POST, "/gate-passes/reports"),
+    (Method::POST, "/gate-passes/prints/searches"),
+    (Method::POST, "/gate-passes/renews"),
+    (Method::POST, "/gate-passes/bulks"),
+    (Method::GET, "/gate-passes/filters"),
+    (Method::GET, "/gate-passes/policies"),
+    (Method::GET, "/gate-passes/dictionaries"),
+    (Method::GET, "/gate-passes/{gate_pass_id}"),
+    (Method::PATCH, "/gate-passes/{gate_pass_id}"),
+    (Method::DELETE, "/gate-passes/{gate_pass_id}"),
+    (Method::POST, "/gate-passes/{gate_pass_id}/blocks"),
+    (Method::POST, "/gate-passes/{gate_pass_id}/emails"),
+    (Method::POST, "/gate-passes/{gate_pass_id}/reissues"),
+    (Method::GET, "/gate-passes/{gate_pass_id}/validations"),
+];
+
+/// Authenticates the requests sent with `Authorization: Bearer <token>`, the handlers
+/// see the token user through the session methods while the session stays anonymous.
+pub async fn middleware_access_token_handler(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse> {
+    let Some(token) = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string())
+    else {
+        return Ok(next.run(req).await);
+    };
+
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| matched_path.as_str())
+        .unwrap_or_default();
+    if !access_token_route_allowed(req.method(), route) {
+        Err(SessionError::AccessForbidden)?
+    }
+
+    let access_token_state = access_token_authenticate(&state, &token).await?;
+
+    Ok(access_token_scope(access_token_state, next.run(req)).await)
+}
+
+/// Checks the matched route, with or without the API prefix, is available to the tokens.
+fn access_token_route_allowed(method: &Method, route: &str) -> bool {
+    let route = route.strip_prefix(API_PATH).unwrap_or(route);
+
+    ACCESS_TOKEN_ROUTES
+        .iter()
+        .any(|(allowed_method, allowed_route)| allowed_method == method && *allowed_route == route)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_token_route_allowed_rejects_account_and_key_endpoints() {
+        for (method, route) in [
+            (Method::GET, "/gate-passes/offline-keys"),
+            (Method::POST, "/gate-passes/syncs"),
+            (Method::POST, "/gate-passes/devices/{device_id}/snapshots"),
+            (Method::GET, "/gate-passes/devices"),
+            (Method::POST, "/gate-passes/devices/{device_id}/revocations"),
+            (Method::PATCH, "/gate-passes/policies"),
+            (Method::GET, "/sync"),
+            (Method::POST, "/system/migrate"),
+            (Method::POST, "/user"),
+            (Method::POST, "/user/{id}/password-resets"),
+            (Method::DELETE, "/user/{id}/two-factor"),
+            (Method::POST, "/user/{id}/devices/revocations"),
+            (Method::POST, "/user/{id}/sessions/{session_id}/revocations"),
+            (Method::POST, "/auth/tokens"),
+            (Method::PATCH, "/auth"),
+            (Method::DELETE, "/auth/two-factor"),
+            (Method::GET, ""),
+        ] {
+            assert!(
+                !access_token_route_allowed(&method, route),
+                "{method} {route}"
+            );
+            assert!(
+                !access_token_route_allowed(&method, &format!("{API_PATH}{route}")),
+                "{method} {API_PATH}{route}"
+            );
+        }
+    }
+
+    #[test]
+    fn access_token_route_allowed_accepts_data_endpoints() {
+        assert!(access_token_route_allowed(
+            &Method::POST,
+            "/gate-passes/searches"
+        ));
+        assert!(access_token_route_allowed(
+            &Method::GET,
+            "/api/gate-passes/{gate_pass_id}"
+        ));
+        assert!(!access_token_route_allowed(
+            &Method::POST,
+            "/gate-passes/{gate_pass_id}"
+        ));
+    }
+}
@@ -1,10 +1,14 @@
 use super::*;
 
+/// Requires the `session` header of the front-end client or the `Authorization` header
+/// of the access token client.
 pub async fn middleware_headers_check_handler(
     req: Request,
     next: Next,
 ) -> Result<impl IntoResponse> {
-    if !req.headers().contains_key("session") { Err(GenericError::BadRequest)? }
+    if !req.headers().contains_key("session") && !req.headers().contains_key(AUTHORIZATION) {
+        Err(GenericError::BadRequest)?
+    }
 
     Ok(next.run(req).await)
 }
\ No newline at end of file
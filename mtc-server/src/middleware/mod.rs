@@ -1,15 +1,21 @@
 use crate::prelude::*;
 
+mod access_token;
 mod protected_storage;
+mod security_audit;
 mod session;
+mod session_check;
 mod service_worker;
 mod headers_check;
 
 pub(crate) mod prelude {
     pub(crate) use {
         super::{
+            access_token::*,
             protected_storage::*,
+            security_audit::*,
             session::*,
+            session_check::*,
             service_worker::*,
             headers_check::*,
         }
@@ -0,0 +1,38 @@
+use super::*;
+
+/// Keeps the client details for the audit events written while the request is handled
+/// and records the permission denials raised by `has_permission`.
+pub async fn middleware_security_audit_handler(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse> {
+    let ip = client_ip(&state.config, req.headers(), &address);
+    let user_agent = req
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let method = req.method().to_string();
+    let path = req.uri().path().to_string();
+
+    security_audit_scope(ip, user_agent.into(), async move {
+        let response = next.run(req).await;
+
+        if let Some((login, permission)) = security_audit_denied_permission() {
+            security_audit(
+                &state,
+                SecurityAuditKind::PermissionDenied,
+                &login,
+                &permission,
+                Some(json!({"method": method, "path": path})),
+            )
+            .await;
+        }
+
+        Ok(response)
+    })
+    .await
+}
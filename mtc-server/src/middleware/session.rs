@@ -22,27 +22,31 @@ impl SessionTrait for Session {
 
 
     async fn get_auth_id(&self) -> Result<Cow<'static, str>> {
-        Ok(self.get::<AuthState>(SESSION_AUTH_KEY).unwrap_or_default().id)
+        Ok(self.get_auth_state().await?.id)
     }
 
     async fn get_auth_login(&self) -> Result<Cow<'static, str>> {
-        Ok(self.get::<AuthState>(SESSION_AUTH_KEY).unwrap_or_default().login)
+        Ok(self.get_auth_state().await?.login)
     }
 
     async fn get_auth_state(&self) -> Result<AuthState> {
-        Ok(self.get::<AuthState>(SESSION_AUTH_KEY).unwrap_or_default())
+        Ok(access_token_auth_state()
+            .unwrap_or_else(|| self.get::<AuthState>(SESSION_AUTH_KEY).unwrap_or_default()))
     }
 
     async fn get_access_state(&self) -> Result<Access> {
-        Ok(self.get::<Access>(SESSION_ACCESS_KEY).unwrap_or_default())
+        Ok(access_token_access()
+            .unwrap_or_else(|| self.get::<Access>(SESSION_ACCESS_KEY).unwrap_or_default()))
     }
 
     async fn has_permission(&self, permission: &str) -> Result<()> {
-        match self.get::<AuthState>(SESSION_AUTH_KEY)
-            .unwrap_or_default()
-            .has_permission(permission) {
+        let auth_state = self.get_auth_state().await?;
+        match auth_state.has_permission(permission) {
             true => Ok(()),
-            _ => Err(SessionError::AccessForbidden)?
+            _ => {
+                security_audit_permission_denied(&auth_state.login, permission);
+                Err(SessionError::AccessForbidden)?
+            }
         }
     }
 }
@@ -0,0 +1,64 @@
+use super::*;
+
+/// Drops the authentication of the revoked sessions and of the sessions of the expired
+/// devices, reloads the state of the sessions whose user roles were changed, the last
+/// use of the session is tracked.
+pub async fn middleware_session_check_handler(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse> {
+    let auth_state = session.get_auth_state().await?;
+    if !auth_state.is_authenticated() {
+        return Ok(next.run(req).await);
+    }
+
+    let ip = client_ip(&state.config, req.headers(), &address);
+    let user_agent = req
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    let status = state
+        .repository
+        .check_user_session(
+            session.get_session_id().to_string(),
+            auth_state.id.clone(),
+            auth_state.login.clone(),
+            ip,
+            user_agent,
+            state.config.security.session_expiration,
+        )
+        .await?;
+
+    match status {
+        UserSessionStatus::Revoked => {
+            info!("Revoked session dropped: login={}", auth_state.login);
+            session.clear();
+        }
+        UserSessionStatus::DeviceExpired => {
+            info!(
+                "Session of expired device dropped: login={}",
+                auth_state.login
+            );
+            session.clear();
+        }
+        UserSessionStatus::Refresh => match session_user_state(&state, auth_state.id).await {
+            Ok((auth_state, access)) => session.set_state(&auth_state, &access).await?,
+            Err(_) => {
+                info!(
+                    "Session of unavailable user dropped: login={}",
+                    auth_state.login
+                );
+                session.clear();
+            }
+        },
+        UserSessionStatus::Active => {}
+    }
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+/// Stored secret of the valid access token used to authenticate the bearer requests.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AccessTokenCredentials {
+    pub user_id: Cow<'static, str>,
+    pub secret_digest: Cow<'static, str>,
+    pub permissions: Vec<Cow<'static, str>>,
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// Directory user along with the group and the roles mapped from the directory groups.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LdapUser {
+    pub dn: Cow<'static, str>,
+    pub login: Cow<'static, str>,
+    pub group: Option<Cow<'static, str>>,
+    pub roles: Vec<Cow<'static, str>>,
+}
@@ -2,11 +2,25 @@ use crate::prelude::*;
 
 mod payload;
 mod access;
+mod two_factor;
+mod sign_in_throttle;
+mod password;
+mod user_session;
+mod access_token;
+mod ldap_user;
+mod user_list;
 
 pub(crate) mod prelude {
     pub(crate) use super::{
         access::*,
         payload::*,
+        two_factor::*,
+        sign_in_throttle::*,
+        password::*,
+        user_session::*,
+        access_token::*,
+        ldap_user::*,
+        user_list::*,
     };
 }
 
@@ -0,0 +1,9 @@
+use super::*;
+
+/// Sign-in waiting for the password change, kept in the session until the password
+/// is changed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PasswordChangePending {
+    pub user_id: Cow<'static, str>,
+    pub login: Cow<'static, str>,
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// Failed sign-in counted for a login or a client IP.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SignInFailure {
+    /// Failures within the lockout window, including this one.
+    pub failures: i64,
+    /// Set when this failure locked the login or the client IP.
+    pub locked: bool,
+}
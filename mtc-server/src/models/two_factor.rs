@@ -0,0 +1,22 @@
+use super::*;
+
+/// Two-factor settings stored on the user record.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct UserTwoFactor {
+    /// Base32 TOTP secret encrypted with the `TWO_FACTOR_ENCRYPTION_KEY`.
+    pub secret: Cow<'static, str>,
+    /// Argon2 hashes of the unused recovery codes.
+    #[serde(default)]
+    pub recovery_codes: Vec<Cow<'static, str>>,
+    /// Last accepted TOTP time step, a code is never accepted twice.
+    #[serde(default)]
+    pub last_step: u64,
+}
+
+/// Sign-in waiting for the second factor, kept in the session between the steps.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TwoFactorPending {
+    pub user_id: Cow<'static, str>,
+    pub login: Cow<'static, str>,
+    pub step: TwoFactorStep,
+}
@@ -0,0 +1,7 @@
+use super::*;
+
+/// Query of the user list, the users of the descendants of the `group` are listed too.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UserListQuery {
+    pub group: Option<Cow<'static, str>>,
+}
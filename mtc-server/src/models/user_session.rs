@@ -0,0 +1,15 @@
+use super::*;
+
+/// State of the signed-in session checked on every request.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UserSessionStatus {
+    #[default]
+    Active,
+    /// The session was revoked, its authentication has to be dropped.
+    Revoked,
+    /// The key of the device linked with the session expired, its authentication has to be
+    /// dropped.
+    DeviceExpired,
+    /// The user roles or permissions were changed, the session state has to be reloaded.
+    Refresh,
+}
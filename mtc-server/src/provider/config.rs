@@ -9,6 +9,7 @@ pub struct Config {
     pub smtp: SmtpConfig,
     pub cache: CacheConfig,
     pub pagination: PaginationConfig,
+    pub ldap: LdapConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -22,13 +23,43 @@ pub struct ServerConfig {
 
 #[derive(Debug, Clone)]
 pub struct SecurityConfig {
+    /// Global salt of the former password hashes, such hashes are replaced on the sign-in.
     pub password_salt: Cow<'static, str>,
+    /// Argon2 memory cost of the new password hashes, KiB.
+    pub argon2_memory_cost: u32,
+    pub argon2_time_cost: u32,
+    pub argon2_parallelism: u32,
+    pub password_min_length: usize,
+    pub password_require_uppercase: bool,
+    pub password_require_lowercase: bool,
+    pub password_require_digit: bool,
+    pub password_require_symbol: bool,
+    /// Days a password stays valid, `0` disables the expiration.
+    pub password_expiration: i64,
     pub strict_transport_security: Cow<'static, str>,
     pub content_security_policy: Cow<'static, str>,
     pub x_frame_options: Cow<'static, str>,
     pub x_content_type_options: Cow<'static, str>,
     pub session_expiration: i64,
     pub max_body_limit: usize,
+    pub snapshot_signing_key: Option<Cow<'static, str>>,
+    /// Key the TOTP secrets of the users are encrypted with, never built into the front-end.
+    pub two_factor_encryption_key: Option<Cow<'static, str>>,
+    /// Header with the client IP set by the reverse proxy, e.g. `X-Forwarded-For`.
+    pub client_ip_header: Option<Cow<'static, str>>,
+    /// Number of the reverse proxies appending to the `client_ip_header`.
+    pub trusted_proxy_hops: usize,
+    pub sign_in_max_failures: i64,
+    pub sign_in_ip_max_failures: i64,
+    pub sign_in_lockout: i64,
+    /// Base delay of the failed sign-in response, doubled on every next failure.
+    pub sign_in_delay: u64,
+    /// Lifetime of the one-time QR sign-in token, seconds.
+    pub qr_sign_in_token_expiration: i64,
+    /// Days a linked device key stays valid, `0` keeps the keys until revoked.
+    pub device_key_expiration: i64,
+    /// Longest lifetime of a personal access token, days.
+    pub access_token_max_expiration: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -40,6 +71,7 @@ pub struct PathConfig {
     pub cert_path: Cow<'static, str>,
     pub log_path: Cow<'static, str>,
     pub migration_path: Cow<'static, str>,
+    pub report_font_path: Cow<'static, str>,
 }
 
 #[derive(Debug, Clone)]
@@ -69,6 +101,63 @@ pub struct PaginationConfig {
     pub rows_per_page: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct LdapConfig {
+    /// Directory server URL, e.g. `ldaps://ldap.mtc.local`, unset disables the directory.
+    pub url: Option<Cow<'static, str>>,
+    /// Service account the directory is searched with.
+    pub bind_dn: Cow<'static, str>,
+    pub bind_password: Cow<'static, str>,
+    pub user_base_dn: Cow<'static, str>,
+    pub user_filter: Cow<'static, str>,
+    /// Attribute holding the login, the CMS login is its uppercase value.
+    pub login_attribute: Cow<'static, str>,
+    /// Attribute listing the DNs of the directory groups of the user.
+    pub group_attribute: Cow<'static, str>,
+    pub group_mappings: Vec<LdapGroupMapping>,
+    /// Minutes between the directory syncs, `0` disables the sync.
+    pub sync_interval: u64,
+    pub timeout: u64,
+}
+
+/// Group and roles given to the members of the directory group.
+#[derive(Debug, Clone, Default)]
+pub struct LdapGroupMapping {
+    pub group_dn: Cow<'static, str>,
+    pub group: Option<Cow<'static, str>>,
+    pub roles: Vec<Cow<'static, str>>,
+}
+
+impl LdapConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.url.is_some()
+    }
+
+    /// Parses `<group dn>-><target>,<target>;...` where the target is `group:<slug>` or
+    /// `role:<slug>`, the group DNs are compared in lowercase.
+    fn parse_group_mappings(mappings: &str) -> Vec<LdapGroupMapping> {
+        mappings
+            .split(';')
+            .filter_map(|mapping| mapping.split_once("->"))
+            .map(|(group_dn, targets)| {
+                let mut mapping = LdapGroupMapping {
+                    group_dn: group_dn.trim().to_lowercase().into(),
+                    ..Default::default()
+                };
+                for target in targets.split(',') {
+                    match target.trim().split_once(':') {
+                        Some(("group", slug)) => mapping.group = Some(slug.trim().to_string().into()),
+                        Some(("role", slug)) => mapping.roles.push(slug.trim().to_string().into()),
+                        _ => {}
+                    }
+                }
+                mapping
+            })
+            .filter(|mapping| !mapping.group_dn.is_empty())
+            .collect()
+    }
+}
+
 impl Config {
     pub fn init() -> Config {
         dotenv::dotenv().ok();
@@ -92,12 +181,32 @@ impl Config {
             },
             security: SecurityConfig {
                 password_salt: env("PASSWORD_SALT", "salt").into(),
+                argon2_memory_cost: env("ARGON2_MEMORY_COST", "19456").parse().unwrap(),
+                argon2_time_cost: env("ARGON2_TIME_COST", "2").parse().unwrap(),
+                argon2_parallelism: env("ARGON2_PARALLELISM", "1").parse().unwrap(),
+                password_min_length: env("PASSWORD_MIN_LENGTH", "8").parse().unwrap(),
+                password_require_uppercase: env("PASSWORD_REQUIRE_UPPERCASE", "true").parse().unwrap_or(true),
+                password_require_lowercase: env("PASSWORD_REQUIRE_LOWERCASE", "true").parse().unwrap_or(true),
+                password_require_digit: env("PASSWORD_REQUIRE_DIGIT", "true").parse().unwrap_or(true),
+                password_require_symbol: env("PASSWORD_REQUIRE_SYMBOL", "false").parse().unwrap_or(false),
+                password_expiration: env("PASSWORD_EXPIRATION_IN_DAYS", "0").parse().unwrap(),
                 strict_transport_security: env("STRICT_TRANSPORT_SECURITY", "max-age=63072000; includeSubDomains; preload").into(),
                 content_security_policy: env("CONTENT_SECURITY_POLICY", "default-src 'self' https://242.mil.gov.ua https://*.youtube.com https://*.gstatic.com https://*.googleapis.com https://*.youtube.com https://i.ytimg.com; frame-ancestors 'self'; script-src 'self' 'wasm-unsafe-eval' 'nonce-{{nonce}}'; img-src * data:; style-src 'self' 'unsafe-inline';").into(),
                 x_frame_options: env("X_FRAME_OPTIONS", "SAMEORIGIN").into(),
                 x_content_type_options: env("X_CONTENT_TYPE_OPTIONS", "nosniff").into(),
                 session_expiration: env("SESSION_EXPIRATION_IN_MINUTES", "1440").parse().unwrap(),
                 max_body_limit: env("MAX_BODY_LIMIT", "104857600").parse().unwrap(),
+                snapshot_signing_key: env_opt("SNAPSHOT_SIGNING_KEY").map(Cow::from),
+                two_factor_encryption_key: env_opt("TWO_FACTOR_ENCRYPTION_KEY").map(Cow::from),
+                client_ip_header: env_opt("CLIENT_IP_HEADER").map(Cow::from),
+                trusted_proxy_hops: env("TRUSTED_PROXY_HOPS", "1").parse().unwrap(),
+                sign_in_max_failures: env("SIGN_IN_MAX_FAILURES", "5").parse().unwrap(),
+                sign_in_ip_max_failures: env("SIGN_IN_IP_MAX_FAILURES", "20").parse().unwrap(),
+                sign_in_lockout: env("SIGN_IN_LOCKOUT_IN_MINUTES", "15").parse().unwrap(),
+                sign_in_delay: env("SIGN_IN_DELAY_IN_MILLISECONDS", "250").parse().unwrap(),
+                qr_sign_in_token_expiration: env("QR_SIGN_IN_TOKEN_EXPIRATION_IN_SECONDS", "120").parse().unwrap(),
+                device_key_expiration: env("DEVICE_KEY_EXPIRATION_IN_DAYS", "0").parse().unwrap(),
+                access_token_max_expiration: env("ACCESS_TOKEN_MAX_EXPIRATION_IN_DAYS", "365").parse().unwrap(),
             },
             paths: Self::init_paths(&data_path),
             database: DatabaseConfig {
@@ -119,6 +228,18 @@ impl Config {
             pagination: PaginationConfig {
                 rows_per_page: env("ROWS_PER_PAGE", "50").parse().unwrap(),
             },
+            ldap: LdapConfig {
+                url: env_opt("LDAP_URL").filter(|url| !url.is_empty()).map(Cow::from),
+                bind_dn: env("LDAP_BIND_DN", "").into(),
+                bind_password: env("LDAP_BIND_PASSWORD", "").into(),
+                user_base_dn: env("LDAP_USER_BASE_DN", "").into(),
+                user_filter: env("LDAP_USER_FILTER", "(objectClass=person)").into(),
+                login_attribute: env("LDAP_LOGIN_ATTRIBUTE", "uid").into(),
+                group_attribute: env("LDAP_GROUP_ATTRIBUTE", "memberOf").into(),
+                group_mappings: LdapConfig::parse_group_mappings(&env("LDAP_GROUP_MAPPINGS", "")),
+                sync_interval: env("LDAP_SYNC_INTERVAL_IN_MINUTES", "60").parse().unwrap(),
+                timeout: env("LDAP_TIMEOUT_IN_SECONDS", "10").parse().unwrap(),
+            },
         }
     }
 
@@ -138,6 +259,9 @@ impl Config {
             migration_path: env_opt("DB_MIGRATION_PATH")
                 .map(Cow::from)
                 .unwrap_or_else(|| Self::build_path(data_path, "migrations")),
+            report_font_path: env_opt("REPORT_FONT_PATH")
+                .map(Cow::from)
+                .unwrap_or_else(|| Self::build_path(data_path, "fonts")),
             data_path: Cow::Owned(data_path.to_string()),
         }
     }
@@ -153,4 +277,26 @@ fn env(key: &str, default: &'static str) -> String {
 
 fn env_opt(key: &str) -> Option<String> {
     dotenv::var(key).ok()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_group_mappings_reads_groups_and_roles() {
+        let mappings = LdapConfig::parse_group_mappings(
+            " CN=Staff,DC=mtc -> group:staff, role:writer ,role:reader; \
+            cn=guests,dc=mtc->role:reader,unknown:x,group; ->role:admin; no-arrow",
+        );
+
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(mappings[0].group_dn, "cn=staff,dc=mtc");
+        assert_eq!(mappings[0].group, Some("staff".into()));
+        assert_eq!(mappings[0].roles, vec![Cow::from("writer"), Cow::from("reader")]);
+        assert_eq!(mappings[1].group_dn, "cn=guests,dc=mtc");
+        assert_eq!(mappings[1].group, None);
+        assert_eq!(mappings[1].roles, vec![Cow::from("reader")]);
+        assert!(LdapConfig::parse_group_mappings("").is_empty());
+    }
+}
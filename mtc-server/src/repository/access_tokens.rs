@@ -0,0 +1,200 @@
+use super::*;
+
+const ACCESS_TOKEN_FIELDS: &str = r#"
+    id.id() as id, name, permissions, created_at, expires_at, last_used_at,
+    revoked, revoked_at,
+    (revoked = false AND expires_at > time::now()) as active
+"#;
+
+pub trait AccessTokenRepository {
+    async fn create_access_token(
+        &self,
+        user_id: impl ToString,
+        name: impl ToString,
+        secret_digest: impl ToString,
+        permissions: Vec<Cow<'static, str>>,
+        expires_in_days: i64,
+    ) -> Result<AccessToken>;
+
+    async fn find_access_tokens(&self, user_id: impl ToString) -> Result<Vec<AccessToken>>;
+
+    async fn find_access_token_credentials(
+        &self,
+        access_token_id: impl ToString,
+    ) -> Result<Option<AccessTokenCredentials>>;
+
+    /// Returns the access of the user roles, except the administrator one, granting any of
+    /// the token permissions.
+    async fn find_access_token_access(
+        &self,
+        user_id: impl ToString,
+        permissions: Vec<Cow<'static, str>>,
+    ) -> Result<Access>;
+
+    async fn update_access_token_last_used(&self, access_token_id: impl ToString) -> Result<()>;
+
+    async fn revoke_access_tokens(
+        &self,
+        user_id: impl ToString,
+        access_token_id: Option<Cow<'static, str>>,
+    ) -> Result<()>;
+}
+
+impl AccessTokenRepository for Repository {
+    async fn create_access_token(
+        &self,
+        user_id: impl ToString,
+        name: impl ToString,
+        secret_digest: impl ToString,
+        permissions: Vec<Cow<'static, str>>,
+        expires_in_days: i64,
+    ) -> Result<AccessToken> {
+        let user_id = user_id.to_string();
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $access_token_record = CREATE ONLY access_tokens SET
+                user = type::thing('users', $user_id),
+                name = $name,
+                secret_digest = $secret_digest,
+                permissions = $permissions,
+                expires_at = time::now() + duration::from::days($expires_in_days);
+            RETURN SELECT {ACCESS_TOKEN_FIELDS} FROM ONLY $access_token_record.id;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.clone(),
+            "name": name.to_string(),
+            "secret_digest": secret_digest.to_string(),
+            "permissions": permissions,
+            "expires_in_days": expires_in_days,
+        }));
+
+        let access_token = self
+            .execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<AccessToken>>(0)?
+            .ok_or(DatabaseError::EntryNotFound)?;
+
+        info!(
+            "Access token created: user_id={user_id}, id={}",
+            access_token.id
+        );
+        Ok(access_token)
+    }
+
+    async fn find_access_tokens(&self, user_id: impl ToString) -> Result<Vec<AccessToken>> {
+        let query = format!(
+            "SELECT {ACCESS_TOKEN_FIELDS} FROM access_tokens
+            WHERE user = type::thing('users', $user_id) ORDER BY created_at DESC"
+        );
+
+        let query_params = QueryParams::from_params(json!({"user_id": user_id.to_string()}));
+
+        Ok(self
+            .execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Vec<AccessToken>>(0)?)
+    }
+
+    async fn find_access_token_credentials(
+        &self,
+        access_token_id: impl ToString,
+    ) -> Result<Option<AccessTokenCredentials>> {
+        let query = r#"
+            SELECT user.id() as user_id, secret_digest, permissions
+            FROM ONLY type::thing('access_tokens', $access_token_id)
+            WHERE revoked = false AND expires_at > time::now();
+        "#;
+
+        let query_params =
+            QueryParams::from_params(json!({"access_token_id": access_token_id.to_string()}));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<AccessTokenCredentials>>(0)?)
+    }
+
+    async fn find_access_token_access(
+        &self,
+        user_id: impl ToString,
+        permissions: Vec<Cow<'static, str>>,
+    ) -> Result<Access> {
+        let query = r#"
+            SELECT
+                math::min(roles.user_access_level) ?? 999 as level,
+                array::any(roles.user_access_all) as full
+            FROM ONLY (
+                SELECT ->user_roles->roles[WHERE slug != $administrator AND array::len(
+                    array::intersect(->role_permissions->permissions.slug, $permissions)
+                ) > 0] as roles
+                FROM ONLY type::thing('users', $user_id)
+            );
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.to_string(),
+            "permissions": permissions,
+            "administrator": ROLE_ADMINISTRATOR,
+        }));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<Access>>(0)?
+            .unwrap_or_default())
+    }
+
+    async fn update_access_token_last_used(&self, access_token_id: impl ToString) -> Result<()> {
+        let query = r#"
+            UPDATE type::thing('access_tokens', $access_token_id) SET last_used_at = time::now()
+            WHERE last_used_at = NONE OR last_used_at < time::now() - 1m;
+        "#;
+
+        let query_params =
+            QueryParams::from_params(json!({"access_token_id": access_token_id.to_string()}));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn revoke_access_tokens(
+        &self,
+        user_id: impl ToString,
+        access_token_id: Option<Cow<'static, str>>,
+    ) -> Result<()> {
+        let user_id = user_id.to_string();
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $access_token_records = SELECT VALUE id FROM access_tokens
+                WHERE user = type::thing('users', $user_id) AND revoked = false
+                    AND (!$access_token_id OR id = type::thing('access_tokens', $access_token_id));
+            UPDATE $access_token_records SET
+                revoked = true,
+                revoked_at = time::now();
+            RETURN count($access_token_records);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.clone(),
+            "access_token_id": access_token_id.clone(),
+        }));
+
+        let number_of_tokens = self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<usize>>(0)?
+            .unwrap_or_default();
+
+        if access_token_id.is_some() && number_of_tokens == 0 {
+            Err(DatabaseError::EntryNotFound)?
+        }
+
+        info!("Access tokens revoked: user_id={user_id}, number_of_tokens={number_of_tokens}");
+        Ok(())
+    }
+}
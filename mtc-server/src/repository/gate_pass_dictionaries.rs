@@ -0,0 +1,172 @@
+use super::*;
+
+const GATE_PASS_DICTIONARY_ENTRY_FIELDS: &str = "*, id.id() as id";
+
+pub trait GatePassDictionaryRepository {
+    async fn find_gate_pass_dictionaries(&self) -> Result<GatePassDictionaries>;
+
+    async fn create_gate_pass_dictionary_entry(
+        &self,
+        request: GatePassDictionaryEntryRequest,
+    ) -> Result<GatePassDictionaryEntry>;
+
+    async fn update_gate_pass_dictionary_entry(
+        &self,
+        entry_id: impl ToString,
+        request: GatePassDictionaryEntryRequest,
+    ) -> Result<GatePassDictionaryEntry>;
+
+    async fn delete_gate_pass_dictionary_entry(
+        &self,
+        entry_id: impl ToString,
+    ) -> Result<GatePassDictionaryEntry>;
+}
+
+impl GatePassDictionaryRepository for Repository {
+    async fn find_gate_pass_dictionaries(&self) -> Result<GatePassDictionaries> {
+        let query = format!(
+            "SELECT {GATE_PASS_DICTIONARY_ENTRY_FIELDS} FROM gate_pass_dictionary_entries
+            ORDER BY kind, sort_order, code"
+        );
+
+        let entries = self
+            .execute_query_with_params(&query, QueryParams::default())
+            .await?
+            .take::<Vec<GatePassDictionaryEntry>>(0)?;
+        Ok(GatePassDictionaries { entries })
+    }
+
+    async fn create_gate_pass_dictionary_entry(
+        &self,
+        request: GatePassDictionaryEntryRequest,
+    ) -> Result<GatePassDictionaryEntry> {
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $existing_record = SELECT id FROM ONLY gate_pass_dictionary_entries
+                WHERE kind = $entry.kind AND code = $entry.code LIMIT 1;
+            IF $existing_record != NONE THEN {{
+                RETURN NONE
+            }} END;
+            LET $entry_record = CREATE ONLY gate_pass_dictionary_entries SET
+                kind = $entry.kind,
+                code = $entry.code,
+                translations = $entry.translations,
+                print_name = $entry.print_name,
+                sort_order = $entry.sort_order,
+                created_by = $entry.created_by,
+                updated_by = $entry.updated_by;
+            RETURN SELECT {GATE_PASS_DICTIONARY_ENTRY_FIELDS} FROM ONLY $entry_record.id;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({"entry": request}));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePassDictionaryEntry>>(0)
+            .map(|entry_opt| match entry_opt {
+                Some(entry) => {
+                    info!(
+                        "GatePass dictionary entry created: id={}, kind={:?}, code={}",
+                        &entry.id, entry.kind, &entry.code
+                    );
+                    Ok(entry)
+                }
+                None => Err(DatabaseError::EntryAlreadyExists.into()),
+            })?
+    }
+
+    /// The kind and code are kept, as gate passes reference entries by them.
+    async fn update_gate_pass_dictionary_entry(
+        &self,
+        entry_id: impl ToString,
+        request: GatePassDictionaryEntryRequest,
+    ) -> Result<GatePassDictionaryEntry> {
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $entry_record = SELECT * FROM ONLY type::thing('gate_pass_dictionary_entries', $entry_id);
+            IF $entry_record = NONE THEN {{
+                RETURN NONE
+            }} END;
+            UPDATE $entry_record.id SET
+                translations = $entry.translations,
+                print_name = $entry.print_name,
+                sort_order = $entry.sort_order,
+                updated_by = $entry.updated_by;
+            RETURN SELECT {GATE_PASS_DICTIONARY_ENTRY_FIELDS} FROM ONLY $entry_record.id;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "entry_id": entry_id.to_string(),
+            "entry": request,
+        }));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePassDictionaryEntry>>(0)
+            .map(|entry_opt| match entry_opt {
+                Some(entry) => {
+                    info!("GatePass dictionary entry updated: id={}", &entry.id);
+                    Ok(entry)
+                }
+                None => Err(DatabaseError::EntryNotFound.into()),
+            })?
+    }
+
+    async fn delete_gate_pass_dictionary_entry(
+        &self,
+        entry_id: impl ToString,
+    ) -> Result<GatePassDictionaryEntry> {
+        let query = format!(
+            "SELECT {GATE_PASS_DICTIONARY_ENTRY_FIELDS}
+            FROM ONLY type::thing('gate_pass_dictionary_entries', $entry_id)"
+        );
+
+        let query_params = QueryParams::from_params(json!({"entry_id": entry_id.to_string()}));
+
+        let entry = self
+            .execute_query_with_params(&query, query_params.clone())
+            .await?
+            .take::<Option<GatePassDictionaryEntry>>(0)?
+            .ok_or(DatabaseError::EntryNotFound)?;
+
+        let usage_clause = match entry.kind {
+            GatePassDictionaryKind::OwnerTitle => "owner.title = $code",
+            GatePassDictionaryKind::VehicleColor => "vehicles.*.color CONTAINS $code",
+            GatePassDictionaryKind::VehicleBodyType => "vehicles.*.body_type CONTAINS $code",
+            GatePassDictionaryKind::BlockReason => {
+                "(block.reason_code = $code OR block_history.*.reason_code CONTAINS $code)"
+            }
+        };
+
+        let usage_query = format!(
+            "RETURN (SELECT count() FROM gate_passes
+            WHERE deleted = false AND {usage_clause} GROUP ALL)[0].count ?? 0;"
+        );
+
+        let number_of_gate_passes = self
+            .execute_query_with_params(
+                &usage_query,
+                QueryParams::from_params(json!({"code": &entry.code})),
+            )
+            .await?
+            .take::<Option<i64>>(0)?
+            .unwrap_or_default();
+        if number_of_gate_passes > 0 {
+            return Err(DatabaseError::EntryInUse.into());
+        }
+
+        self.execute_query_with_params(
+            "DELETE type::thing('gate_pass_dictionary_entries', $entry_id)",
+            query_params,
+        )
+        .await?;
+        info!("GatePass dictionary entry deleted: id={}", &entry.id);
+        Ok(entry)
+    }
+}
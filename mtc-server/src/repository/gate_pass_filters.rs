@@ -0,0 +1,93 @@
+use super::*;
+
+const GATE_PASS_FILTER_FIELDS: &str = "*, id.id() as id";
+
+pub trait GatePassFilterRepository {
+    async fn find_gate_pass_filters(&self) -> Result<Vec<GatePassFilter>>;
+
+    async fn find_gate_pass_filter(&self, filter_id: impl ToString) -> Result<GatePassFilter>;
+
+    async fn create_gate_pass_filter(
+        &self,
+        request: GatePassFilterRequest,
+    ) -> Result<GatePassFilter>;
+
+    async fn delete_gate_pass_filter(&self, filter_id: impl ToString) -> Result<GatePassFilter>;
+}
+
+impl GatePassFilterRepository for Repository {
+    async fn find_gate_pass_filters(&self) -> Result<Vec<GatePassFilter>> {
+        let query =
+            format!("SELECT {GATE_PASS_FILTER_FIELDS} FROM gate_pass_filters ORDER BY title");
+
+        let filters = self
+            .execute_query_with_params(&query, QueryParams::default())
+            .await?
+            .take::<Vec<GatePassFilter>>(0)?;
+        Ok(filters)
+    }
+
+    async fn find_gate_pass_filter(&self, filter_id: impl ToString) -> Result<GatePassFilter> {
+        let query = format!(
+            "SELECT {GATE_PASS_FILTER_FIELDS} FROM ONLY type::thing('gate_pass_filters', $filter_id)"
+        );
+
+        let query_params = QueryParams::from_params(json!({"filter_id": filter_id.to_string()}));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePassFilter>>(0)?
+            .ok_or(DatabaseError::EntryNotFound.into())
+    }
+
+    async fn create_gate_pass_filter(
+        &self,
+        request: GatePassFilterRequest,
+    ) -> Result<GatePassFilter> {
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $existing_record = SELECT id FROM ONLY gate_pass_filters
+                WHERE title = $gate_pass_filter.title LIMIT 1;
+            IF $existing_record != NONE THEN {{
+                RETURN NONE
+            }} END;
+            LET $filter_record = CREATE ONLY gate_pass_filters SET
+                title = $gate_pass_filter.title,
+                filter = $gate_pass_filter.filter,
+                created_by = $gate_pass_filter.created_by,
+                updated_by = $gate_pass_filter.updated_by;
+            RETURN SELECT {GATE_PASS_FILTER_FIELDS} FROM ONLY $filter_record.id;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({"gate_pass_filter": request}));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePassFilter>>(0)
+            .map(|filter_opt| match filter_opt {
+                Some(filter) => {
+                    info!(
+                        "GatePass filter created: id={}, title={}",
+                        &filter.id, &filter.title
+                    );
+                    Ok(filter)
+                }
+                None => Err(DatabaseError::EntryAlreadyExists.into()),
+            })?
+    }
+
+    async fn delete_gate_pass_filter(&self, filter_id: impl ToString) -> Result<GatePassFilter> {
+        let filter = self.find_gate_pass_filter(filter_id.to_string()).await?;
+
+        self.execute_query_with_params(
+            "DELETE type::thing('gate_pass_filters', $filter_id)",
+            QueryParams::from_params(json!({"filter_id": filter.id})),
+        )
+        .await?;
+        info!("GatePass filter deleted: id={}", &filter.id);
+        Ok(filter)
+    }
+}
@@ -1,6 +1,22 @@
 use super::*;
 use surrealdb::RecordId;
 
+const GATE_PASS_POLICY_KEY: &str = "gate_pass_policy";
+/// The print statistics are kept apart, so that a print doesn't change `updated_at`
+/// and push the gate passes to the scanners again.
+const GATE_PASS_FIELDS: &str = r#"
+    *, id.id() as id,
+    (SELECT VALUE print_count FROM ONLY type::thing('gate_pass_print_stats', record::id($parent.id))) ?? 0
+        as print_count,
+    (SELECT VALUE last_printed_at FROM ONLY type::thing('gate_pass_print_stats', record::id($parent.id)))
+        as last_printed_at
+"#;
+const GATE_PASS_DEVICE_FIELDS: &str = r#"
+    id.id() as id, (<-user_api_keys<-users.login)[0] ?? "" as login, os, device,
+    last_synced_at, (revoked OR (expires_at != NONE AND expires_at < time::now())) as revoked,
+    revoked_at, revoked_by
+"#;
+
 pub trait GatePassRepository {
     async fn create_gate_pass(&self, request: CreateGatePassRequest) -> Result<GatePass>;
 
@@ -14,6 +30,12 @@ pub trait GatePassRepository {
     async fn update_gate_pass_block(&self, request: UpdateGatePassBlockRequest)
     -> Result<GatePass>;
 
+    async fn reissue_gate_pass(
+        &self,
+        gate_pass_id: impl ToString,
+        updated_by: impl ToString,
+    ) -> Result<GatePass>;
+
     async fn delete_gate_pass(&self, gate_pass_id: impl ToString) -> Result<GatePass>;
 
     async fn find_gate_pass(&self, gate_pass_id: impl ToString) -> Result<GatePass>;
@@ -29,6 +51,67 @@ pub trait GatePassRepository {
     ) -> Result<SyncGatePassResponse>;
 
     async fn renew_gate_passes(&self, request: RenewGatePassRequest) -> Result<()>;
+
+    async fn bulk_update_gate_passes(
+        &self,
+        request: SearchGatePassRequest,
+        operation: &BulkGatePassOperation,
+        policy: &GatePassPolicy,
+        updated_by: impl ToString,
+    ) -> Result<(Vec<GatePass>, Vec<GatePass>)>;
+
+    async fn record_gate_pass_print(
+        &self,
+        gate_pass_ids: Vec<Cow<'static, str>>,
+        two_side_print_mode: TwoSidePrintMode,
+        created_by: impl ToString,
+    ) -> Result<GatePassPrint>;
+
+    async fn confirm_gate_pass_print(
+        &self,
+        print_id: impl ToString,
+        confirmed_by: impl ToString,
+    ) -> Result<GatePassPrint>;
+
+    async fn search_gate_pass_prints(
+        &self,
+        request: SearchGatePassPrintRequest,
+    ) -> Result<PageResponse<GatePassPrint>>;
+
+    async fn find_gate_pass_offline_key(
+        &self,
+        device_id: impl ToString,
+        login: impl ToString,
+    ) -> Result<GatePassOfflineKey>;
+
+    async fn find_gate_pass_device_key(
+        &self,
+        device_id: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>>;
+
+    async fn find_blocked_gate_passes(&self) -> Result<Vec<BlockedGatePass>>;
+
+    async fn archive_expired_gate_pass_blocks(&self) -> Result<usize>;
+
+    async fn find_gate_pass_devices(&self) -> Result<Vec<GatePassDevice>>;
+
+    async fn find_revoked_gate_pass_device(
+        &self,
+        session_id: impl ToString,
+        device_id: Option<Cow<'static, str>>,
+    ) -> Result<Option<GatePassDevice>>;
+
+    async fn update_gate_pass_device_synced_at(&self, session_id: impl ToString) -> Result<()>;
+
+    async fn revoke_gate_pass_device(
+        &self,
+        device_id: impl ToString,
+        revoked_by: impl ToString,
+    ) -> Result<GatePassDevice>;
+
+    async fn find_gate_pass_policy(&self) -> Result<GatePassPolicy>;
+
+    async fn update_gate_pass_policy(&self, policy: GatePassPolicy) -> Result<GatePassPolicy>;
 }
 
 impl GatePassRepository for Repository {
@@ -44,13 +127,14 @@ impl GatePassRepository for Repository {
                 allow_any_vehicle = $gate_pass.allow_any_vehicle,
                 created_by = $gate_pass.created_by,
                 updated_by = $gate_pass.updated_by;
-            RETURN SELECT *, id.id() as id FROM $gate_pass_record.id;
+            RETURN SELECT {GATE_PASS_FIELDS} FROM $gate_pass_record.id;
         COMMIT TRANSACTION;
-        "#;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
 
         let query_params = QueryParams::from_params(json!({"gate_pass": request}));
 
-        self.execute_query_with_params(query, query_params)
+        self.execute_query_with_params(&query, query_params)
             .await?
             .take::<Option<GatePass>>(0)
             .map(|gate_pass_opt| {
@@ -103,13 +187,14 @@ impl GatePassRepository for Repository {
                 vehicles = $gate_pass.vehicles,
                 allow_any_vehicle = $gate_pass.allow_any_vehicle,
                 updated_by = $gate_pass.updated_by;
-            RETURN SELECT *, id.id() as id FROM $gate_pass_record.id;
+            RETURN SELECT {GATE_PASS_FIELDS} FROM $gate_pass_record.id;
         COMMIT TRANSACTION;
-        "#;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
 
         let query_params = QueryParams::from_params(json!({"gate_pass": request}));
 
-        self.execute_query_with_params(query, query_params)
+        self.execute_query_with_params(&query, query_params)
             .await?
             .take::<Option<GatePass>>(0)
             .map(|gate_pass_opt| {
@@ -134,22 +219,29 @@ impl GatePassRepository for Repository {
             } END;
             IF $gate_pass_block.block = NONE THEN {
                 UPDATE $gate_pass_record SET
-                    block = NONE;
+                    block_history = fn::gate_pass_block_history(block_history, block, time::now(), $gate_pass_block.updated_by),
+                    block = NONE,
+                    updated_by = $gate_pass_block.updated_by;
             } ELSE {
                 UPDATE $gate_pass_record SET
+                    block_history = fn::gate_pass_block_history(block_history, block, time::now(), $gate_pass_block.updated_by),
                     block = {
                         expired_at: type::datetime($gate_pass_block.block.expired_at),
-                        reason: $gate_pass_block.block.reason
+                        reason_code: $gate_pass_block.block.reason_code,
+                        reason: $gate_pass_block.block.reason,
+                        blocked_at: time::now(),
+                        blocked_by: $gate_pass_block.updated_by
                     },
                     updated_by = $gate_pass_block.updated_by;
             } END;
-            RETURN SELECT *, id.id() as id FROM $gate_pass_record.id;
+            RETURN SELECT {GATE_PASS_FIELDS} FROM $gate_pass_record.id;
         COMMIT TRANSACTION;
-        "#;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
 
         let query_params = QueryParams::from_params(json!({"gate_pass_block": request}));
 
-        self.execute_query_with_params(query, query_params)
+        self.execute_query_with_params(&query, query_params)
             .await?
             .take::<Option<GatePass>>(0)
             .map(|gate_pass_opt| {
@@ -162,6 +254,43 @@ impl GatePassRepository for Repository {
             })?
     }
 
+    async fn reissue_gate_pass(
+        &self,
+        gate_pass_id: impl ToString,
+        updated_by: impl ToString,
+    ) -> Result<GatePass> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $gate_pass_record = SELECT * FROM ONLY type::thing('gate_passes', $gate_pass_id) WHERE deleted = false;
+            IF $gate_pass_record = NONE THEN {
+                RETURN NONE
+            } END;
+            UPDATE $gate_pass_record SET
+                qr_token = rand::string(12),
+                updated_by = $updated_by;
+            RETURN SELECT {GATE_PASS_FIELDS} FROM $gate_pass_record.id;
+        COMMIT TRANSACTION;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
+
+        let query_params = QueryParams::from_params(json!({
+            "gate_pass_id": gate_pass_id.to_string(),
+            "updated_by": updated_by.to_string(),
+        }));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePass>>(0)
+            .map(|gate_pass_opt| {
+                if let Some(gate_pass) = gate_pass_opt {
+                    info!("GatePass reissued: id={}", &gate_pass.id);
+                    Ok(gate_pass)
+                } else {
+                    Err(DatabaseError::EntryNotFound.into())
+                }
+            })?
+    }
+
     async fn delete_gate_pass(&self, gate_pass_id: impl ToString) -> Result<GatePass> {
         let query = r#"
         BEGIN TRANSACTION;
@@ -170,14 +299,15 @@ impl GatePassRepository for Repository {
                 RETURN NONE
             } END;
             UPDATE $gate_pass_record SET vehicles = [], deleted = true;
-            RETURN SELECT *, id.id() as id FROM $gate_pass_record.id;
+            RETURN SELECT {GATE_PASS_FIELDS} FROM $gate_pass_record.id;
         COMMIT TRANSACTION;
-        "#;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
 
         let query_params =
             QueryParams::from_params(json!({"gate_pass_id": gate_pass_id.to_string()}));
 
-        self.execute_query_with_params(query, query_params)
+        self.execute_query_with_params(&query, query_params)
             .await?
             .take::<Option<GatePass>>(0)
             .map(|gate_pass_opt| {
@@ -192,13 +322,14 @@ impl GatePassRepository for Repository {
 
     async fn find_gate_pass(&self, gate_pass_id: impl ToString) -> Result<GatePass> {
         let query = r#"
-            SELECT *, id.id() as id FROM ONLY type::thing('gate_passes', $gate_pass_id);
-        "#;
+            SELECT {GATE_PASS_FIELDS} FROM ONLY type::thing('gate_passes', $gate_pass_id);
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
 
         let query_params =
             QueryParams::from_params(json!({"gate_pass_id": gate_pass_id.to_string()}));
 
-        self.execute_query_with_params(query, query_params)
+        self.execute_query_with_params(&query, query_params)
             .await?
             .take::<Option<GatePass>>(0)
             .map(|gate_pass_opt| match gate_pass_opt {
@@ -214,51 +345,17 @@ impl GatePassRepository for Repository {
         &self,
         request: SearchGatePassRequest,
     ) -> Result<PageResponse<GatePass>> {
-        let mut where_clauses = String::from("WHERE deleted = false");
-        let id_param_name = "ids";
-        let mut id_param_values = vec![];
-        if let Some(ids) = request.ids.filter(|list| !list.is_empty()) {
-            where_clauses.push_str(&format!(" AND id IN ${} ", id_param_name));
-            id_param_values.extend(gate_pass_record_ids(ids).into_iter());
-        }
-        let last_name_param_name = "owner_last_names";
-        let mut last_name_param_values = vec![];
-        if let Some(last_names) = request.last_names.filter(|list| !list.is_empty()) {
-            where_clauses.push_str(&format!(
-                " AND owner.last_name IN ${} ",
-                last_name_param_name
-            ));
-            last_name_param_values.extend(last_names.into_iter());
-        }
-        let number_plate_param_name = "vehicle_number_plate_names";
-        let mut number_plate_param_values = vec![];
-        if let Some(number_plates) = request.number_plates.filter(|list| !list.is_empty()) {
-            where_clauses.push_str(&format!(
-                " AND vehicles[0].number_plate IN ${} ",
-                number_plate_param_name
-            ));
-            number_plate_param_values.extend(number_plates.into_iter());
-        }
-
-        let query_params = QueryParams {
-            params: json!({
-                last_name_param_name: last_name_param_values,
-                number_plate_param_name: number_plate_param_values
-            }),
-            ids: vec![(id_param_name.to_string(), id_param_values)]
-                .into_iter()
-                .collect(),
-        };
+        let page_request_opt = request.page_request.clone();
+        let (where_clauses, query_params) = gate_pass_where_clauses(request);
 
-        let query = format!("SELECT *, id.id() as id FROM gate_passes {where_clauses}");
+        let query = format!("SELECT {GATE_PASS_FIELDS} FROM gate_passes {where_clauses}");
 
         let mut page_request = PageRequest::new(
             40,
             0,
             vec![OrderBy::new("created_at".to_string(), SortDirection::Desc)],
         );
-        if let Some((page_size, page_index)) = request
-            .page_request
+        if let Some((page_size, page_index)) = page_request_opt
             .map(|page_request| (page_request.page_size, page_request.page_index))
         {
             page_request.page_size = page_size;
@@ -287,14 +384,16 @@ impl GatePassRepository for Repository {
                 LET $end_updated_at = ($start_updated_at + 30d);
                 RETURN {
                     "last_synced_at": time::min([$end_updated_at, $now]),
-                    "gate_passes": SELECT *, id.id() as id FROM gate_passes WHERE updated_at >= $start_updated_at AND updated_at < $end_updated_at
+                    "server_time": $now,
+                    "gate_passes": SELECT {GATE_PASS_FIELDS} FROM gate_passes WHERE updated_at >= $start_updated_at AND updated_at < $end_updated_at
                 };
-        "#;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS);
 
         let query_params =
             QueryParams::from_params(json!({"last_synced_at": request.last_synced_at}));
 
-        self.execute_query_with_params(query, query_params)
+        self.execute_query_with_params(&query, query_params)
             .await?
             .take::<Option<SyncGatePassResponse>>(3)
             .map(|response_opt| match response_opt {
@@ -352,6 +451,501 @@ impl GatePassRepository for Repository {
                 Ok(())
             })?
     }
+
+    /// Selects the gate passes, checks them for the operation and updates the passed ones in
+    /// a single transaction. Returns the selected gate passes as they were before the update
+    /// and the passed gate passes as they are after it, the checks mirror
+    /// [`BulkGatePassOperation::check`].
+    async fn bulk_update_gate_passes(
+        &self,
+        request: SearchGatePassRequest,
+        operation: &BulkGatePassOperation,
+        policy: &GatePassPolicy,
+        updated_by: impl ToString,
+    ) -> Result<(Vec<GatePass>, Vec<GatePass>)> {
+        let (where_clauses, mut query_params) = gate_pass_where_clauses(request);
+
+        let mut excluded_titles = vec![];
+        let mut excluded_units = vec![];
+        let check_clauses = match operation {
+            BulkGatePassOperation::Block(_) => {
+                " AND (block = NONE OR block.expired_at <= time::now()) "
+            }
+            BulkGatePassOperation::Unblock => " AND block != NONE ",
+            BulkGatePassOperation::ChangeExpiry(expired_at) => {
+                match policy.renewal_exclusions_by(expired_at, Utc::now()) {
+                    Some((titles, units)) => {
+                        excluded_titles = titles;
+                        excluded_units = units;
+                        r#" AND owner.title NOTINSIDE $excluded_titles
+                            AND string::uppercase(owner.unit) NOTINSIDE $excluded_units "#
+                    }
+                    None => " AND false ",
+                }
+            }
+            _ => "",
+        };
+
+        let (update_clause, operation_value) = match operation {
+            BulkGatePassOperation::Block(block) => (
+                r#"UPDATE $gate_pass_records SET
+                    block_history = fn::gate_pass_block_history(block_history, block, time::now(), $updated_by),
+                    block = {
+                        expired_at: type::datetime($operation_value.expired_at),
+                        reason_code: $operation_value.reason_code,
+                        reason: $operation_value.reason,
+                        blocked_at: time::now(),
+                        blocked_by: $updated_by
+                    },
+                    updated_by = $updated_by;"#,
+                json!(block),
+            ),
+            BulkGatePassOperation::Unblock => (
+                r#"UPDATE $gate_pass_records SET
+                    block_history = fn::gate_pass_block_history(block_history, block, time::now(), $updated_by),
+                    block = NONE,
+                    updated_by = $updated_by;"#,
+                Value::Null,
+            ),
+            BulkGatePassOperation::ChangeUnit(unit) => (
+                r#"UPDATE $gate_pass_records SET
+                    owner.unit = $operation_value,
+                    updated_by = $updated_by;"#,
+                json!(unit),
+            ),
+            BulkGatePassOperation::ChangeExpiry(expired_at) => (
+                r#"UPDATE $gate_pass_records SET
+                    expired_at = type::datetime($operation_value),
+                    updated_by = $updated_by;"#,
+                json!(expired_at),
+            ),
+            BulkGatePassOperation::SendEmail(_) | BulkGatePassOperation::Export => {
+                ("", Value::Null)
+            }
+        };
+
+        if let Some(params) = query_params.params.as_object_mut() {
+            params.insert("operation_value".to_string(), operation_value);
+            params.insert("excluded_titles".to_string(), json!(excluded_titles));
+            params.insert("excluded_units".to_string(), json!(excluded_units));
+            params.insert("updated_by".to_string(), json!(updated_by.to_string()));
+        }
+
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $selected_gate_passes = SELECT {GATE_PASS_FIELDS} FROM gate_passes {WHERE_CLAUSES};
+            LET $gate_pass_records = SELECT VALUE id FROM gate_passes {WHERE_CLAUSES} {CHECK_CLAUSES};
+            {UPDATE_CLAUSE}
+            RETURN [$selected_gate_passes, (SELECT {GATE_PASS_FIELDS} FROM $gate_pass_records)];
+        COMMIT TRANSACTION;
+        "#
+        .replace("{GATE_PASS_FIELDS}", GATE_PASS_FIELDS)
+        .replace("{WHERE_CLAUSES}", &where_clauses)
+        .replace("{CHECK_CLAUSES}", check_clauses)
+        .replace("{UPDATE_CLAUSE}", update_clause);
+
+        let (selected_gate_passes, gate_passes) = self
+            .execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<(Vec<GatePass>, Vec<GatePass>)>>(0)?
+            .unwrap_or_default();
+
+        info!(
+            "GatePasses bulk updated: operation={:?}, number_of_selected={}, number_of_gate_passes={}",
+            operation,
+            selected_gate_passes.len(),
+            gate_passes.len()
+        );
+        Ok((selected_gate_passes, gate_passes))
+    }
+
+    /// Records the gate passes sent to the printer, they are counted as printed once
+    /// the print is confirmed.
+    async fn record_gate_pass_print(
+        &self,
+        gate_pass_ids: Vec<Cow<'static, str>>,
+        two_side_print_mode: TwoSidePrintMode,
+        created_by: impl ToString,
+    ) -> Result<GatePassPrint> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $gate_pass_records = SELECT VALUE id FROM gate_passes WHERE id IN $gate_pass_record_ids;
+            LET $gate_pass_print = CREATE ONLY gate_pass_prints CONTENT {
+                gate_pass_ids: $gate_pass_ids,
+                number_of_gate_passes: array::len($gate_pass_records),
+                two_side_print_mode: $two_side_print_mode,
+                created_by: $created_by
+            };
+            RETURN SELECT *, id.id() as id FROM ONLY $gate_pass_print.id;
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams {
+            params: json!({
+                "gate_pass_ids": gate_pass_ids,
+                "two_side_print_mode": two_side_print_mode,
+                "created_by": created_by.to_string(),
+            }),
+            ids: vec![(
+                "gate_pass_record_ids".to_string(),
+                gate_pass_record_ids(gate_pass_ids),
+            )]
+            .into_iter()
+            .collect(),
+        };
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<GatePassPrint>>(0)
+            .map(|gate_pass_print_opt| match gate_pass_print_opt {
+                Some(gate_pass_print) => {
+                    info!(
+                        "GatePass print recorded: id={}, number_of_gate_passes={}",
+                        &gate_pass_print.id, gate_pass_print.number_of_gate_passes
+                    );
+                    Ok(gate_pass_print)
+                }
+                None => Err(DatabaseError::SomethingWentWrong.into()),
+            })?
+    }
+
+    async fn confirm_gate_pass_print(
+        &self,
+        print_id: impl ToString,
+        confirmed_by: impl ToString,
+    ) -> Result<GatePassPrint> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $gate_pass_print = SELECT * FROM ONLY type::thing('gate_pass_prints', $print_id)
+                WHERE confirmed_at = NONE;
+            IF $gate_pass_print = NONE THEN {
+                RETURN NONE
+            } END;
+            UPDATE $gate_pass_print.id SET
+                confirmed_at = time::now(),
+                confirmed_by = $confirmed_by;
+            FOR $gate_pass_id IN $gate_pass_print.gate_pass_ids {
+                UPSERT type::thing('gate_pass_print_stats', $gate_pass_id) SET
+                    print_count += 1,
+                    last_printed_at = time::now(),
+                    updated_by = $confirmed_by;
+            };
+            RETURN SELECT *, id.id() as id FROM ONLY $gate_pass_print.id;
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "print_id": print_id.to_string(),
+            "confirmed_by": confirmed_by.to_string(),
+        }));
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<GatePassPrint>>(0)
+            .map(|gate_pass_print_opt| match gate_pass_print_opt {
+                Some(gate_pass_print) => {
+                    info!(
+                        "GatePass print confirmed: id={}, number_of_gate_passes={}",
+                        &gate_pass_print.id, gate_pass_print.number_of_gate_passes
+                    );
+                    Ok(gate_pass_print)
+                }
+                None => Err(DatabaseError::EntryNotFound.into()),
+            })?
+    }
+
+    async fn search_gate_pass_prints(
+        &self,
+        request: SearchGatePassPrintRequest,
+    ) -> Result<PageResponse<GatePassPrint>> {
+        let mut where_clauses = String::new();
+        if request.gate_pass_id.is_some() {
+            where_clauses.push_str(" WHERE $gate_pass_id IN gate_pass_ids ");
+        }
+        let query = format!("SELECT *, id.id() as id FROM gate_pass_prints {where_clauses}");
+        let query_params = QueryParams::from_params(json!({
+            "gate_pass_id": request.gate_pass_id,
+        }));
+
+        let mut page_request = PageRequest::new(
+            40,
+            0,
+            vec![OrderBy::new("created_at".to_string(), SortDirection::Desc)],
+        );
+        if let Some((page_size, page_index)) = request
+            .page_request
+            .map(|page_request| (page_request.page_size, page_request.page_index))
+        {
+            page_request.page_size = page_size;
+            page_request.page_index = page_index;
+        }
+
+        self.execute_query_with_params_and_pagination::<GatePassPrint>(
+            &query,
+            query_params,
+            &page_request,
+        )
+        .await
+    }
+
+    async fn find_gate_pass_offline_key(
+        &self,
+        device_id: impl ToString,
+        login: impl ToString,
+    ) -> Result<GatePassOfflineKey> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $offline_key_record = type::thing('gate_pass_device_keys', $device_id);
+            IF (SELECT * FROM ONLY $offline_key_record) = NONE THEN {
+                CREATE $offline_key_record CONTENT { key: rand::string(48), created_by: $login };
+            } END;
+            RETURN SELECT key FROM ONLY $offline_key_record;
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "device_id": device_id.to_string(),
+            "login": login.to_string(),
+        }));
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<GatePassOfflineKey>>(0)
+            .map(|offline_key_opt| match offline_key_opt {
+                Some(offline_key) => Ok(offline_key),
+                None => Err(DatabaseError::EntryNotFound.into()),
+            })?
+    }
+
+    /// Returns the offline key of the linked device, `None` when the device is revoked or
+    /// expired or it has not requested its offline key yet.
+    async fn find_gate_pass_device_key(
+        &self,
+        device_id: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $device_record = SELECT * FROM ONLY type::thing('api_keys', $device_id);
+            IF $device_record = NONE OR $device_record.revoked = true OR $device_record.device_id = NONE
+                OR ($device_record.expires_at != NONE AND $device_record.expires_at < time::now()) THEN {
+                RETURN NONE
+            } END;
+            RETURN SELECT VALUE key FROM ONLY type::thing('gate_pass_device_keys', $device_record.device_id);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"device_id": device_id.to_string()}));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<String>>(0)?
+            .map(Cow::Owned))
+    }
+
+    async fn find_blocked_gate_passes(&self) -> Result<Vec<BlockedGatePass>> {
+        let query = r#"
+            SELECT id.id() as id, number, expired_at, owner, vehicles, block FROM gate_passes
+            WHERE deleted = false AND block != NONE AND block.expired_at > time::now()
+            ORDER BY block.expired_at ASC;
+        "#;
+
+        let blocked_gate_passes = self
+            .execute_query_with_params(query, QueryParams::default())
+            .await?
+            .take::<Vec<BlockedGatePass>>(0)?;
+        info!(
+            "Blocked GatePasses found: number_of_gate_passes={}",
+            blocked_gate_passes.len()
+        );
+        Ok(blocked_gate_passes)
+    }
+
+    async fn archive_expired_gate_pass_blocks(&self) -> Result<usize> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $gate_pass_records = SELECT VALUE id FROM gate_passes
+                WHERE deleted = false AND block != NONE AND block.expired_at <= time::now();
+            UPDATE $gate_pass_records SET
+                block_history = fn::gate_pass_block_history(block_history, block, block.expired_at, NONE),
+                block = NONE;
+            RETURN array::len($gate_pass_records);
+        COMMIT TRANSACTION;
+        "#;
+
+        let number_of_gate_passes = self
+            .execute_query_with_params(query, QueryParams::default())
+            .await?
+            .take::<Option<usize>>(0)?
+            .unwrap_or_default();
+        if number_of_gate_passes > 0 {
+            info!("GatePass blocks archived: number_of_gate_passes={number_of_gate_passes}");
+        }
+        Ok(number_of_gate_passes)
+    }
+
+    async fn find_gate_pass_devices(&self) -> Result<Vec<GatePassDevice>> {
+        let query = format!(
+            "SELECT {GATE_PASS_DEVICE_FIELDS} FROM api_keys
+            WHERE last_synced_at != NONE ORDER BY last_synced_at DESC"
+        );
+
+        let gate_pass_devices = self
+            .execute_query_with_params(&query, QueryParams::default())
+            .await?
+            .take::<Vec<GatePassDevice>>(0)?;
+        info!(
+            "GatePass devices found: number_of_devices={}",
+            gate_pass_devices.len()
+        );
+        Ok(gate_pass_devices)
+    }
+
+    /// Finds the revoked or expired device of the session or of the persistent device id,
+    /// so signing in again on the revoked device does not restore its access.
+    async fn find_revoked_gate_pass_device(
+        &self,
+        session_id: impl ToString,
+        device_id: Option<Cow<'static, str>>,
+    ) -> Result<Option<GatePassDevice>> {
+        let query = format!(
+            "SELECT {GATE_PASS_DEVICE_FIELDS} FROM ONLY api_keys
+            WHERE (sessionid = $session_id OR ($device_id AND device_id = $device_id))
+                AND (revoked OR (expires_at != NONE AND expires_at < time::now()))
+            LIMIT 1"
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "session_id": session_id.to_string(),
+            "device_id": device_id,
+        }));
+
+        Ok(self
+            .execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePassDevice>>(0)?)
+    }
+
+    async fn update_gate_pass_device_synced_at(&self, session_id: impl ToString) -> Result<()> {
+        let query = r#"
+            UPDATE api_keys SET last_synced_at = time::now(), last_used_at = time::now()
+            WHERE sessionid = $session_id AND revoked = false;
+        "#;
+
+        let query_params =
+            QueryParams::from_params(json!({"session_id": session_id.to_string()}));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn revoke_gate_pass_device(
+        &self,
+        device_id: impl ToString,
+        revoked_by: impl ToString,
+    ) -> Result<GatePassDevice> {
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $device_record = SELECT * FROM ONLY type::thing('api_keys', $device_id);
+            IF $device_record = NONE THEN {{
+                RETURN NONE
+            }} END;
+            UPDATE $device_record.id SET
+                revoked = true,
+                revoked_at = time::now(),
+                revoked_by = $revoked_by;
+            IF $device_record.device_id != NONE THEN {{
+                DELETE type::thing('gate_pass_device_keys', $device_record.device_id);
+            }} END;
+            RETURN SELECT {GATE_PASS_DEVICE_FIELDS} FROM ONLY $device_record.id;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "device_id": device_id.to_string(),
+            "revoked_by": revoked_by.to_string(),
+        }));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<GatePassDevice>>(0)
+            .map(|gate_pass_device_opt| match gate_pass_device_opt {
+                Some(gate_pass_device) => {
+                    info!("GatePass device revoked: id={}", &gate_pass_device.id);
+                    Ok(gate_pass_device)
+                }
+                None => Err(DatabaseError::EntryNotFound.into()),
+            })?
+    }
+
+    async fn find_gate_pass_policy(&self) -> Result<GatePassPolicy> {
+        let value = self.get_system_value(GATE_PASS_POLICY_KEY).await?;
+        if value.is_null() {
+            return Ok(GatePassPolicy::default());
+        }
+        Ok(serde_json::from_value::<GatePassPolicy>(value)?)
+    }
+
+    async fn update_gate_pass_policy(&self, policy: GatePassPolicy) -> Result<GatePassPolicy> {
+        self.update_system_value(GATE_PASS_POLICY_KEY, json!(policy))
+            .await?;
+        info!("GatePass policy updated: policy={:?}", policy);
+        Ok(policy)
+    }
+}
+
+fn gate_pass_where_clauses(request: SearchGatePassRequest) -> (String, QueryParams) {
+    let mut where_clauses = String::from("WHERE deleted = false");
+    let id_param_name = "ids";
+    let mut id_param_values = vec![];
+    if let Some(ids) = request.ids.filter(|list| !list.is_empty()) {
+        where_clauses.push_str(&format!(" AND id IN ${} ", id_param_name));
+        id_param_values.extend(gate_pass_record_ids(ids).into_iter());
+    }
+    let last_name_param_name = "owner_last_names";
+    let mut last_name_param_values = vec![];
+    if let Some(last_names) = request.last_names.filter(|list| !list.is_empty()) {
+        where_clauses.push_str(&format!(
+            " AND owner.last_name IN ${} ",
+            last_name_param_name
+        ));
+        last_name_param_values.extend(last_names.into_iter());
+    }
+    let number_plate_param_name = "vehicle_number_plate_names";
+    let mut number_plate_param_values = vec![];
+    if let Some(number_plates) = request.number_plates.filter(|list| !list.is_empty()) {
+        where_clauses.push_str(&format!(
+            " AND vehicles[0].number_plate IN ${} ",
+            number_plate_param_name
+        ));
+        number_plate_param_values.extend(number_plates.into_iter());
+    }
+    let unit_param_name = "owner_units";
+    let mut unit_param_values = vec![];
+    if let Some(units) = request.units.filter(|list| !list.is_empty()) {
+        where_clauses.push_str(&format!(" AND owner.unit IN ${} ", unit_param_name));
+        unit_param_values.extend(units.into_iter());
+    }
+    if request.never_printed.unwrap_or_default() {
+        where_clauses.push_str(
+            " AND !record::exists(type::thing('gate_pass_print_stats', record::id(id))) ",
+        );
+    }
+
+    let query_params = QueryParams {
+        params: json!({
+            last_name_param_name: last_name_param_values,
+            number_plate_param_name: number_plate_param_values,
+            unit_param_name: unit_param_values
+        }),
+        ids: vec![(id_param_name.to_string(), id_param_values)]
+            .into_iter()
+            .collect(),
+    };
+
+    (where_clauses, query_params)
 }
 
 fn gate_pass_record_ids(ids: Vec<Cow<str>>) -> Vec<RecordId> {
@@ -6,6 +6,8 @@ pub trait GroupsRepository {
     async fn find_group(&self, id: impl ToString) -> Result<Group>;
     async fn update_group(&self, payload: Value, by: impl ToString) -> Result<()>;
     async fn delete_group(&self, id: impl ToString) -> Result<()>;
+    async fn is_group_leaf(&self, id: impl ToString) -> Result<bool>;
+    async fn has_group_users(&self, id: impl ToString) -> Result<bool>;
     async fn assign_group_to_user(
         &self,
         id: impl ToString,
@@ -14,9 +16,12 @@ pub trait GroupsRepository {
 }
 
 impl GroupsRepository for Repository {
+    /// Returns the groups ordered as a tree, see [`GroupNode::tree`].
     async fn find_group_list(&self) -> Result<Vec<Entry>> {
         let sql = r#"
-            SELECT id.id() as id, slug, title FROM groups ORDER BY slug;
+            SELECT id.id() as id, slug, title,
+                IF parent != NONE THEN parent.id() ELSE "" END as variant
+            FROM groups ORDER BY slug;
         "#;
 
         self
@@ -24,6 +29,7 @@ impl GroupsRepository for Repository {
             .query(sql)
             .await?
             .take::<Vec<Entry>>(0)
+            .map(GroupNode::tree)
             .map(Ok)?
     }
 
@@ -41,7 +47,8 @@ impl GroupsRepository for Repository {
 
     async fn find_group(&self, id: impl ToString) -> Result<Group> {
         let sql = r#"
-        SELECT *, id.id() as id FROM ONLY type::thing('groups', $group_id);
+        SELECT *, id.id() as id, IF parent != NONE THEN parent.id() ELSE "" END as parent
+        FROM ONLY type::thing('groups', $group_id);
         "#;
 
         self
@@ -57,6 +64,7 @@ impl GroupsRepository for Repository {
         let id = payload["id"].as_str().unwrap_or_default();
         let slug = payload["slug"].as_str().unwrap_or_default();
         let title = payload["title"].as_str().unwrap_or_default();
+        let parent = payload["parent"].as_str().unwrap_or_default();
 
         let mut sql = String::new();
 
@@ -81,6 +89,12 @@ impl GroupsRepository for Repository {
             "#)?
         }
 
+        if payload.contains_key("parent") {
+            sql.write_str(r#"
+            parent: IF $parent THEN type::thing('groups', $parent) ELSE NONE END,
+            "#)?
+        }
+
         sql.write_str(r#"
             updated_by: $by
         };
@@ -92,6 +106,7 @@ impl GroupsRepository for Repository {
             .bind(("group_id", id.to_string()))
             .bind(("slug", slug.to_string()))
             .bind(("title", title.to_string()))
+            .bind(("parent", parent.to_string()))
             .bind(("by", by.to_string()))
             .await?
             .check()?;
@@ -114,6 +129,36 @@ impl GroupsRepository for Repository {
         Ok(())
     }
 
+    /// Returns `true` for an existing group without subgroups, the users are assigned to
+    /// these groups only.
+    async fn is_group_leaf(&self, id: impl ToString) -> Result<bool> {
+        let sql = r#"
+            LET $group = type::thing('groups', $group_id);
+            RETURN record::exists($group) AND count(SELECT 1 FROM groups WHERE parent = $group) = 0;
+        "#;
+
+        Ok(self
+            .database
+            .query(sql)
+            .bind(("group_id", id.to_string()))
+            .await?
+            .take::<Option<bool>>(0)?
+            .unwrap_or_default())
+    }
+
+    async fn has_group_users(&self, id: impl ToString) -> Result<bool> {
+        let sql = r#"
+            RETURN count(SELECT 1 FROM user_groups WHERE out = type::thing('groups', $group_id)) > 0;
+        "#;
+
+        Ok(self
+            .database
+            .query(sql)
+            .bind(("group_id", id.to_string()))
+            .await?
+            .take::<Option<bool>>(0)?
+            .unwrap_or_default())
+    }
 
     async fn assign_group_to_user(&self, id: impl ToString, group: impl ToString) -> Result<()> {
         let id = id.to_string();
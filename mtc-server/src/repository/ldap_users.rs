@@ -0,0 +1,120 @@
+use super::*;
+
+pub trait LdapUserRepository {
+    async fn find_user_ldap_dn(
+        &self,
+        login: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>>;
+
+    async fn sync_ldap_user(
+        &self,
+        ldap_user: &LdapUser,
+        password_hash: Option<Cow<'static, str>>,
+        by: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>>;
+
+    async fn block_missing_ldap_users(
+        &self,
+        dns: Vec<Cow<'static, str>>,
+        by: impl ToString,
+    ) -> Result<Vec<Cow<'static, str>>>;
+}
+
+impl LdapUserRepository for Repository {
+    /// Returns the directory DN of the user, empty for the local account and `None` for
+    /// the unknown login.
+    async fn find_user_ldap_dn(
+        &self,
+        login: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>> {
+        let query = r#"
+            RETURN (SELECT VALUE ldap_dn ?? "" FROM ONLY users WHERE login = $login LIMIT 1);
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"login": login.to_string()}));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<Cow<'static, str>>>(0)?)
+    }
+
+    /// Creates or updates the directory user and replaces its group and roles with the
+    /// mapped ones, the local account of the same login is left untouched and `None`
+    /// is returned for it. The password hash is required for the new user only, the user
+    /// blocked by the sync is unblocked.
+    async fn sync_ldap_user(
+        &self,
+        ldap_user: &LdapUser,
+        password_hash: Option<Cow<'static, str>>,
+        by: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $found = SELECT id, ldap_dn FROM ONLY users WHERE login = $login LIMIT 1;
+            IF $found != NONE AND $found.ldap_dn = NONE THEN {
+                RETURN NONE
+            } END;
+            LET $user_rec = IF $found = NONE {
+                (CREATE ONLY users CONTENT {
+                    login: $login,
+                    password: $password,
+                    password_changed_at: time::now(),
+                    created_by: $by,
+                    updated_by: $by,
+                }).id
+            } ELSE {
+                $found.id
+            };
+            UPDATE $user_rec MERGE { ldap_dn: $dn, updated_by: $by };
+            UPDATE $user_rec SET blocked = false, ldap_blocked = false WHERE ldap_blocked = true;
+            DELETE $user_rec->user_groups;
+            FOR $group_rec IN (SELECT VALUE id FROM groups WHERE slug = $group) {
+                RELATE $user_rec->user_groups->$group_rec;
+            };
+            DELETE $user_rec->user_roles;
+            FOR $role_rec IN (SELECT VALUE id FROM roles WHERE slug IN $roles) {
+                RELATE $user_rec->user_roles->$role_rec;
+            };
+            RETURN $user_rec.id();
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "login": ldap_user.login,
+            "dn": ldap_user.dn,
+            "group": ldap_user.group,
+            "roles": ldap_user.roles,
+            "password": password_hash,
+            "by": by.to_string(),
+        }));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<Cow<'static, str>>>(0)?)
+    }
+
+    /// Blocks the directory users missing from the given DNs, returns their ids.
+    async fn block_missing_ldap_users(
+        &self,
+        dns: Vec<Cow<'static, str>>,
+        by: impl ToString,
+    ) -> Result<Vec<Cow<'static, str>>> {
+        let query = r#"
+            UPDATE users SET blocked = true, ldap_blocked = true, updated_by = $by
+            WHERE ldap_dn != NONE AND blocked = false AND ldap_dn NOT IN $dns
+            RETURN VALUE id.id();
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "dns": dns,
+            "by": by.to_string(),
+        }));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Vec<Cow<'static, str>>>(0)?)
+    }
+}
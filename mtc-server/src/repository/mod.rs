@@ -10,6 +10,15 @@ mod storage_utils;
 mod content;
 mod system;
 mod gate_passes;
+mod gate_pass_dictionaries;
+mod gate_pass_filters;
+mod two_factor;
+mod sign_in_throttles;
+mod user_devices;
+mod user_sessions;
+mod security_audit;
+mod access_tokens;
+mod ldap_users;
 mod base_repository;
 
 pub(crate) mod prelude {
@@ -25,6 +34,15 @@ pub(crate) mod prelude {
         content::*,
         system::*,
         gate_passes::*,
+        gate_pass_dictionaries::*,
+        gate_pass_filters::*,
+        two_factor::*,
+        sign_in_throttles::*,
+        user_devices::*,
+        user_sessions::*,
+        security_audit::*,
+        access_tokens::*,
+        ldap_users::*,
         base_repository::*,
     };
 }
@@ -96,6 +96,8 @@ impl RolesRepository for Repository {
             payload.key_i64("user_access_level").unwrap_or(999);
         let user_access_all =
             payload.key_bool("user_access_all").unwrap_or_default();
+        let two_factor_required =
+            payload.key_bool("two_factor_required").unwrap_or_default();
         let permissions =
             payload.key_obj::<Vec<Cow<'static, str>>>("permissions").unwrap_or_default();
 
@@ -124,6 +126,10 @@ impl RolesRepository for Repository {
             sql.write_str(r#"user_access_all: $user_access_all,"#)?
         }
 
+        if payload.contains_key("two_factor_required") {
+            sql.write_str(r#"two_factor_required: $two_factor_required,"#)?
+        }
+
         sql.write_str(r#"updated_by: $by};"#)?;
 
         sql.write_str("RETURN $rec_id[0].id.id(); COMMIT TRANSACTION;")?;
@@ -136,6 +142,7 @@ impl RolesRepository for Repository {
             .bind(("title", title))
             .bind(("user_access_level", user_access_level))
             .bind(("user_access_all", user_access_all))
+            .bind(("two_factor_required", two_factor_required))
             .bind(("by", by.to_string()))
             .await?
             .take::<Option<Cow<'static, str>>>(0)?;
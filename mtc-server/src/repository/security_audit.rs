@@ -0,0 +1,124 @@
+use super::*;
+
+const SECURITY_AUDIT_EVENT_FIELDS: &str = "*, id.id() as id";
+
+pub trait SecurityAuditRepository {
+    async fn create_security_audit_event(
+        &self,
+        kind: SecurityAuditKind,
+        actor: impl ToString,
+        target: impl ToString,
+        ip: impl ToString,
+        user_agent: impl ToString,
+        details: Option<Value>,
+    ) -> Result<()>;
+
+    async fn search_security_audit_events(
+        &self,
+        request: SearchSecurityAuditRequest,
+    ) -> Result<PageResponse<SecurityAuditEvent>>;
+}
+
+impl SecurityAuditRepository for Repository {
+    async fn create_security_audit_event(
+        &self,
+        kind: SecurityAuditKind,
+        actor: impl ToString,
+        target: impl ToString,
+        ip: impl ToString,
+        user_agent: impl ToString,
+        details: Option<Value>,
+    ) -> Result<()> {
+        let query = r#"
+            CREATE security_audit_events SET
+                kind = $kind,
+                actor = $actor,
+                target = $target,
+                ip = $ip,
+                user_agent = $user_agent,
+                details = $details ?? NONE;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "kind": kind,
+            "actor": actor.to_string(),
+            "target": target.to_string(),
+            "ip": ip.to_string(),
+            "user_agent": user_agent.to_string(),
+            "details": details,
+        }));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn search_security_audit_events(
+        &self,
+        request: SearchSecurityAuditRequest,
+    ) -> Result<PageResponse<SecurityAuditEvent>> {
+        let page_request_opt = request.page_request.clone();
+        let (where_clauses, query_params) = security_audit_where_clauses(request);
+
+        let query = format!(
+            "SELECT {SECURITY_AUDIT_EVENT_FIELDS} FROM security_audit_events {where_clauses}"
+        );
+
+        let mut page_request = PageRequest::new(
+            50,
+            0,
+            vec![OrderBy::new("created_at".to_string(), SortDirection::Desc)],
+        );
+        if let Some((page_size, page_index)) =
+            page_request_opt.map(|page_request| (page_request.page_size, page_request.page_index))
+        {
+            page_request.page_size = page_size;
+            page_request.page_index = page_index;
+        }
+
+        self.execute_query_with_params_and_pagination::<SecurityAuditEvent>(
+            &query,
+            query_params,
+            &page_request,
+        )
+        .await
+    }
+}
+
+fn security_audit_where_clauses(request: SearchSecurityAuditRequest) -> (String, QueryParams) {
+    let mut where_clauses = String::from("WHERE true");
+    let kinds = request.kinds.unwrap_or_default();
+    if !kinds.is_empty() {
+        where_clauses.push_str(" AND kind IN $kinds ");
+    }
+    if request.actor.is_some() {
+        where_clauses.push_str(
+            " AND string::contains(string::lowercase(actor), string::lowercase($actor)) ",
+        );
+    }
+    if request.target.is_some() {
+        where_clauses.push_str(
+            " AND string::contains(string::lowercase(target), string::lowercase($target)) ",
+        );
+    }
+    if request.ip.is_some() {
+        where_clauses.push_str(" AND string::starts_with(ip, $ip) ");
+    }
+    if request.date_from.is_some() {
+        where_clauses.push_str(" AND created_at >= <datetime>(<string>$date_from + 'T00:00:00Z') ");
+    }
+    if request.date_to.is_some() {
+        where_clauses
+            .push_str(" AND created_at < <datetime>(<string>$date_to + 'T00:00:00Z') + 1d ");
+    }
+
+    let query_params = QueryParams::from_params(json!({
+        "kinds": kinds,
+        "actor": request.actor,
+        "target": request.target,
+        "ip": request.ip,
+        "date_from": request.date_from,
+        "date_to": request.date_to,
+    }));
+
+    (where_clauses, query_params)
+}
@@ -0,0 +1,178 @@
+use super::*;
+
+const SIGN_IN_LOCKOUT_FIELDS: &str = r#"
+    *, id.id() as id, (unlocked_at = NONE AND locked_until > time::now()) as active
+"#;
+
+pub trait SignInThrottleRepository {
+    async fn is_sign_in_locked(&self, login: impl ToString, ip: impl ToString) -> Result<bool>;
+
+    async fn record_sign_in_failure(
+        &self,
+        kind: SignInThrottleKind,
+        key: impl ToString,
+        ip: impl ToString,
+        max_failures: i64,
+        lockout_minutes: i64,
+    ) -> Result<SignInFailure>;
+
+    async fn reset_sign_in_failures(&self, login: impl ToString) -> Result<()>;
+
+    async fn find_sign_in_lockouts(&self) -> Result<Vec<SignInLockout>>;
+
+    async fn unlock_sign_in_lockout(
+        &self,
+        lockout_id: impl ToString,
+        unlocked_by: impl ToString,
+    ) -> Result<SignInLockout>;
+}
+
+impl SignInThrottleRepository for Repository {
+    async fn is_sign_in_locked(&self, login: impl ToString, ip: impl ToString) -> Result<bool> {
+        let query = r#"
+            RETURN count(
+                SELECT id FROM sign_in_throttles
+                WHERE ((kind = 'Login' AND key = $login) OR (kind = 'Ip' AND key = $ip))
+                    AND locked_until > time::now()
+            ) > 0;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "login": login.to_string(),
+            "ip": ip.to_string(),
+        }));
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<bool>>(0)
+            .map(|locked| Ok(locked.unwrap_or_default()))?
+    }
+
+    async fn record_sign_in_failure(
+        &self,
+        kind: SignInThrottleKind,
+        key: impl ToString,
+        ip: impl ToString,
+        max_failures: i64,
+        lockout_minutes: i64,
+    ) -> Result<SignInFailure> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $throttle_record = type::thing('sign_in_throttles', [$kind, $key]);
+            LET $window = duration::from::mins($lockout_minutes);
+            LET $current = $throttle_record.*;
+            LET $failures = IF $current.last_failure_at > time::now() - $window THEN
+                $current.failures + 1
+            ELSE
+                1
+            END;
+            LET $locked = $failures >= $max_failures;
+            UPSERT $throttle_record SET
+                kind = $kind,
+                key = $key,
+                failures = $failures,
+                last_failure_at = time::now(),
+                locked_until = IF $locked THEN time::now() + $window ELSE NONE END;
+            IF $locked THEN {
+                CREATE sign_in_lockouts SET
+                    kind = $kind,
+                    key = $key,
+                    ip = $ip,
+                    failures = $failures,
+                    locked_at = time::now(),
+                    locked_until = time::now() + $window;
+            } END;
+            RETURN { failures: $failures, locked: $locked };
+        COMMIT TRANSACTION;
+        "#;
+
+        let key = key.to_string();
+        let query_params = QueryParams::from_params(json!({
+            "kind": kind,
+            "key": key.clone(),
+            "ip": ip.to_string(),
+            "max_failures": max_failures,
+            "lockout_minutes": lockout_minutes,
+        }));
+
+        let failure = self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<SignInFailure>>(0)?
+            .unwrap_or_default();
+
+        if failure.locked {
+            warn!(
+                "Sign-in locked: kind={kind:?}, key={key}, failures={}",
+                failure.failures
+            );
+        }
+        Ok(failure)
+    }
+
+    async fn reset_sign_in_failures(&self, login: impl ToString) -> Result<()> {
+        let query = r#"
+            DELETE type::thing('sign_in_throttles', ['Login', $login]);
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"login": login.to_string()}));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn find_sign_in_lockouts(&self) -> Result<Vec<SignInLockout>> {
+        let query = format!(
+            "SELECT {SIGN_IN_LOCKOUT_FIELDS} FROM sign_in_lockouts
+            ORDER BY locked_at DESC LIMIT 500"
+        );
+
+        Ok(self
+            .execute_query_with_params(&query, QueryParams::default())
+            .await?
+            .take::<Vec<SignInLockout>>(0)?)
+    }
+
+    async fn unlock_sign_in_lockout(
+        &self,
+        lockout_id: impl ToString,
+        unlocked_by: impl ToString,
+    ) -> Result<SignInLockout> {
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $lockout_record = SELECT * FROM ONLY type::thing('sign_in_lockouts', $lockout_id);
+            IF $lockout_record = NONE THEN {{
+                RETURN NONE
+            }} END;
+            DELETE sign_in_throttles
+                WHERE kind = $lockout_record.kind AND key = $lockout_record.key;
+            UPDATE sign_in_lockouts SET
+                unlocked_at = time::now(),
+                unlocked_by = $unlocked_by
+            WHERE kind = $lockout_record.kind AND key = $lockout_record.key AND unlocked_at = NONE;
+            RETURN SELECT {SIGN_IN_LOCKOUT_FIELDS} FROM ONLY $lockout_record.id;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "lockout_id": lockout_id.to_string(),
+            "unlocked_by": unlocked_by.to_string(),
+        }));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<SignInLockout>>(0)
+            .map(|lockout_opt| match lockout_opt {
+                Some(lockout) => {
+                    info!(
+                        "Sign-in lockout unlocked: id={}, kind={:?}, key={}",
+                        &lockout.id, lockout.kind, &lockout.key
+                    );
+                    Ok(lockout)
+                }
+                None => Err(DatabaseError::EntryNotFound.into()),
+            })?
+    }
+}
@@ -1,4 +1,5 @@
 use chrono::Datelike;
+use std::collections::BTreeMap;
 use super::*;
 
 pub trait SystemTrait {
@@ -465,15 +466,18 @@ impl SystemTrait for Repository {
         Ok(())
     }
 
+    /// Returns the users of the groups in the tree order, the counts of a group include the
+    /// users of its descendants.
     async fn get_groups_stat(&self) -> Result<Vec<GroupStat>> {
-        self
+        let groups = self.find_group_list().await?;
+        let counts = self
             .database
             .query(r#"
-            SELECT 
-                title,
+            SELECT
+                id.id() as id,
                 count(<-user_groups<-(users WHERE last_access > $month_start AND blocked = false)) as online,
                 count(<-user_groups<-(users WHERE blocked = false)) as total
-            FROM groups ORDER BY title;
+            FROM groups;
             "#)
             .bind(("month_start",
                    Utc::now()
@@ -484,8 +488,29 @@ impl SystemTrait for Repository {
                        .unwrap()
             ))
             .await?
-            .take::<Vec<GroupStat>>(0)
-            .map(Ok)?
+            .take::<Vec<Value>>(0)?
+            .into_iter()
+            .filter_map(|count| Some((
+                count.key_str("id")?,
+                (count.key_i64("online")?, count.key_i64("total")?),
+            )))
+            .collect::<BTreeMap<Cow<'static, str>, (i64, i64)>>();
+
+        Ok(groups
+            .iter()
+            .map(|group| {
+                let (online, total) = GroupNode::subtree(&groups, &group.id)
+                    .iter()
+                    .filter_map(|id| counts.get(id))
+                    .fold((0, 0), |(online, total), count| (online + count.0, total + count.1));
+                GroupStat {
+                    title: group.title.clone(),
+                    depth: GroupNode::of(group).depth,
+                    online,
+                    total,
+                }
+            })
+            .collect())
     }
 }
 
@@ -0,0 +1,90 @@
+use super::*;
+
+pub trait TwoFactorRepository {
+    async fn find_user_two_factor(&self, user_id: impl ToString) -> Result<Option<UserTwoFactor>>;
+
+    async fn is_user_two_factor_required(&self, user_id: impl ToString) -> Result<bool>;
+
+    async fn update_user_two_factor(
+        &self,
+        user_id: impl ToString,
+        two_factor: UserTwoFactor,
+    ) -> Result<()>;
+
+    async fn delete_user_two_factor(
+        &self,
+        user_id: impl ToString,
+        by: impl ToString,
+    ) -> Result<()>;
+}
+
+impl TwoFactorRepository for Repository {
+    async fn find_user_two_factor(&self, user_id: impl ToString) -> Result<Option<UserTwoFactor>> {
+        let query = r#"
+            RETURN (SELECT VALUE two_factor FROM ONLY type::thing('users', $user_id));
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"user_id": user_id.to_string()}));
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<UserTwoFactor>>(0)
+            .map(Ok)?
+    }
+
+    async fn is_user_two_factor_required(&self, user_id: impl ToString) -> Result<bool> {
+        let query = r#"
+            RETURN array::any(
+                (SELECT VALUE ->user_roles->roles.two_factor_required
+                FROM ONLY type::thing('users', $user_id)) ?? []
+            );
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"user_id": user_id.to_string()}));
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<bool>>(0)
+            .map(|required| Ok(required.unwrap_or_default()))?
+    }
+
+    async fn update_user_two_factor(
+        &self,
+        user_id: impl ToString,
+        two_factor: UserTwoFactor,
+    ) -> Result<()> {
+        let query = r#"
+            UPDATE type::thing('users', $user_id) SET two_factor = $two_factor;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.to_string(),
+            "two_factor": two_factor,
+        }));
+
+        self.execute_query_with_params(query, query_params).await?;
+
+        Ok(())
+    }
+
+    async fn delete_user_two_factor(
+        &self,
+        user_id: impl ToString,
+        by: impl ToString,
+    ) -> Result<()> {
+        let user_id = user_id.to_string();
+        let query = r#"
+            UPDATE type::thing('users', $user_id) SET two_factor = NONE, updated_by = $by;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.clone(),
+            "by": by.to_string(),
+        }));
+
+        self.execute_query_with_params(query, query_params).await?;
+
+        info!("User two-factor reset: user_id={user_id}");
+        Ok(())
+    }
+}
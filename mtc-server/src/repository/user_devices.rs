@@ -0,0 +1,265 @@
+use super::*;
+
+const USER_DEVICE_FIELDS: &str = r#"
+    id.id() as id, os, device, created_at, last_used_at, expires_at,
+    revoked, revoked_at, revoked_by,
+    (revoked = false AND (expires_at = NONE OR expires_at > time::now())) as active,
+    sessionid = $session_id as current
+"#;
+
+pub trait UserDeviceRepository {
+    async fn create_qr_sign_in_token(
+        &self,
+        user_id: impl ToString,
+        token: impl ToString,
+        expires_in_seconds: i64,
+    ) -> Result<()>;
+
+    async fn consume_qr_sign_in_token(
+        &self,
+        token: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>>;
+
+    async fn create_user_device(
+        &self,
+        user_id: impl ToString,
+        session_id: impl ToString,
+        device_id: Option<Cow<'static, str>>,
+        os: impl ToString,
+        device: impl ToString,
+        expires_in_days: i64,
+    ) -> Result<()>;
+
+    async fn find_user_devices(
+        &self,
+        user_id: impl ToString,
+        session_id: impl ToString,
+    ) -> Result<Vec<UserDevice>>;
+
+    async fn revoke_user_devices(
+        &self,
+        user_id: impl ToString,
+        device_id: Option<Cow<'static, str>>,
+        revoked_by: impl ToString,
+    ) -> Result<()>;
+
+    async fn update_user_device_expiry(
+        &self,
+        user_id: impl ToString,
+        device_id: impl ToString,
+        expires_in_days: Option<i64>,
+    ) -> Result<UserDevice>;
+}
+
+impl UserDeviceRepository for Repository {
+    async fn create_qr_sign_in_token(
+        &self,
+        user_id: impl ToString,
+        token: impl ToString,
+        expires_in_seconds: i64,
+    ) -> Result<()> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            DELETE qr_sign_in_tokens WHERE expires_at < time::now();
+            CREATE qr_sign_in_tokens SET
+                token = $token,
+                user = type::thing('users', $user_id),
+                expires_at = time::now() + duration::from::secs($expires_in_seconds);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.to_string(),
+            "token": token.to_string(),
+            "expires_in_seconds": expires_in_seconds,
+        }));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn consume_qr_sign_in_token(
+        &self,
+        token: impl ToString,
+    ) -> Result<Option<Cow<'static, str>>> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $token_record = SELECT * FROM ONLY qr_sign_in_tokens
+                WHERE token = $token AND used_at = NONE AND expires_at > time::now() LIMIT 1;
+            IF $token_record = NONE THEN {
+                RETURN NONE
+            } END;
+            UPDATE $token_record.id SET used_at = time::now();
+            RETURN $token_record.user.id();
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"token": token.to_string()}));
+
+        Ok(self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<Cow<'static, str>>>(0)?)
+    }
+
+    async fn create_user_device(
+        &self,
+        user_id: impl ToString,
+        session_id: impl ToString,
+        device_id: Option<Cow<'static, str>>,
+        os: impl ToString,
+        device: impl ToString,
+        expires_in_days: i64,
+    ) -> Result<()> {
+        let user_id = user_id.to_string();
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $device_record = CREATE ONLY api_keys SET
+                api_key = rand::uuid::v4(),
+                sessionid = $session_id,
+                device_id = $device_id ?? NONE,
+                os = $os,
+                device = $device,
+                is_active = true,
+                last_used_at = time::now(),
+                expires_at = IF $expires_in_days > 0 THEN
+                    time::now() + duration::from::days($expires_in_days)
+                ELSE
+                    NONE
+                END;
+            RELATE (type::thing('users', $user_id))->user_api_keys->($device_record.id);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.clone(),
+            "session_id": session_id.to_string(),
+            "device_id": device_id,
+            "os": os.to_string(),
+            "device": device.to_string(),
+            "expires_in_days": expires_in_days,
+        }));
+
+        self.execute_query_with_params(query, query_params).await?;
+
+        info!("User device linked: user_id={user_id}");
+        Ok(())
+    }
+
+    async fn find_user_devices(
+        &self,
+        user_id: impl ToString,
+        session_id: impl ToString,
+    ) -> Result<Vec<UserDevice>> {
+        let query = format!(
+            "SELECT {USER_DEVICE_FIELDS} FROM type::thing('users', $user_id)->user_api_keys->api_keys
+            WHERE is_active = true ORDER BY created_at DESC"
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.to_string(),
+            "session_id": session_id.to_string(),
+        }));
+
+        Ok(self
+            .execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Vec<UserDevice>>(0)?)
+    }
+
+    async fn revoke_user_devices(
+        &self,
+        user_id: impl ToString,
+        device_id: Option<Cow<'static, str>>,
+        revoked_by: impl ToString,
+    ) -> Result<()> {
+        let user_id = user_id.to_string();
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $device_records = SELECT VALUE id
+                FROM type::thing('users', $user_id)->user_api_keys->api_keys
+                WHERE revoked = false AND (!$device_id OR id = type::thing('api_keys', $device_id));
+            LET $session_ids = SELECT VALUE sessionid FROM $device_records
+                WHERE sessionid != NONE AND sessionid != '';
+            LET $device_ids = SELECT VALUE device_id FROM $device_records WHERE device_id != NONE;
+            DELETE gate_pass_device_keys WHERE record::id(id) IN $device_ids;
+            UPDATE $device_records SET
+                revoked = true,
+                revoked_at = time::now(),
+                revoked_by = $revoked_by;
+            UPDATE user_sessions SET
+                revoked = true,
+                revoked_at = time::now(),
+                revoked_by = $revoked_by
+            WHERE sessionid IN $session_ids AND revoked = false;
+            RETURN count($device_records);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.clone(),
+            "device_id": device_id.clone(),
+            "revoked_by": revoked_by.to_string(),
+        }));
+
+        let number_of_devices = self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<usize>>(0)?
+            .unwrap_or_default();
+
+        if device_id.is_some() && number_of_devices == 0 {
+            Err(DatabaseError::EntryNotFound)?
+        }
+
+        info!("User devices revoked: user_id={user_id}, number_of_devices={number_of_devices}");
+        Ok(())
+    }
+
+    async fn update_user_device_expiry(
+        &self,
+        user_id: impl ToString,
+        device_id: impl ToString,
+        expires_in_days: Option<i64>,
+    ) -> Result<UserDevice> {
+        let query = format!(
+            r#"
+        BEGIN TRANSACTION;
+            LET $device_record = (SELECT VALUE id
+                FROM type::thing('users', $user_id)->user_api_keys->api_keys
+                WHERE id = type::thing('api_keys', $device_id))[0];
+            IF $device_record = NONE THEN {{
+                RETURN NONE
+            }} END;
+            UPDATE $device_record SET expires_at = IF $expires_in_days THEN
+                time::now() + duration::from::days($expires_in_days)
+            ELSE
+                NONE
+            END;
+            RETURN SELECT {USER_DEVICE_FIELDS} FROM ONLY $device_record;
+        COMMIT TRANSACTION;
+        "#
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.to_string(),
+            "device_id": device_id.to_string(),
+            "expires_in_days": expires_in_days,
+            "session_id": "",
+        }));
+
+        self.execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Option<UserDevice>>(0)
+            .map(|device_opt| match device_opt {
+                Some(device) => {
+                    info!(
+                        "User device expiry updated: id={}, expires_at={:?}",
+                        &device.id, &device.expires_at
+                    );
+                    Ok(device)
+                }
+                None => Err(DatabaseError::EntryNotFound.into()),
+            })?
+    }
+}
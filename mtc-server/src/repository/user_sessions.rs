@@ -0,0 +1,240 @@
+use super::*;
+
+const USER_SESSION_FIELDS: &str = r#"
+    id.id() as id, login, ip, user_agent, created_at, last_seen_at, expires_at,
+    revoked, revoked_at, revoked_by,
+    (revoked = false AND expires_at > time::now()) as active,
+    sessionid = $session_id as current
+"#;
+
+pub trait UserSessionRepository {
+    async fn create_user_session(
+        &self,
+        session_id: impl ToString,
+        user_id: impl ToString,
+        login: impl ToString,
+        expiration_minutes: i64,
+    ) -> Result<()>;
+
+    async fn check_user_session(
+        &self,
+        session_id: impl ToString,
+        user_id: impl ToString,
+        login: impl ToString,
+        ip: impl ToString,
+        user_agent: impl ToString,
+        expiration_minutes: i64,
+    ) -> Result<UserSessionStatus>;
+
+    async fn delete_user_session(&self, session_id: impl ToString) -> Result<()>;
+
+    async fn find_user_sessions(
+        &self,
+        user_id: impl ToString,
+        session_id: impl ToString,
+    ) -> Result<Vec<UserSession>>;
+
+    async fn revoke_user_sessions(
+        &self,
+        user_id: impl ToString,
+        user_session_id: Option<Cow<'static, str>>,
+        revoked_by: impl ToString,
+    ) -> Result<()>;
+
+    async fn refresh_user_sessions(&self, user_id: impl ToString) -> Result<()>;
+
+    async fn refresh_role_sessions(&self, role_id: Option<Cow<'static, str>>) -> Result<()>;
+}
+
+impl UserSessionRepository for Repository {
+    async fn create_user_session(
+        &self,
+        session_id: impl ToString,
+        user_id: impl ToString,
+        login: impl ToString,
+        expiration_minutes: i64,
+    ) -> Result<()> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            DELETE user_sessions WHERE sessionid = $session_id OR expires_at < time::now();
+            CREATE user_sessions SET
+                sessionid = $session_id,
+                user = type::thing('users', $user_id),
+                login = $login,
+                expires_at = time::now() + duration::from::mins($expiration_minutes);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "session_id": session_id.to_string(),
+            "user_id": user_id.to_string(),
+            "login": login.to_string(),
+            "expiration_minutes": expiration_minutes,
+        }));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn check_user_session(
+        &self,
+        session_id: impl ToString,
+        user_id: impl ToString,
+        login: impl ToString,
+        ip: impl ToString,
+        user_agent: impl ToString,
+        expiration_minutes: i64,
+    ) -> Result<UserSessionStatus> {
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $expired_device = SELECT VALUE id FROM ONLY api_keys
+                WHERE sessionid = $session_id AND expires_at != NONE AND expires_at < time::now()
+                LIMIT 1;
+            IF $expired_device != NONE THEN {
+                RETURN 'DeviceExpired';
+            } END;
+            LET $current = SELECT * FROM ONLY user_sessions WHERE sessionid = $session_id LIMIT 1;
+            IF $current = NONE THEN {
+                CREATE user_sessions SET
+                    sessionid = $session_id,
+                    user = type::thing('users', $user_id),
+                    login = $login,
+                    ip = $ip,
+                    user_agent = $user_agent,
+                    expires_at = time::now() + duration::from::mins($expiration_minutes);
+                RETURN 'Active';
+            } END;
+            IF $current.revoked THEN {
+                RETURN 'Revoked';
+            } END;
+            IF $current.refresh OR $current.ip != $ip OR $current.last_seen_at < time::now() - 1m THEN {
+                UPDATE $current.id SET
+                    ip = $ip,
+                    user_agent = $user_agent,
+                    refresh = false,
+                    last_seen_at = time::now(),
+                    expires_at = time::now() + duration::from::mins($expiration_minutes);
+            } END;
+            RETURN IF $current.refresh THEN 'Refresh' ELSE 'Active' END;
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "session_id": session_id.to_string(),
+            "user_id": user_id.to_string(),
+            "login": login.to_string(),
+            "ip": ip.to_string(),
+            "user_agent": user_agent.to_string(),
+            "expiration_minutes": expiration_minutes,
+        }));
+
+        self.execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<UserSessionStatus>>(0)
+            .map(|status| Ok(status.unwrap_or_default()))?
+    }
+
+    async fn delete_user_session(&self, session_id: impl ToString) -> Result<()> {
+        let query = r#"
+            DELETE user_sessions WHERE sessionid = $session_id;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"session_id": session_id.to_string()}));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn find_user_sessions(
+        &self,
+        user_id: impl ToString,
+        session_id: impl ToString,
+    ) -> Result<Vec<UserSession>> {
+        let query = format!(
+            "SELECT {USER_SESSION_FIELDS} FROM user_sessions
+            WHERE user = type::thing('users', $user_id) AND expires_at > time::now()
+            ORDER BY last_seen_at DESC"
+        );
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.to_string(),
+            "session_id": session_id.to_string(),
+        }));
+
+        Ok(self
+            .execute_query_with_params(&query, query_params)
+            .await?
+            .take::<Vec<UserSession>>(0)?)
+    }
+
+    async fn revoke_user_sessions(
+        &self,
+        user_id: impl ToString,
+        user_session_id: Option<Cow<'static, str>>,
+        revoked_by: impl ToString,
+    ) -> Result<()> {
+        let user_id = user_id.to_string();
+        let query = r#"
+        BEGIN TRANSACTION;
+            LET $session_records = SELECT VALUE id FROM user_sessions
+                WHERE user = type::thing('users', $user_id) AND revoked = false
+                    AND (!$user_session_id OR id = type::thing('user_sessions', $user_session_id));
+            UPDATE $session_records SET
+                revoked = true,
+                revoked_at = time::now(),
+                revoked_by = $revoked_by;
+            RETURN count($session_records);
+        COMMIT TRANSACTION;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({
+            "user_id": user_id.clone(),
+            "user_session_id": user_session_id.clone(),
+            "revoked_by": revoked_by.to_string(),
+        }));
+
+        let number_of_sessions = self
+            .execute_query_with_params(query, query_params)
+            .await?
+            .take::<Option<usize>>(0)?
+            .unwrap_or_default();
+
+        if user_session_id.is_some() && number_of_sessions == 0 {
+            Err(DatabaseError::EntryNotFound)?
+        }
+
+        if number_of_sessions > 0 {
+            info!(
+                "User sessions revoked: user_id={user_id}, number_of_sessions={number_of_sessions}"
+            );
+        }
+        Ok(())
+    }
+
+    async fn refresh_user_sessions(&self, user_id: impl ToString) -> Result<()> {
+        let query = r#"
+            UPDATE user_sessions SET refresh = true
+            WHERE user = type::thing('users', $user_id) AND revoked = false;
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"user_id": user_id.to_string()}));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+
+    async fn refresh_role_sessions(&self, role_id: Option<Cow<'static, str>>) -> Result<()> {
+        let query = r#"
+            UPDATE user_sessions SET refresh = true
+            WHERE revoked = false AND (
+                !$role_id
+                OR user IN (SELECT VALUE in FROM user_roles WHERE out = type::thing('roles', $role_id))
+            );
+        "#;
+
+        let query_params = QueryParams::from_params(json!({"role_id": role_id}));
+
+        self.execute_query_with_params(query, query_params).await?;
+        Ok(())
+    }
+}
@@ -6,14 +6,30 @@ pub trait UserRepository {
         access: Access,
         login: Option<Cow<'static, str>>,
         archive: Option<bool>,
+        groups: Vec<Cow<'static, str>>,
     ) -> Result<Vec<Entry>>;
     async fn find_user(&self, id: impl ToString, access: Access) -> Result<User>;
     async fn find_user_by_login(&self, login: impl ToString, access: Access) -> Result<User>;
-    async fn find_user_by_api_key(&self, api_key: impl ToString) -> Result<User>;
     async fn set_user_password(
         &self, id: impl ToString,
         password_hash: impl ToString,
     ) -> Result<()>;
+    /// Sets the new password, a one-time password has to be changed on the sign-in
+    /// and is accepted once.
+    async fn change_user_password(
+        &self,
+        id: impl ToString,
+        password_hash: impl ToString,
+        one_time: bool,
+    ) -> Result<()>;
+    /// Marks the one-time password of the user used, returns `false` when it was
+    /// already used.
+    async fn use_one_time_password(&self, id: impl ToString) -> Result<bool>;
+    async fn is_user_password_change_required(
+        &self,
+        id: impl ToString,
+        expiration_days: i64,
+    ) -> Result<bool>;
     async fn find_user_access(&self, login: impl ToString) -> Result<Access>;
     async fn increment_user_access_count(&self, login: impl ToString) -> Result<()>;
     async fn update_user(
@@ -29,28 +45,6 @@ pub trait UserRepository {
         access: Access,
     ) -> Result<Vec<UserDetailsDto>>;
     async fn find_user_state(&self, id: impl ToString) -> Result<Value>;
-    async fn update_user_api_key(
-        &self,
-        id: impl ToString,
-        session: impl ToString,
-        api_key: impl ToString,
-        os: impl ToString,
-        device: impl ToString,
-    ) -> Result<()>;
-    async fn assign_api_key_to_user(
-        &self,
-        id: impl ToString,
-        api_key_id: impl ToString,
-    ) -> Result<()>;
-    async fn create_user_api_key(
-        &self,
-        id: impl ToString,
-        api_key: impl ToString,
-    ) -> Result<Cow<'static, str>>;
-    async fn find_api_key_by_user_id(
-        &self,
-        id: impl ToString,
-    ) -> Result<Cow<'static, str>>;
 }
 
 impl UserRepository for Repository {
@@ -59,11 +53,13 @@ impl UserRepository for Repository {
         access: Access,
         login: Option<Cow<'static, str>>,
         archive: Option<bool>,
+        groups: Vec<Cow<'static, str>>,
     ) -> Result<Vec<Entry>> {
         let mut sql = r#"
             SELECT * FROM (
                 SELECT id.id() as id, login as slug,
                 (SELECT VALUE title FROM ->user_groups->groups)[0] ?? "" as title,
+                (SELECT VALUE id.id() FROM ->user_groups->groups)[0] ?? "" as group,
                 math::max(->user_roles->roles.user_access_level) as access_level,
                 blocked as variant
                 FROM users
@@ -81,6 +77,10 @@ impl UserRepository for Repository {
             sql.write_str(r#" AND login ?~ $login "#)?
         }
 
+        if !groups.is_empty() {
+            sql.write_str(r#" AND group IN $groups "#)?
+        }
+
         sql.write_str("ORDER BY login START 0 LIMIT 50;")?;
 
         self
@@ -88,6 +88,7 @@ impl UserRepository for Repository {
             .query(sql)
             .bind(("access_level", access.level))
             .bind(("login", login))
+            .bind(("groups", groups))
             .await?
             .take::<Vec<Entry>>(0)
             .map(Ok)?
@@ -99,6 +100,7 @@ impl UserRepository for Repository {
                 SELECT
                     *,
                     id.id() as id,
+                    two_factor != NONE as two_factor_enabled,
                     (SELECT VALUE id.id() FROM ->user_groups->groups)[0] ?? "" as group,
                     math::max(->user_roles->roles.user_access_level) as access_level
                 FROM type::thing('users', $id)
@@ -124,6 +126,7 @@ impl UserRepository for Repository {
                 SELECT
                     *,
                     id.id() as id,
+                    two_factor != NONE as two_factor_enabled,
                     (SELECT VALUE id.id() FROM ->user_groups->groups)[0] ?? "" as group,
                     math::max(->user_roles->roles.user_access_level) as access_level
                 FROM users
@@ -143,41 +146,93 @@ impl UserRepository for Repository {
             .ok_or(DatabaseError::EntryNotFound.into())
     }
 
-    async fn find_user_by_api_key(&self, api_key: impl ToString) -> Result<User> {
+    async fn set_user_password(
+        &self,
+        id: impl ToString,
+        password_hash: impl ToString,
+    ) -> Result<()> {
         let sql = r#"
-            SELECT *, id.id() as id,
-            (SELECT VALUE id.id() FROM ->user_groups->groups)[0] ?? "" as group,
-            math::max(->user_roles->roles.user_access_level) as access_level
-            FROM users WHERE $api_key in ->user_api_keys->api_keys.api_key;
-            "#;
+            UPDATE type::thing('users', $id) MERGE {
+                password: $password
+            };
+        "#;
 
-        self.database
-            .query(sql)
-            .bind(("api_key", api_key.to_string()))
-            .await?
-            .take::<Option<User>>(0)?
-            .ok_or(DatabaseError::EntryNotFound.into())
+        self.database.query(sql)
+            .bind(("id", id.to_string()))
+            .bind(("password", password_hash.to_string()))
+            .await?;
+
+        Ok(())
     }
 
-    async fn set_user_password(
+    async fn change_user_password(
         &self,
         id: impl ToString,
         password_hash: impl ToString,
+        one_time: bool,
     ) -> Result<()> {
         let sql = r#"
             UPDATE type::thing('users', $id) MERGE {
-                password: $password
+                password: $password,
+                password_changed_at: time::now(),
+                password_change_required: $one_time,
+                one_time_password: $one_time,
+                one_time_password_used: false
             };
         "#;
 
         self.database.query(sql)
             .bind(("id", id.to_string()))
             .bind(("password", password_hash.to_string()))
-            .await?;
+            .bind(("one_time", one_time))
+            .await?
+            .check()?;
 
         Ok(())
     }
 
+    async fn use_one_time_password(&self, id: impl ToString) -> Result<bool> {
+        let sql = r#"
+            BEGIN TRANSACTION;
+                LET $user = SELECT one_time_password, one_time_password_used
+                    FROM ONLY type::thing('users', $id);
+                IF $user.one_time_password AND $user.one_time_password_used THEN {
+                    RETURN false
+                } END;
+                UPDATE type::thing('users', $id) SET one_time_password_used = true
+                    WHERE one_time_password = true;
+                RETURN true;
+            COMMIT TRANSACTION;
+        "#;
+
+        Ok(self.database.query(sql)
+            .bind(("id", id.to_string()))
+            .await?
+            .take::<Option<bool>>(0)?
+            .unwrap_or_default())
+    }
+
+    async fn is_user_password_change_required(
+        &self,
+        id: impl ToString,
+        expiration_days: i64,
+    ) -> Result<bool> {
+        let sql = r#"
+            RETURN (SELECT VALUE ldap_dn = NONE AND (password_change_required = true OR (
+                $expiration_days > 0
+                AND (password_changed_at ?? created_at)
+                    < time::now() - duration::from::days($expiration_days)
+            )) FROM ONLY type::thing('users', $id));
+        "#;
+
+        Ok(self.database.query(sql)
+            .bind(("id", id.to_string()))
+            .bind(("expiration_days", expiration_days))
+            .await?
+            .take::<Option<bool>>(0)?
+            .unwrap_or_default())
+    }
+
     async fn find_user_access(&self, login: impl ToString) -> Result<Access> {
         let sql = r#"
             SELECT math::min(->user_roles->roles.user_access_level) as level,
@@ -218,21 +273,14 @@ impl UserRepository for Repository {
         let mut password = payload.key_str("password").unwrap_or_default();
         let group = payload.key_str("group").unwrap_or_default();
         let blocked = payload.key_bool("blocked").unwrap_or_default();
+        let password_change_required = payload
+            .key_bool("password_change_required")
+            .unwrap_or_default();
         let roles = payload.key_obj::<Vec<Cow<'static, str>>>("roles")
             .unwrap_or_default();
 
         if !password.is_empty() {
-            let Ok(salt) =
-                SaltString::from_b64(&self.config.security.password_salt) else {
-                Err(SessionError::PasswordHash)?
-            };
-
-            let argon2 = Argon2::default();
-            let Ok(password_hash) = argon2.hash_password(password.as_bytes(), &salt)
-            else {
-                Err(SessionError::PasswordHash)?
-            };
-            password = password_hash.to_string().into();
+            password = password_hash(&self.config, &password)?;
         }
 
         if payload.contains_key("id") && !id.is_empty() {
@@ -250,11 +298,18 @@ impl UserRepository for Repository {
         }
 
         if !password.is_empty() {
-            sql.write_str(r#"password: $password,"#)?
+            sql.write_str(r#"
+                password: $password, password_changed_at: time::now(),
+                one_time_password: false, one_time_password_used: false,
+            "#)?
+        }
+
+        if payload.contains_key("password_change_required") {
+            sql.write_str(r#"password_change_required: $password_change_required,"#)?
         }
 
         if payload.contains_key("blocked") {
-            sql.write_str(r#"blocked: $blocked,"#)?
+            sql.write_str(r#"blocked: $blocked, ldap_blocked: false,"#)?
         }
 
         sql.write_str(r#"updated_by: $by};"#)?;
@@ -268,6 +323,7 @@ impl UserRepository for Repository {
             .bind(("login", login))
             .bind(("password", password))
             .bind(("blocked", blocked))
+            .bind(("password_change_required", password_change_required))
             .bind(("by", by.to_string()))
             .await?
             .take::<Option<Cow<'static, str>>>(0)?;
@@ -350,133 +406,4 @@ impl UserRepository for Repository {
             .take::<Option<Value>>(0)?
             .ok_or(DatabaseError::EntryNotFound.into())
     }
-
-    async fn update_user_api_key(
-        &self,
-        id: impl ToString,
-        api_key: impl ToString,
-        session: impl ToString,
-        os: impl ToString,
-        device: impl ToString,
-    ) -> Result<()> {
-        let id = id.to_string();
-        let api_key = api_key.to_string();
-
-        let mut api_key_id = self.database
-            .query(r#"
-            RETURN (SELECT VALUE id.id() as id
-            FROM api_keys WHERE api_key = $api_key)[0];
-            "#)
-            .bind(("api_key", api_key.clone()))
-            .await?
-            .take::<Option<Cow<'static, str>>>(0)?
-            .unwrap_or_default();
-
-        if api_key_id.is_empty()
-        {
-            api_key_id = self.create_user_api_key(id.clone(), api_key.clone()).await?;
-        };
-
-        let sql = r#"
-        UPDATE type::thing('api_keys', $api_key) MERGE {
-            sessionid: $sessionid,
-            os: $os,
-            device: $device,
-            is_active: true
-        };
-        "#;
-
-        self.database
-            .query(sql)
-            .bind(("api_key", api_key_id))
-            .bind(("sessionid", session.to_string()))
-            .bind(("os", os.to_string()))
-            .bind(("device", device.to_string()))
-            .await?
-            .check()?;
-
-        Ok(())
-    }
-
-    async fn assign_api_key_to_user(
-        &self,
-        id: impl ToString,
-        api_key_id: impl ToString,
-    ) -> Result<()> {
-        let sql = r#"
-            BEGIN TRANSACTION;
-            LET $user_rec = type::thing('users', $user_id);
-            LET $api_key_rec = type::thing('api_keys', $api_key_id);
-            RELATE $user_rec->user_api_keys->$api_key_rec;
-            COMMIT TRANSACTION;
-        "#;
-        self.database
-            .query(sql)
-            .bind(("user_id", id.to_string()))
-            .bind(("api_key_id", api_key_id.to_string()))
-            .await?
-            .check()?;
-
-        Ok(())
-    }
-
-    async fn create_user_api_key(
-        &self,
-        id: impl ToString,
-        api_key: impl ToString,
-    ) -> Result<Cow<'static, str>> {
-        let id = id.to_string();
-
-        let sql = r#"
-            BEGIN TRANSACTION;
-                LET $rec_id = CREATE api_keys CONTENT {
-                    api_key: $api_key
-                };
-                RETURN $rec_id[0].id.id();
-            COMMIT TRANSACTION;
-        "#;
-
-        let Some(api_key_id) = self.database
-            .query(sql)
-            .bind(("api_key", api_key.to_string()))
-            .await?
-            .take::<Option<Cow<'static, str>>>(0)? else {
-            Err(DatabaseError::EntryNotFound)?
-        };
-
-        self.assign_api_key_to_user(
-            id.clone(),
-            api_key_id.clone(),
-        ).await?;
-
-        Ok(api_key_id)
-    }
-
-    async fn find_api_key_by_user_id(&self, id: impl ToString) -> Result<Cow<'static, str>> {
-        let id = id.to_string();
-
-        let sql = r#"
-            (SELECT VALUE ->user_api_keys->(api_keys WHERE is_active = false).api_key
-            FROM type::thing('users', $id))[0][0]
-        "#;
-        if let Some(api_key) = self.database
-            .query(sql)
-            .bind(("id", id.clone()))
-            .await?
-            .take::<Option<Cow<'static, str>>>(0)? {
-            Ok(api_key)
-        } else {
-            let api_key_id = self
-                .create_user_api_key(id, uuid::Uuid::new_v4().to_string())
-                .await?;
-            let sql = r#"
-                SELECT VALUE api_key FROM ONLY type::thing('api_keys', $key_id);
-            "#;
-            self.database.query(sql)
-                .bind(("key_id", api_key_id))
-                .await?
-                .take::<Option<Cow<'static, str>>>(0)?
-                .ok_or(DatabaseError::EntryNotFound.into())
-        }
-    }
 }
\ No newline at end of file
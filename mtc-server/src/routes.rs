@@ -30,6 +30,15 @@ pub fn routes(
         .route("/user/{id}", get(find_user_handler)
             .delete(delete_user_handler)
         )
+        .route("/user/{id}/two-factor", delete(reset_user_two_factor_handler))
+        .route("/user/{id}/password-resets", post(reset_user_password_handler))
+        .route("/user/{id}/devices", get(find_user_devices_handler))
+        .route("/user/{id}/devices/revocations", post(revoke_user_devices_handler))
+        .route("/user/{id}/devices/{device_id}/revocations", post(revoke_user_device_handler))
+        .route("/user/{id}/devices/{device_id}/expirations", post(update_user_device_expiry_handler))
+        .route("/user/{id}/sessions", get(find_user_sessions_handler))
+        .route("/user/{id}/sessions/revocations", post(revoke_user_sessions_handler))
+        .route("/user/{id}/sessions/{session_id}/revocations", post(revoke_user_session_handler))
 
         .route("/roles", get(find_custom_role_list_handler))
         .route("/role", post(update_role_handler))
@@ -48,9 +57,30 @@ pub fn routes(
             .delete(delete_custom_permission_handler)
         )
 
+        .route("/security-audit/searches", post(search_security_audit_handler))
+        .route("/security-audit/exports", post(export_security_audit_handler))
+
         .route("/assets/course", post(find_course_files_handler))
 
         .route("/auth/qr", get(sign_in_qr_code_handler))
+        .route("/auth/two-factor", get(find_two_factor_status_handler)
+            .post(verify_two_factor_handler)
+            .delete(delete_two_factor_handler)
+        )
+        .route("/auth/two-factor/setups", post(create_two_factor_setup_handler))
+        .route("/auth/two-factor/confirmations", post(confirm_two_factor_handler))
+        .route("/auth/two-factor/recovery-codes", post(create_two_factor_recovery_codes_handler))
+        .route("/auth/password-policy", get(find_password_policy_handler))
+        .route("/auth/lockouts", get(find_sign_in_lockouts_handler))
+        .route("/auth/lockouts/{id}/unlocks", post(unlock_sign_in_lockout_handler))
+        .route("/auth/devices", get(find_own_devices_handler))
+        .route("/auth/devices/revocations", post(revoke_own_devices_handler))
+        .route("/auth/devices/{id}/revocations", post(revoke_own_devices_handler))
+        .route("/auth/devices/{id}/expirations", post(update_own_device_expiry_handler))
+        .route("/auth/tokens", get(find_own_access_tokens_handler)
+            .post(create_own_access_token_handler)
+        )
+        .route("/auth/tokens/{id}/revocations", post(revoke_own_access_token_handler))
         .route("/auth", post(sign_in_handler)
             .delete(sign_out_handler)
             .patch(change_password_handler)
@@ -79,12 +109,62 @@ pub fn routes(
         .route(
             "/gate-passes/syncs", post(find_sync_gate_passes_handler),
         )
+        .route(
+            "/gate-passes/offline-keys", get(find_gate_pass_offline_key_handler),
+        )
+        .route(
+            "/gate-passes/blocks", get(find_blocked_gate_passes_handler),
+        )
+        .route(
+            "/gate-passes/reports", post(create_gate_pass_report_handler),
+        )
+        .route(
+            "/gate-passes/devices", get(find_gate_pass_devices_handler),
+        )
+        .route(
+            "/gate-passes/devices/{device_id}/revocations",
+            post(revoke_gate_pass_device_handler),
+        )
+        .route(
+            "/gate-passes/devices/{device_id}/snapshots",
+            post(export_gate_pass_snapshot_handler),
+        )
         .route(
             "/gate-passes/prints", post(print_gate_pass_handler),
         )
+        .route(
+            "/gate-passes/prints/searches", post(search_gate_pass_prints_handler),
+        )
+        .route(
+            "/gate-passes/prints/{print_id}/confirmations",
+            post(confirm_gate_pass_print_handler),
+        )
         .route(
             "/gate-passes/renews", post(renew_gate_pass_handler),
         )
+        .route(
+            "/gate-passes/bulks", post(bulk_gate_pass_handler),
+        )
+        .route(
+            "/gate-passes/filters",
+            get(find_gate_pass_filters_handler).post(create_gate_pass_filter_handler),
+        )
+        .route(
+            "/gate-passes/filters/{filter_id}", delete(delete_gate_pass_filter_handler),
+        )
+        .route(
+            "/gate-passes/policies",
+            get(find_gate_pass_policy_handler).patch(update_gate_pass_policy_handler),
+        )
+        .route(
+            "/gate-passes/dictionaries",
+            get(find_gate_pass_dictionaries_handler).post(create_gate_pass_dictionary_entry_handler),
+        )
+        .route(
+            "/gate-passes/dictionaries/{entry_id}",
+            patch(update_gate_pass_dictionary_entry_handler)
+                .delete(delete_gate_pass_dictionary_entry_handler),
+        )
         .route(
             "/gate-passes/{gate_pass_id}",
             patch(update_gate_pass_handler)
@@ -98,7 +178,13 @@ pub fn routes(
         .route(
             "/gate-passes/{gate_pass_id}/emails", post(send_gate_pass_email_handler),
         )
+        .route(
+            "/gate-passes/{gate_pass_id}/reissues", post(reissue_gate_pass_handler),
+        )
         .route(
             "/gate-passes/{gate_pass_id}/validations", get(find_validation_gate_pass_handler))
+        .layer(from_fn_with_state(state.clone(), middleware_access_token_handler))
+        .layer(from_fn_with_state(state.clone(), middleware_session_check_handler))
+        .layer(from_fn_with_state(state.clone(), middleware_security_audit_handler))
         .with_state(state)
 }
\ No newline at end of file
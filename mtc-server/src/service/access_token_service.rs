@@ -0,0 +1,161 @@
+use crate::error::Error;
+use crate::prelude::{
+    Access, AccessTokenRepository, AppState, AuthState, SessionError, session_user_state,
+};
+use mtc_common::prelude::ROLE_ADMINISTRATOR;
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::future::Future;
+use subtle::ConstantTimeEq;
+
+/// Prefix of the access token value, `mtc_<token id>_<secret>`.
+const ACCESS_TOKEN_PREFIX: &str = "mtc_";
+const ACCESS_TOKEN_SECRET_LENGTH: usize = 40;
+
+/// State of the user authenticated with the access token.
+pub struct AccessTokenState {
+    pub auth_state: AuthState,
+    pub access: Access,
+}
+
+tokio::task_local! {
+    static ACCESS_TOKEN_STATE: AccessTokenState;
+}
+
+/// Runs the request handling on behalf of the access token user, the session of the
+/// request is left untouched.
+pub async fn access_token_scope<F: Future>(state: AccessTokenState, future: F) -> F::Output {
+    ACCESS_TOKEN_STATE.scope(state, future).await
+}
+
+/// Returns the auth state of the access token the request is authenticated with.
+pub fn access_token_auth_state() -> Option<AuthState> {
+    ACCESS_TOKEN_STATE
+        .try_with(|state| state.auth_state.clone())
+        .ok()
+}
+
+/// Returns the access of the access token the request is authenticated with.
+pub fn access_token_access() -> Option<Access> {
+    ACCESS_TOKEN_STATE.try_with(|state| state.access).ok()
+}
+
+/// Generates the secret of the new access token, returns it along with its digest.
+pub fn access_token_secret() -> (String, Cow<'static, str>) {
+    let secret = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(ACCESS_TOKEN_SECRET_LENGTH)
+        .map(char::from)
+        .collect::<String>();
+    let secret_digest = access_token_secret_digest(&secret);
+
+    (secret, secret_digest)
+}
+
+/// SHA-256 digest of the secret, the random secret needs no salt nor slow hashing so
+/// the token is checked cheaply on every request.
+fn access_token_secret_digest(secret: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("{:x}", Sha256::digest(secret.as_bytes())))
+}
+
+/// Composes the value the token is sent with.
+pub fn access_token_value(access_token_id: &str, secret: &str) -> String {
+    format!("{ACCESS_TOKEN_PREFIX}{access_token_id}_{secret}")
+}
+
+/// Splits the token value into the token id and the secret.
+fn access_token_parse(token: &str) -> Option<(&str, &str)> {
+    token
+        .strip_prefix(ACCESS_TOKEN_PREFIX)
+        .and_then(|token| token.split_once('_'))
+        .filter(|(access_token_id, secret)| {
+            !access_token_id.is_empty() && secret.len() == ACCESS_TOKEN_SECRET_LENGTH
+        })
+}
+
+/// Authenticates the access token, the user state is reduced to the token permissions
+/// the user still has and the access to the one of the user roles granting them, the
+/// administrator role is not carried over.
+pub async fn access_token_authenticate(
+    state: &AppState,
+    token: &str,
+) -> Result<AccessTokenState, Error> {
+    let Some((access_token_id, secret)) = access_token_parse(token) else {
+        Err(SessionError::InvalidCredentials)?
+    };
+
+    let Some(credentials) = state
+        .repository
+        .find_access_token_credentials(access_token_id)
+        .await?
+    else {
+        Err(SessionError::InvalidCredentials)?
+    };
+
+    let secret_digest = access_token_secret_digest(secret);
+    if !bool::from(
+        secret_digest
+            .as_bytes()
+            .ct_eq(credentials.secret_digest.as_bytes()),
+    ) {
+        Err(SessionError::InvalidCredentials)?
+    }
+
+    let Ok((mut auth_state, _)) = session_user_state(state, credentials.user_id.clone()).await
+    else {
+        Err(SessionError::UserBlocked)?
+    };
+    auth_state.roles.remove(ROLE_ADMINISTRATOR);
+    auth_state
+        .permissions
+        .retain(|permission| credentials.permissions.contains(permission));
+    let access = state
+        .repository
+        .find_access_token_access(credentials.user_id, credentials.permissions)
+        .await?;
+
+    state
+        .repository
+        .update_access_token_last_used(access_token_id)
+        .await?;
+
+    Ok(AccessTokenState { auth_state, access })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_token_parse_splits_token_value() {
+        let (secret, _) = access_token_secret();
+        let token = access_token_value("abc123", &secret);
+
+        assert_eq!(
+            access_token_parse(&token),
+            Some(("abc123", secret.as_str()))
+        );
+        assert_eq!(
+            access_token_parse(&token[ACCESS_TOKEN_PREFIX.len()..]),
+            None
+        );
+        assert_eq!(access_token_parse(&format!("mtc__{secret}")), None);
+        assert_eq!(access_token_parse("mtc_abc123_short"), None);
+        assert_eq!(access_token_parse("mtc_abc123"), None);
+        assert_eq!(access_token_parse(""), None);
+    }
+
+    #[test]
+    fn access_token_secret_digest_is_hex_sha256() {
+        let (secret, secret_digest) = access_token_secret();
+
+        assert_eq!(secret.len(), ACCESS_TOKEN_SECRET_LENGTH);
+        assert_eq!(secret_digest, access_token_secret_digest(&secret));
+        assert_eq!(
+            access_token_secret_digest("secret"),
+            "2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b"
+        );
+    }
+}
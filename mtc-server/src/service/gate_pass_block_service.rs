@@ -0,0 +1,19 @@
+use crate::prelude::{AppState, Arc, GatePassRepository, error};
+use std::time::Duration;
+
+/// Interval between the checks for the expired gate pass blocks.
+const GATE_PASS_BLOCK_EXPIRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically moves the expired gate pass blocks into the block history, the archived
+/// gate passes get a new `updated_at` so the scanners pick the change up on the next sync.
+pub fn spawn_gate_pass_block_expiry(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(GATE_PASS_BLOCK_EXPIRY_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(error) = state.repository.archive_expired_gate_pass_blocks().await {
+                error!("failed to archive expired gate pass blocks: error={error:?}");
+            }
+        }
+    });
+}
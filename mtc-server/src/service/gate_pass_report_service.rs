@@ -0,0 +1,602 @@
+use crate::error::Error;
+use crate::prelude::GenericError::{InternalError, ReportFontMissing};
+use crate::prelude::error;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use genpdf::elements::{Break, FrameCellDecorator, Paragraph, TableLayout};
+use genpdf::style::Style;
+use genpdf::{Alignment, Document, Element as _, SimplePageDecorator, Size, fonts};
+use itertools::Itertools;
+use mtc_common::prelude::{
+    GatePass, GatePassBlock, GatePassDictionaries, GatePassDictionaryKind, GatePassReportKind,
+    GatePassReportRequest,
+};
+use rust_xlsxwriter::{ExcelDateTime, Format, FormatAlign, FormatBorder, Workbook};
+use std::path::Path;
+
+/// Font family the PDF reports are rendered with, the `REPORT_FONT_PATH` directory
+/// should contain its `-Regular`, `-Bold`, `-Italic` and `-BoldItalic` TTF files. The
+/// fonts are shipped in `data/fonts`.
+pub const GATE_PASS_REPORT_FONT_NAME: &str = "DejaVuSans";
+const GATE_PASS_REPORT_FONT_STYLES: [&str; 4] = ["Regular", "Bold", "Italic", "BoldItalic"];
+/// Indent of the signature hint, it puts the hint under the signature lines.
+const GATE_PASS_REPORT_SIGNATURE_HINT_INDENT: &str = "                              ";
+
+pub enum GatePassReportCell {
+    Text(String),
+    Number(f64),
+    Date(Option<NaiveDate>),
+}
+
+impl GatePassReportCell {
+    fn display(&self) -> String {
+        match self {
+            GatePassReportCell::Text(text) => text.clone(),
+            GatePassReportCell::Number(number) => number.to_string(),
+            GatePassReportCell::Date(date) => date
+                .map(|date| date.format("%d.%m.%Y").to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub struct GatePassReportColumn {
+    pub title: String,
+    /// Column width in characters in the spreadsheet.
+    pub width: f64,
+    /// Relative column width in the PDF table.
+    pub weight: usize,
+}
+
+pub struct GatePassReportSection {
+    pub title: Option<String>,
+    pub rows: Vec<Vec<GatePassReportCell>>,
+}
+
+/// Format independent report content, rendered to XLSX or PDF.
+pub struct GatePassReport {
+    pub title: String,
+    /// Summary line under the title, e.g. the date and the number of gate passes.
+    pub details: String,
+    pub sheet_name: String,
+    pub signatures: Vec<String>,
+    pub signature_hint: String,
+    pub generated_at: DateTime<Utc>,
+    pub columns: Vec<GatePassReportColumn>,
+    pub sections: Vec<GatePassReportSection>,
+}
+
+pub fn gate_pass_report(
+    request: &GatePassReportRequest,
+    gate_passes: Vec<GatePass>,
+    dictionaries: &GatePassDictionaries,
+    now: DateTime<Utc>,
+) -> GatePassReport {
+    let expiring_within_days = request.expiring_within_days();
+    let title = match request.kind {
+        GatePassReportKind::Register => request.t("gate-pass-report-kind-register"),
+        GatePassReportKind::ByUnit => request.t("gate-pass-report-kind-byunit"),
+        GatePassReportKind::Expiring => request
+            .t("gate-pass-report-title-expiring")
+            .replace("{days}", &expiring_within_days.to_string()),
+        GatePassReportKind::Blocked => request.t("gate-pass-report-kind-blocked"),
+    };
+    let column = |key: &str, width: f64, weight: usize| GatePassReportColumn {
+        title: request.t(key),
+        width,
+        weight,
+    };
+
+    let mut columns = vec![
+        column("gate-pass-report-column-row-number", 6., 2),
+        column("gate-pass-report-column-number", 12., 4),
+        column("gate-pass-report-column-owner-title", 18., 6),
+        column("gate-pass-report-column-owner-name", 36., 12),
+        column("gate-pass-report-column-unit", 14., 5),
+        column("gate-pass-report-column-number-plate", 14., 5),
+        column("gate-pass-report-column-vehicle", 28., 9),
+        column("gate-pass-report-column-expired-at", 12., 4),
+    ];
+    match request.kind {
+        GatePassReportKind::Expiring => {
+            columns.push(column("gate-pass-report-column-days-left", 10., 3))
+        }
+        GatePassReportKind::Blocked => columns.extend([
+            column("gate-pass-report-column-block-reason", 30., 9),
+            column("gate-pass-report-column-block-expired-at", 12., 4),
+            column("gate-pass-report-column-blocked-by", 16., 5),
+        ]),
+        GatePassReportKind::Register | GatePassReportKind::ByUnit => {}
+    }
+
+    let expiring_until = now + Duration::days(expiring_within_days);
+    let gate_passes = match request.kind {
+        GatePassReportKind::Register => gate_passes,
+        GatePassReportKind::ByUnit => gate_passes
+            .into_iter()
+            .sorted_by(|left, right| {
+                (&left.owner.unit, &left.owner.last_name)
+                    .cmp(&(&right.owner.unit, &right.owner.last_name))
+            })
+            .collect(),
+        GatePassReportKind::Expiring => gate_passes
+            .into_iter()
+            .filter(|gate_pass| {
+                parse_date_time(&gate_pass.expired_at)
+                    .is_some_and(|expired_at| expired_at > now && expired_at <= expiring_until)
+            })
+            .sorted_by(|left, right| left.expired_at.cmp(&right.expired_at))
+            .collect(),
+        GatePassReportKind::Blocked => gate_passes
+            .into_iter()
+            .filter(|gate_pass| {
+                gate_pass
+                    .block
+                    .as_ref()
+                    .is_some_and(|block| !block.expired_by(now))
+            })
+            .sorted_by(|left, right| {
+                let block_expired_at = |gate_pass: &GatePass| {
+                    gate_pass
+                        .block
+                        .as_ref()
+                        .map(|block| block.expired_at.clone())
+                        .unwrap_or_default()
+                };
+                block_expired_at(left).cmp(&block_expired_at(right))
+            })
+            .collect(),
+    };
+
+    let mut row_number = 0;
+    let mut gate_pass_row = |gate_pass: &GatePass| {
+        row_number += 1;
+        gate_pass_report_row(request.kind, row_number, gate_pass, dictionaries, now)
+    };
+    let sections = if request.kind == GatePassReportKind::ByUnit {
+        gate_passes
+            .iter()
+            .group_by(|gate_pass| gate_pass.owner.unit.clone())
+            .into_iter()
+            .map(|(unit, gate_passes)| GatePassReportSection {
+                title: Some(
+                    request
+                        .t("gate-pass-report-section-unit")
+                        .replace("{unit}", &unit),
+                ),
+                rows: gate_passes.map(&mut gate_pass_row).collect(),
+            })
+            .collect()
+    } else {
+        vec![GatePassReportSection {
+            title: None,
+            rows: gate_passes.iter().map(gate_pass_row).collect(),
+        }]
+    };
+
+    let number_of_gate_passes = sections
+        .iter()
+        .map(|section| section.rows.len())
+        .sum::<usize>();
+    let details = request
+        .t("gate-pass-report-generated-at")
+        .replace("{date}", &now.format("%d.%m.%Y %H:%M").to_string())
+        .replace("{number}", &number_of_gate_passes.to_string());
+
+    GatePassReport {
+        title,
+        details,
+        sheet_name: request.t("gate-pass-report-sheet"),
+        signatures: vec![
+            request.t("gate-pass-report-signature-chief"),
+            request.t("gate-pass-report-signature-executor"),
+        ],
+        signature_hint: format!(
+            "{GATE_PASS_REPORT_SIGNATURE_HINT_INDENT}{}",
+            request.t("gate-pass-report-signature-hint")
+        ),
+        generated_at: now,
+        columns,
+        sections,
+    }
+}
+
+fn gate_pass_report_row(
+    kind: GatePassReportKind,
+    row_number: usize,
+    gate_pass: &GatePass,
+    dictionaries: &GatePassDictionaries,
+    now: DateTime<Utc>,
+) -> Vec<GatePassReportCell> {
+    let owner = &gate_pass.owner;
+    let vehicle = gate_pass.first_vehicle();
+    let mut row = vec![
+        GatePassReportCell::Number(row_number as f64),
+        GatePassReportCell::Text(gate_pass.number.to_string()),
+        GatePassReportCell::Text(
+            dictionaries
+                .print_name(GatePassDictionaryKind::OwnerTitle, owner.title.code())
+                .to_string(),
+        ),
+        GatePassReportCell::Text(format!(
+            "{} {} {}",
+            owner.last_name.to_uppercase(),
+            owner.first_name,
+            owner.middle_name
+        )),
+        GatePassReportCell::Text(owner.unit.to_string()),
+        GatePassReportCell::Text(
+            vehicle
+                .map(|vehicle| vehicle.number_plate.to_string())
+                .unwrap_or_default(),
+        ),
+        GatePassReportCell::Text(
+            vehicle
+                .map(|vehicle| {
+                    [
+                        vehicle.manufacturer.to_string(),
+                        vehicle.model.clone().unwrap_or_default().to_string(),
+                        dictionaries
+                            .print_name(GatePassDictionaryKind::VehicleColor, vehicle.color.code())
+                            .to_string(),
+                    ]
+                    .into_iter()
+                    .filter(|value| !value.is_empty())
+                    .join(", ")
+                })
+                .unwrap_or_default(),
+        ),
+        GatePassReportCell::Date(parse_date(&gate_pass.expired_at)),
+    ];
+    match (kind, gate_pass.block.as_ref()) {
+        (GatePassReportKind::Expiring, _) => {
+            let days_left = parse_date_time(&gate_pass.expired_at)
+                .map(|expired_at| (expired_at - now).num_days())
+                .unwrap_or_default();
+            row.push(GatePassReportCell::Number(days_left as f64));
+        }
+        (GatePassReportKind::Blocked, Some(block)) => row.extend([
+            GatePassReportCell::Text(block_reason(block, dictionaries)),
+            GatePassReportCell::Date(parse_date(&block.expired_at)),
+            GatePassReportCell::Text(block.blocked_by.clone().unwrap_or_default().to_string()),
+        ]),
+        _ => {}
+    }
+    row
+}
+
+fn block_reason(block: &GatePassBlock, dictionaries: &GatePassDictionaries) -> String {
+    block
+        .reason_code
+        .as_ref()
+        .map(|reason_code| {
+            dictionaries
+                .print_name(GatePassDictionaryKind::BlockReason, reason_code)
+                .to_string()
+        })
+        .into_iter()
+        .chain(Some(block.reason.to_string()))
+        .filter(|reason| !reason.trim().is_empty())
+        .join(": ")
+}
+
+fn parse_date_time(value: &str) -> Option<DateTime<Utc>> {
+    value.parse::<DateTime<Utc>>().ok()
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    parse_date_time(value).map(|date_time| date_time.date_naive())
+}
+
+pub fn gate_pass_report_xlsx(report: &GatePassReport) -> crate::prelude::Result<Vec<u8>> {
+    gate_pass_report_workbook(report)
+        .and_then(|mut workbook| workbook.save_to_buffer())
+        .map_err(|error| {
+            error!("failed to render gate pass xlsx report: {error:?}");
+            Error::GenericError(InternalError)
+        })
+}
+
+fn gate_pass_report_workbook(
+    report: &GatePassReport,
+) -> Result<Workbook, rust_xlsxwriter::XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(&report.sheet_name)?;
+    worksheet.set_landscape();
+    worksheet.set_paper_size(9);
+    worksheet.set_print_fit_to_pages(1, 0);
+
+    let last_column = (report.columns.len() - 1) as u16;
+    let title_format = Format::new().set_bold().set_font_size(14);
+    let header_format = Format::new()
+        .set_bold()
+        .set_text_wrap()
+        .set_align(FormatAlign::Center)
+        .set_align(FormatAlign::VerticalCenter)
+        .set_border(FormatBorder::Thin)
+        .set_background_color("#D9D9D9");
+    let section_format = Format::new()
+        .set_bold()
+        .set_border(FormatBorder::Thin)
+        .set_background_color("#F2F2F2");
+    let text_format = Format::new().set_text_wrap().set_border(FormatBorder::Thin);
+    let number_format = Format::new().set_border(FormatBorder::Thin);
+    let date_format = Format::new()
+        .set_num_format("dd.mm.yyyy")
+        .set_border(FormatBorder::Thin);
+
+    worksheet.merge_range(0, 0, 0, last_column, &report.title, &title_format)?;
+    worksheet.write_string(1, 0, &report.details)?;
+
+    let header_row = 3;
+    for (column, report_column) in report.columns.iter().enumerate() {
+        worksheet.set_column_width(column as u16, report_column.width)?;
+        worksheet.write_string_with_format(
+            header_row,
+            column as u16,
+            &report_column.title,
+            &header_format,
+        )?;
+    }
+    worksheet.set_freeze_panes(header_row + 1, 0)?;
+    worksheet.set_repeat_rows(header_row, header_row)?;
+
+    let mut row = header_row;
+    for section in report.sections.iter() {
+        if let Some(title) = section.title.as_ref() {
+            row += 1;
+            worksheet.merge_range(row, 0, row, last_column, title, &section_format)?;
+        }
+        for cells in section.rows.iter() {
+            row += 1;
+            for (column, cell) in cells.iter().enumerate() {
+                let column = column as u16;
+                match cell {
+                    GatePassReportCell::Text(text) => {
+                        worksheet.write_string_with_format(row, column, text, &text_format)?;
+                    }
+                    GatePassReportCell::Number(number) => {
+                        worksheet.write_number_with_format(row, column, *number, &number_format)?;
+                    }
+                    GatePassReportCell::Date(Some(date)) => {
+                        let date = ExcelDateTime::from_ymd(
+                            date.year() as u16,
+                            date.month() as u8,
+                            date.day() as u8,
+                        )?;
+                        worksheet.write_datetime_with_format(row, column, &date, &date_format)?;
+                    }
+                    GatePassReportCell::Date(None) => {
+                        worksheet.write_blank(row, column, &date_format)?;
+                    }
+                }
+            }
+        }
+    }
+    if report
+        .sections
+        .iter()
+        .all(|section| section.title.is_none())
+        && row > header_row
+    {
+        worksheet.autofilter(header_row, 0, row, last_column)?;
+    }
+
+    Ok(workbook)
+}
+
+pub fn gate_pass_report_pdf(
+    report: &GatePassReport,
+    font_path: &str,
+) -> crate::prelude::Result<Vec<u8>> {
+    let missing_fonts = GATE_PASS_REPORT_FONT_STYLES
+        .iter()
+        .map(|style| Path::new(font_path).join(format!("{GATE_PASS_REPORT_FONT_NAME}-{style}.ttf")))
+        .filter(|font_file| !font_file.is_file())
+        .collect::<Vec<_>>();
+    if !missing_fonts.is_empty() {
+        error!(
+            "gate pass report fonts are missing, copy them from data/fonts or set REPORT_FONT_PATH: missing_fonts={missing_fonts:?}"
+        );
+        return Err(Error::GenericError(ReportFontMissing));
+    }
+    let font_family =
+        fonts::from_files(font_path, GATE_PASS_REPORT_FONT_NAME, None).map_err(|error| {
+            error!("failed to load gate pass report fonts: path={font_path}, error={error:?}");
+            Error::GenericError(InternalError)
+        })?;
+    let mut document = Document::new(font_family);
+    document.set_title(report.title.as_str());
+    // A4 landscape
+    document.set_paper_size(Size::new(297, 210));
+    document.set_font_size(8);
+    let mut page_decorator = SimplePageDecorator::new();
+    page_decorator.set_margins(10);
+    page_decorator.set_header(|page| Paragraph::new(format!("{page}")).aligned(Alignment::Right));
+    document.set_page_decorator(page_decorator);
+
+    document.push(
+        Paragraph::new(report.title.as_str())
+            .aligned(Alignment::Center)
+            .styled(Style::new().bold().with_font_size(12)),
+    );
+    document.push(Paragraph::new(report.details.as_str()).aligned(Alignment::Center));
+    document.push(Break::new(1));
+
+    for section in report.sections.iter() {
+        if let Some(title) = section.title.as_ref() {
+            document.push(Paragraph::new(title.as_str()).styled(Style::new().bold()));
+        }
+        let mut table =
+            TableLayout::new(report.columns.iter().map(|column| column.weight).collect());
+        table.set_cell_decorator(FrameCellDecorator::new(true, true, false));
+        let mut header_row = table.row();
+        for column in report.columns.iter() {
+            header_row = header_row.element(
+                Paragraph::new(column.title.as_str())
+                    .styled(Style::new().bold())
+                    .padded(1),
+            );
+        }
+        header_row.push().map_err(pdf_error)?;
+        for cells in section.rows.iter() {
+            let mut table_row = table.row();
+            for cell in cells.iter() {
+                table_row = table_row.element(Paragraph::new(cell.display()).padded(1));
+            }
+            table_row.push().map_err(pdf_error)?;
+        }
+        document.push(table);
+        document.push(Break::new(1));
+    }
+
+    for signature in report.signatures.iter() {
+        document.push(Break::new(1));
+        document.push(Paragraph::new(signature.as_str()));
+        document.push(Paragraph::new(report.signature_hint.as_str()));
+    }
+
+    let mut content = Vec::new();
+    document.render(&mut content).map_err(pdf_error)?;
+    Ok(content)
+}
+
+fn pdf_error(error: genpdf::error::Error) -> Error {
+    error!("failed to render gate pass pdf report: {error:?}");
+    Error::GenericError(InternalError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mtc_common::prelude::GatePassOwner;
+    use std::borrow::Cow;
+
+    fn gate_pass(number: &'static str, unit: &'static str, expired_in_days: i64) -> GatePass {
+        GatePass {
+            id: number.into(),
+            number: number.into(),
+            expired_at: (now() + Duration::days(expired_in_days))
+                .to_rfc3339()
+                .into(),
+            owner: GatePassOwner {
+                last_name: number.into(),
+                unit: unit.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn blocked(mut gate_pass: GatePass, blocked_for_days: i64) -> GatePass {
+        gate_pass.block = Some(GatePassBlock {
+            expired_at: (now() + Duration::days(blocked_for_days))
+                .to_rfc3339()
+                .into(),
+            reason: "lost".into(),
+            ..Default::default()
+        });
+        gate_pass
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-01-10T12:00:00Z".parse().unwrap()
+    }
+
+    fn report(kind: GatePassReportKind, gate_passes: Vec<GatePass>) -> GatePassReport {
+        let request = GatePassReportRequest {
+            kind,
+            expiring_within_days: Some(10),
+            i18n: [
+                (
+                    "gate-pass-report-title-expiring",
+                    "Expiring within {days} days",
+                ),
+                ("gate-pass-report-section-unit", "Unit: {unit}"),
+                (
+                    "gate-pass-report-generated-at",
+                    "As of {date}, gate passes: {number}",
+                ),
+            ]
+            .into_iter()
+            .map(|(key, value)| (Cow::Borrowed(key), Cow::Borrowed(value)))
+            .collect(),
+            ..Default::default()
+        };
+        gate_pass_report(
+            &request,
+            gate_passes,
+            &GatePassDictionaries::default(),
+            now(),
+        )
+    }
+
+    fn numbers(section: &GatePassReportSection) -> Vec<String> {
+        section.rows.iter().map(|row| row[1].display()).collect()
+    }
+
+    #[test]
+    fn expiring_report_keeps_gate_passes_expiring_within_days() {
+        let report = report(
+            GatePassReportKind::Expiring,
+            vec![
+                gate_pass("A-3", "1", 7),
+                gate_pass("A-1", "1", 2),
+                gate_pass("A-2", "1", -1),
+                gate_pass("A-4", "1", 20),
+            ],
+        );
+
+        assert_eq!(report.title, "Expiring within 10 days");
+        assert_eq!(report.details, "As of 10.01.2026 12:00, gate passes: 2");
+        assert_eq!(report.columns.len(), 9);
+        assert_eq!(report.sections.len(), 1);
+        assert_eq!(numbers(&report.sections[0]), vec!["A-1", "A-3"]);
+        assert_eq!(report.sections[0].rows[1][0].display(), "2");
+        assert_eq!(report.sections[0].rows[1][8].display(), "7");
+    }
+
+    #[test]
+    fn blocked_report_keeps_active_blocks() {
+        let report = report(
+            GatePassReportKind::Blocked,
+            vec![
+                blocked(gate_pass("B-1", "1", 30), 5),
+                blocked(gate_pass("B-2", "1", 30), -1),
+                gate_pass("B-3", "1", 30),
+                blocked(gate_pass("B-4", "1", 30), 2),
+            ],
+        );
+
+        assert_eq!(report.title, "gate-pass-report-kind-blocked");
+        assert_eq!(report.columns.len(), 11);
+        assert_eq!(numbers(&report.sections[0]), vec!["B-4", "B-1"]);
+        assert_eq!(report.sections[0].rows[0][8].display(), "lost");
+    }
+
+    #[test]
+    fn by_unit_report_numbers_rows_across_sections() {
+        let report = report(
+            GatePassReportKind::ByUnit,
+            vec![
+                gate_pass("C-2", "2", 30),
+                gate_pass("C-1", "1", 30),
+                gate_pass("C-3", "2", 30),
+            ],
+        );
+
+        let titles = report
+            .sections
+            .iter()
+            .map(|section| section.title.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, vec!["Unit: 1", "Unit: 2"]);
+        assert_eq!(numbers(&report.sections[1]), vec!["C-2", "C-3"]);
+        let row_numbers = report
+            .sections
+            .iter()
+            .flat_map(|section| section.rows.iter().map(|row| row[0].display()))
+            .collect::<Vec<_>>();
+        assert_eq!(row_numbers, vec!["1", "2", "3"]);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::error::Error;
+use crate::prelude::GenericError::InternalError;
+use crate::prelude::{Config, MagicCryptTrait, error, new_magic_crypt};
+use base64::{Engine as _, engine::general_purpose};
+use ed25519_dalek::{Signer, SigningKey};
+use mtc_common::prelude::{
+    GATE_PASS_SNAPSHOT_VERSION, GatePassSnapshot, GatePassSnapshotFile, gate_pass_snapshot_key,
+};
+use std::borrow::Cow;
+
+/// Reads the Ed25519 signing key from the base64 encoded 32 bytes seed
+/// in the `SNAPSHOT_SIGNING_KEY` setting.
+pub fn gate_pass_snapshot_signing_key(config: &Config) -> Option<SigningKey> {
+    let seed = general_purpose::STANDARD
+        .decode(config.security.snapshot_signing_key.as_ref()?.as_bytes())
+        .ok()?
+        .try_into()
+        .ok()?;
+    Some(SigningKey::from_bytes(&seed))
+}
+
+/// Returns the base64 encoded verifying key that the scanners are built with
+/// as `SNAPSHOT_VERIFYING_KEY`.
+pub fn gate_pass_snapshot_verifying_key(config: &Config) -> Option<String> {
+    gate_pass_snapshot_signing_key(config)
+        .map(|signing_key| general_purpose::STANDARD.encode(signing_key.verifying_key().as_bytes()))
+}
+
+/// Encrypts the snapshot for the device with the key derived from the offline key of the
+/// device and signs the encrypted payload, so the scanner verifies the signature before
+/// decrypting anything. No other device can read the snapshot.
+pub fn gate_pass_snapshot_file(
+    snapshot: &GatePassSnapshot,
+    offline_key: &str,
+    config: &Config,
+) -> Result<GatePassSnapshotFile, Error> {
+    let Some(signing_key) = gate_pass_snapshot_signing_key(config) else {
+        error!("failed to sign gate pass snapshot: SNAPSHOT_SIGNING_KEY is missing or invalid");
+        return Err(Error::GenericError(InternalError));
+    };
+    let mcrypt = new_magic_crypt!(gate_pass_snapshot_key(offline_key), 256);
+    let payload = mcrypt.encrypt_str_to_base64(serde_json::to_string(snapshot)?);
+    let signature = signing_key.sign(payload.as_bytes());
+    Ok(GatePassSnapshotFile {
+        version: GATE_PASS_SNAPSHOT_VERSION,
+        payload: Cow::Owned(payload),
+        signature: Cow::Owned(general_purpose::STANDARD.encode(signature.to_bytes())),
+    })
+}
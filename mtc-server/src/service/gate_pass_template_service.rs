@@ -5,7 +5,7 @@ use askama::Template;
 use chrono::{DateTime, Datelike, Utc};
 use itertools::Itertools;
 use mtc_common::prelude::{
-    GatePass, GatePassOwnerTitle, GatePassVehicleNumberPlateType, VehicleColor,
+    GatePass, GatePassDictionaries, GatePassDictionaryKind, GatePassVehicleNumberPlateType,
 };
 
 #[derive(Template)]
@@ -55,6 +55,7 @@ pub struct GatePassFrontHtmlTemplate<'a> {
 pub fn gate_pass_front_html(
     gate_pass: &GatePass,
     qr_code_png_base64: &String,
+    dictionaries: &GatePassDictionaries,
 ) -> crate::prelude::Result<String> {
     let mut vehicle_number_plate_zsu = false;
     let mut vehicle_number_plate_civil = "".to_string();
@@ -80,8 +81,8 @@ pub fn gate_pass_front_html(
         vehicle_number_plate_numbers_zsu: vehicle_number_plate_numbers_zsu.as_ref(),
         vehicle_number_plate_letters_zsu: vehicle_number_plate_letters_zsu.as_ref(),
         qr_code: &qr_code_png_base64,
-        owner_details: &owner_details(&gate_pass),
-        vehicle_details: &vehicle_details(&gate_pass),
+        owner_details: &owner_details(&gate_pass, dictionaries),
+        vehicle_details: &vehicle_details(&gate_pass, dictionaries),
         expired_at_details: &expired_at_details(&gate_pass)?,
     };
 
@@ -91,31 +92,11 @@ pub fn gate_pass_front_html(
     })
 }
 
-fn owner_details(gate_pass: &GatePass) -> String {
-    let title = match gate_pass.owner.title {
-        GatePassOwnerTitle::Soldier => "солдат",
-        GatePassOwnerTitle::SeniorSoldier => "ст. солдат",
-        GatePassOwnerTitle::JuniorSergeant => "мол. сержант",
-        GatePassOwnerTitle::Sergeant => "сержант",
-        GatePassOwnerTitle::SeniorSergeant => "ст. сержант",
-        GatePassOwnerTitle::ChiefSergeant => "гол. сержант",
-        GatePassOwnerTitle::StaffSergeant => "штаб-сержант",
-        GatePassOwnerTitle::MasterSergeant => "майстер-сержант",
-        GatePassOwnerTitle::SeniorMasterSergeant => "ст. майстер-сержант",
-        GatePassOwnerTitle::ChiefMasterSergeant => "гол. майстер-сержант",
-        GatePassOwnerTitle::JuniorLieutenant => "мол. лейтенант",
-        GatePassOwnerTitle::Lieutenant => "лейтенант",
-        GatePassOwnerTitle::SeniorLieutenant => "ст. лейтенант",
-        GatePassOwnerTitle::Captain => "капітан",
-        GatePassOwnerTitle::Major => "майор",
-        GatePassOwnerTitle::LieutenantColonel => "підполковник",
-        GatePassOwnerTitle::Colonel => "полковник",
-        GatePassOwnerTitle::BrigadierGeneral => "бригадний генерал",
-        GatePassOwnerTitle::MajorGeneral => "генерал-майор",
-        GatePassOwnerTitle::LieutenantGeneral => "генерал-лейтенант",
-        GatePassOwnerTitle::General => "генерал",
-        GatePassOwnerTitle::GeneralOfTheArmyOfUkraine => "генерал армії",
-    };
+fn owner_details(gate_pass: &GatePass, dictionaries: &GatePassDictionaries) -> String {
+    let title = dictionaries.print_name(
+        GatePassDictionaryKind::OwnerTitle,
+        gate_pass.owner.title.code(),
+    );
     format!(
         "{} {} {} {}",
         title,
@@ -125,24 +106,11 @@ fn owner_details(gate_pass: &GatePass) -> String {
     )
 }
 
-fn vehicle_details(gate_pass: &GatePass) -> String {
-    let color = match gate_pass.vehicles.first().unwrap().color {
-        VehicleColor::White => "білий",
-        VehicleColor::Beige => "бежевий",
-        VehicleColor::Grey => "сірий",
-        VehicleColor::DarkGrey => "темно-сірий",
-        VehicleColor::Blue => "синій",
-        VehicleColor::DarkBlue => "темно-синій",
-        VehicleColor::Purple => "фіолетовий",
-        VehicleColor::Red => "красний",
-        VehicleColor::DarkRed => "темно-красний",
-        VehicleColor::Green => "зелений",
-        VehicleColor::DarkGreen => "темно-зелений",
-        VehicleColor::Yellow => "жовтий",
-        VehicleColor::Orange => "помаранчевий",
-        VehicleColor::Brown => "коричневий",
-        VehicleColor::Black => "чорний",
-    };
+fn vehicle_details(gate_pass: &GatePass, dictionaries: &GatePassDictionaries) -> String {
+    let color = dictionaries.print_name(
+        GatePassDictionaryKind::VehicleColor,
+        gate_pass.vehicles.first().unwrap().color.code(),
+    );
     format!(
         "{} {}, {}, {}",
         gate_pass.vehicles.first().unwrap().manufacturer,
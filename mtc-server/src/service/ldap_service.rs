@@ -0,0 +1,378 @@
+use crate::error::Error;
+use crate::prelude::{
+    AppState, Arc, LdapConfig, LdapError, LdapUser, LdapUserRepository, error, info, json,
+    password_hash, security_audit, session_user_changed, warn,
+};
+use ldap3::{Ldap, LdapConnAsync, LdapConnSettings, Scope, SearchEntry, ldap_escape};
+use mtc_common::prelude::SecurityAuditKind;
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use std::borrow::Cow;
+use std::time::Duration;
+
+/// Author of the user changes made by the directory.
+const LDAP_SYNC_BY: &str = "ldap";
+/// Length of the random local password of the directory user, it is never used to sign in.
+const LDAP_PASSWORD_LENGTH: usize = 40;
+
+/// Authenticates the user against the directory and syncs it, returns the user id or
+/// `None` when the directory rejects the credentials.
+pub async fn ldap_sign_in(
+    state: &AppState,
+    login: &str,
+    password: &str,
+) -> Result<Option<Cow<'static, str>>, Error> {
+    let Some(ldap_user) = ldap_authenticate(&state.config.ldap, login, password).await? else {
+        return Ok(None);
+    };
+
+    ldap_sync_user(state, &ldap_user).await
+}
+
+/// Periodically syncs the directory users, does nothing unless both the directory and
+/// the sync interval are configured.
+pub fn spawn_ldap_sync(state: Arc<AppState>) {
+    let ldap = &state.config.ldap;
+    if !ldap.is_enabled() || ldap.sync_interval == 0 {
+        return;
+    }
+
+    let period = Duration::from_secs(ldap.sync_interval * 60);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            if let Err(error) = ldap_sync(&state).await {
+                error!("failed to sync LDAP directory: error={error:?}");
+            }
+        }
+    });
+}
+
+/// Creates or updates the directory users with the mapped groups and blocks the directory
+/// users that are gone from the directory or have no mapped roles left. A search without
+/// any mapped user is taken for a misconfiguration and blocks nobody.
+pub async fn ldap_sync(state: &AppState) -> Result<(), Error> {
+    let config = &state.config.ldap;
+    let mut ldap = ldap_connect(config).await?;
+    let entries = ldap_search(&mut ldap, config, &config.user_filter).await;
+    let _ = ldap.unbind().await;
+
+    let ldap_users = entries?
+        .into_iter()
+        .filter_map(|entry| ldap_user_from_entry(config, entry))
+        .collect::<Vec<LdapUser>>();
+    if ldap_users.is_empty() {
+        warn!("LDAP directory sync found no users of the mapped groups, nothing is changed");
+        return Ok(());
+    }
+
+    let by = Cow::Borrowed(LDAP_SYNC_BY);
+    let mut dns = vec![];
+    let mut synced = 0;
+    for ldap_user in ldap_users {
+        match ldap_sync_user(state, &ldap_user).await {
+            Ok(Some(user_id)) => {
+                synced += 1;
+                session_user_changed(state, user_id, false, by.clone()).await?;
+            }
+            Ok(None) => warn!(
+                "LDAP user skipped, the login belongs to a local account: login={}",
+                ldap_user.login
+            ),
+            Err(error) => error!(
+                "failed to sync LDAP user: login={}, error={error:?}",
+                ldap_user.login
+            ),
+        }
+        dns.push(ldap_user.dn);
+    }
+
+    let blocked = state
+        .repository
+        .block_missing_ldap_users(dns, LDAP_SYNC_BY)
+        .await?;
+    for user_id in &blocked {
+        session_user_changed(state, user_id.clone(), true, by.clone()).await?;
+    }
+
+    info!(
+        "LDAP directory synced: users={synced}, blocked={}",
+        blocked.len()
+    );
+    security_audit(
+        state,
+        SecurityAuditKind::DirectorySync,
+        LDAP_SYNC_BY,
+        config.url.as_deref().unwrap_or_default(),
+        Some(json!({ "synced": synced, "blocked": blocked })),
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Binds as the directory user found by the login, returns it when the password is
+/// accepted and the user is a member of a mapped group.
+async fn ldap_authenticate(
+    config: &LdapConfig,
+    login: &str,
+    password: &str,
+) -> Result<Option<LdapUser>, LdapError> {
+    // an empty password makes an unauthenticated bind which always succeeds
+    if password.is_empty() {
+        return Ok(None);
+    }
+
+    let mut ldap = ldap_connect(config).await?;
+    let filter = format!(
+        "(&{}({}={}))",
+        config.user_filter,
+        config.login_attribute,
+        ldap_escape(login)
+    );
+    let entries = ldap_search(&mut ldap, config, &filter).await;
+
+    let ldap_user = match entries {
+        Ok(mut entries) if entries.len() == 1 => ldap_user_from_entry(config, entries.remove(0)),
+        Ok(_) => None,
+        Err(error) => {
+            let _ = ldap.unbind().await;
+            return Err(error);
+        }
+    };
+
+    let authenticated = match &ldap_user {
+        Some(ldap_user) => ldap
+            .simple_bind(&ldap_user.dn, password)
+            .await
+            .is_ok_and(|result| result.success().is_ok()),
+        None => false,
+    };
+    let _ = ldap.unbind().await;
+
+    Ok(ldap_user.filter(|_| authenticated))
+}
+
+/// Creates or updates the directory user, returns `None` for the login of a local account.
+async fn ldap_sync_user(
+    state: &AppState,
+    ldap_user: &LdapUser,
+) -> Result<Option<Cow<'static, str>>, Error> {
+    let password_hash = match state
+        .repository
+        .find_user_ldap_dn(ldap_user.login.clone())
+        .await?
+    {
+        Some(ldap_dn) if ldap_dn.is_empty() => return Ok(None),
+        Some(_) => None,
+        None => {
+            let password = rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(LDAP_PASSWORD_LENGTH)
+                .map(char::from)
+                .collect::<String>();
+            Some(password_hash(&state.config, &password)?)
+        }
+    };
+
+    state
+        .repository
+        .sync_ldap_user(ldap_user, password_hash, LDAP_SYNC_BY)
+        .await
+}
+
+/// Connects to the directory and binds as the service account.
+async fn ldap_connect(config: &LdapConfig) -> Result<Ldap, LdapError> {
+    let Some(url) = config.url.as_deref() else {
+        Err(LdapError::NotConfigured)?
+    };
+
+    let settings = LdapConnSettings::new().set_conn_timeout(Duration::from_secs(config.timeout));
+    let (connection, mut ldap) = LdapConnAsync::with_settings(settings, url).await?;
+    tokio::spawn(async move {
+        if let Err(error) = connection.drive().await {
+            warn!("LDAP connection failed: error={error:?}");
+        }
+    });
+
+    ldap.simple_bind(&config.bind_dn, &config.bind_password)
+        .await?
+        .success()?;
+
+    Ok(ldap)
+}
+
+async fn ldap_search(
+    ldap: &mut Ldap,
+    config: &LdapConfig,
+    filter: &str,
+) -> Result<Vec<SearchEntry>, LdapError> {
+    let (entries, _) = ldap
+        .search(
+            &config.user_base_dn,
+            Scope::Subtree,
+            filter,
+            vec![
+                config.login_attribute.as_ref(),
+                config.group_attribute.as_ref(),
+            ],
+        )
+        .await?
+        .success()?;
+
+    Ok(entries.into_iter().map(SearchEntry::construct).collect())
+}
+
+/// Maps the directory entry to the user, `None` when the entry has no login or no mapped
+/// roles. The first mapped group of the user wins, the roles of all the mapped groups are
+/// combined.
+fn ldap_user_from_entry(config: &LdapConfig, entry: SearchEntry) -> Option<LdapUser> {
+    let attribute = |name: &str| {
+        entry
+            .attrs
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, values)| values.clone())
+            .unwrap_or_default()
+    };
+
+    let login = attribute(&config.login_attribute)
+        .first()?
+        .to_uppercase()
+        .replace(' ', "");
+    if login.is_empty() {
+        return None;
+    }
+    let group_dns = attribute(&config.group_attribute)
+        .iter()
+        .map(|group_dn| group_dn.to_lowercase())
+        .collect::<Vec<String>>();
+
+    let mut ldap_user = LdapUser {
+        dn: Cow::Owned(entry.dn.clone()),
+        login: Cow::Owned(login),
+        ..Default::default()
+    };
+    for mapping in &config.group_mappings {
+        if !group_dns
+            .iter()
+            .any(|group_dn| group_dn == mapping.group_dn.as_ref())
+        {
+            continue;
+        }
+        if ldap_user.group.is_none() {
+            ldap_user.group = mapping.group.clone();
+        }
+        for role in &mapping.roles {
+            if !ldap_user.roles.contains(role) {
+                ldap_user.roles.push(role.clone());
+            }
+        }
+    }
+
+    (!ldap_user.roles.is_empty()).then_some(ldap_user)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::LdapGroupMapping;
+    use std::collections::HashMap;
+
+    fn config() -> LdapConfig {
+        LdapConfig {
+            url: Some("ldap://localhost".into()),
+            bind_dn: "".into(),
+            bind_password: "".into(),
+            user_base_dn: "ou=users,dc=mtc".into(),
+            user_filter: "(objectClass=person)".into(),
+            login_attribute: "uid".into(),
+            group_attribute: "memberOf".into(),
+            group_mappings: vec![
+                LdapGroupMapping {
+                    group_dn: "cn=instructors,dc=mtc".into(),
+                    group: Some("instructors".into()),
+                    roles: vec!["writer".into()],
+                },
+                LdapGroupMapping {
+                    group_dn: "cn=staff,dc=mtc".into(),
+                    group: Some("staff".into()),
+                    roles: vec!["writer".into(), "reader".into()],
+                },
+                LdapGroupMapping {
+                    group_dn: "cn=nobody,dc=mtc".into(),
+                    group: Some("nobody".into()),
+                    roles: vec![],
+                },
+            ],
+            sync_interval: 0,
+            timeout: 10,
+        }
+    }
+
+    fn entry(attrs: &[(&str, &[&str])]) -> SearchEntry {
+        SearchEntry {
+            dn: "uid=ivanenko,ou=users,dc=mtc".to_string(),
+            attrs: attrs
+                .iter()
+                .map(|(name, values)| {
+                    (
+                        name.to_string(),
+                        values.iter().map(|value| value.to_string()).collect(),
+                    )
+                })
+                .collect::<HashMap<String, Vec<String>>>(),
+            bin_attrs: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn ldap_user_from_entry_maps_groups_and_roles() {
+        let ldap_user = ldap_user_from_entry(
+            &config(),
+            entry(&[
+                ("UID", &["ivan enko"]),
+                ("memberOf", &["CN=Staff,DC=mtc", "cn=instructors,dc=mtc"]),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(ldap_user.dn, "uid=ivanenko,ou=users,dc=mtc");
+        assert_eq!(ldap_user.login, "IVANENKO");
+        // the first mapping the user is a member of gives the group
+        assert_eq!(ldap_user.group, Some("instructors".into()));
+        assert_eq!(
+            ldap_user.roles,
+            vec![Cow::from("writer"), Cow::from("reader")]
+        );
+    }
+
+    #[test]
+    fn ldap_user_from_entry_skips_unmapped_entries() {
+        let config = config();
+
+        assert_eq!(
+            ldap_user_from_entry(&config, entry(&[("memberOf", &["cn=staff,dc=mtc"])])),
+            None
+        );
+        assert_eq!(
+            ldap_user_from_entry(
+                &config,
+                entry(&[("uid", &[" "]), ("memberOf", &["cn=staff,dc=mtc"])])
+            ),
+            None
+        );
+        assert_eq!(
+            ldap_user_from_entry(
+                &config,
+                entry(&[("uid", &["petrenko"]), ("memberOf", &["cn=nobody,dc=mtc"])])
+            ),
+            None
+        );
+        assert_eq!(
+            ldap_user_from_entry(&config, entry(&[("uid", &["petrenko"])])),
+            None
+        );
+    }
+}
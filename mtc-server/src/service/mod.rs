@@ -1,6 +1,21 @@
+mod access_token_service;
+mod gate_pass_block_service;
 mod gate_pass_mail_service;
+mod gate_pass_report_service;
+mod gate_pass_snapshot_service;
 mod gate_pass_template_service;
+mod ldap_service;
+mod password_service;
+mod security_audit_service;
+mod session_service;
+mod sign_in_throttle_service;
+mod two_factor_service;
 
 pub(crate) mod prelude {
-    pub(crate) use super::{gate_pass_mail_service::*, gate_pass_template_service::*};
+    pub(crate) use super::{
+        access_token_service::*, gate_pass_block_service::*, gate_pass_mail_service::*,
+        gate_pass_report_service::*, gate_pass_snapshot_service::*, gate_pass_template_service::*,
+        ldap_service::*, password_service::*, security_audit_service::*, session_service::*,
+        sign_in_throttle_service::*, two_factor_service::*,
+    };
 }
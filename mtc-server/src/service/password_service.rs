@@ -0,0 +1,212 @@
+use crate::error::Error;
+use crate::prelude::{
+    Argon2, Config, PasswordHash, PasswordHasher, PasswordVerifier, Rng, SaltString, SessionError,
+    error,
+};
+use argon2::{Algorithm, Params, Version};
+use mtc_common::prelude::PasswordPolicy;
+use rand::seq::SliceRandom;
+use std::borrow::Cow;
+use validator::{ValidationError, ValidationErrors};
+
+/// Length of the random per-hash salt, bytes.
+const PASSWORD_SALT_LENGTH: usize = 16;
+
+/// Minimal length of the generated passwords.
+const PASSWORD_GENERATED_LENGTH: usize = 10;
+
+const PASSWORD_UPPERCASE_CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+const PASSWORD_LOWERCASE_CHARSET: &[u8] = b"abcdefghjkmnpqrstuvwxyz";
+const PASSWORD_DIGIT_CHARSET: &[u8] = b"23456789";
+const PASSWORD_SYMBOL_CHARSET: &[u8] = b"!#$%&*+-=?@_";
+
+/// Outcome of the password check against the stored hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordCheck {
+    Invalid,
+    Valid,
+    /// The password matches a hash made with the global salt or outdated Argon2
+    /// parameters, it has to be hashed again.
+    ValidOutdated,
+}
+
+fn password_hasher(config: &Config) -> Result<Argon2<'static>, Error> {
+    let security = &config.security;
+    let params = Params::new(
+        security.argon2_memory_cost,
+        security.argon2_time_cost,
+        security.argon2_parallelism,
+        None,
+    )
+    .map_err(|error| {
+        error!("invalid Argon2 parameters: error={error:?}");
+        SessionError::PasswordHash
+    })?;
+
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+/// Hashes the password with a random salt and the configured Argon2 parameters.
+pub fn password_hash(config: &Config, password: &str) -> Result<Cow<'static, str>, Error> {
+    let salt_bytes = rand::rng().random::<[u8; PASSWORD_SALT_LENGTH]>();
+    let Ok(salt) = SaltString::encode_b64(&salt_bytes) else {
+        Err(SessionError::PasswordHash)?
+    };
+
+    password_hasher(config)?
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| Cow::Owned(hash.to_string()))
+        .map_err(|_| SessionError::PasswordHash.into())
+}
+
+/// Checks the password against the stored hash, the hash parameters are taken from
+/// the hash itself so the hashes of the former schemes are still accepted.
+pub fn password_verify(
+    config: &Config,
+    password: &str,
+    stored_hash: &str,
+) -> Result<PasswordCheck, Error> {
+    let Ok(hash) = PasswordHash::new(stored_hash) else {
+        Err(SessionError::PasswordHash)?
+    };
+
+    if Argon2::default()
+        .verify_password(password.as_bytes(), &hash)
+        .is_err()
+    {
+        return Ok(PasswordCheck::Invalid);
+    }
+
+    let security = &config.security;
+    let global_salt = hash
+        .salt
+        .is_some_and(|salt| salt.as_str() == security.password_salt);
+    let current_params = hash.algorithm.as_str() == Algorithm::Argon2id.as_str()
+        && Params::try_from(&hash).is_ok_and(|params| {
+            params.m_cost() == security.argon2_memory_cost
+                && params.t_cost() == security.argon2_time_cost
+                && params.p_cost() == security.argon2_parallelism
+        });
+
+    if global_salt || !current_params {
+        return Ok(PasswordCheck::ValidOutdated);
+    }
+    Ok(PasswordCheck::Valid)
+}
+
+/// Password policy built from the security configuration.
+pub fn password_policy(config: &Config) -> PasswordPolicy {
+    let security = &config.security;
+    PasswordPolicy {
+        min_length: security.password_min_length,
+        uppercase: security.password_require_uppercase,
+        lowercase: security.password_require_lowercase,
+        digit: security.password_require_digit,
+        symbol: security.password_require_symbol,
+        expiration_days: security.password_expiration,
+    }
+}
+
+/// Fails with the broken rules as the validation errors of the `password` field.
+pub fn password_check_policy(config: &Config, password: &str) -> Result<(), Error> {
+    let violations = password_policy(config).violations(password);
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let mut errors = ValidationErrors::new();
+    violations
+        .into_iter()
+        .for_each(|violation| errors.add("password", ValidationError::new(violation)));
+    Err(errors.into())
+}
+
+/// Generates a random password satisfying the password policy, the look-alike
+/// characters are left out.
+pub fn password_generate(config: &Config) -> Cow<'static, str> {
+    let policy = password_policy(config);
+    let mut rng = rand::rng();
+    let mut one_char = |charset: &[u8]| charset[rng.random_range(0..charset.len())] as char;
+
+    let mut password = [
+        (policy.uppercase, PASSWORD_UPPERCASE_CHARSET),
+        (policy.lowercase, PASSWORD_LOWERCASE_CHARSET),
+        (policy.digit, PASSWORD_DIGIT_CHARSET),
+        (policy.symbol, PASSWORD_SYMBOL_CHARSET),
+    ]
+    .into_iter()
+    .filter(|(required, _)| *required)
+    .map(|(_, charset)| one_char(charset))
+    .collect::<Vec<char>>();
+
+    let charset = [
+        PASSWORD_UPPERCASE_CHARSET,
+        PASSWORD_LOWERCASE_CHARSET,
+        PASSWORD_DIGIT_CHARSET,
+    ]
+    .concat();
+    while password.len() < policy.min_length.max(PASSWORD_GENERATED_LENGTH) {
+        password.push(one_char(&charset));
+    }
+    password.shuffle(&mut rand::rng());
+
+    Cow::Owned(password.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let mut config = Config::init();
+        config.security.password_salt = "c2FsdHNhbHRzYWx0".into();
+        config.security.argon2_memory_cost = 1024;
+        config.security.argon2_time_cost = 1;
+        config.security.argon2_parallelism = 1;
+        config
+    }
+
+    #[test]
+    fn password_verify_accepts_current_hash() {
+        let config = config();
+        let hash = password_hash(&config, "Secret123").unwrap();
+
+        assert_eq!(
+            password_verify(&config, "Secret123", &hash).unwrap(),
+            PasswordCheck::Valid
+        );
+        assert_eq!(
+            password_verify(&config, "Secret124", &hash).unwrap(),
+            PasswordCheck::Invalid
+        );
+    }
+
+    #[test]
+    fn password_verify_asks_to_rehash_outdated_hashes() {
+        let mut config = config();
+        let global_salt = SaltString::from_b64(&config.security.password_salt).unwrap();
+        let global_salt_hash = password_hasher(&config)
+            .unwrap()
+            .hash_password(b"Secret123", &global_salt)
+            .unwrap()
+            .to_string();
+        let hash = password_hash(&config, "Secret123").unwrap();
+
+        assert_eq!(
+            password_verify(&config, "Secret123", &global_salt_hash).unwrap(),
+            PasswordCheck::ValidOutdated
+        );
+
+        config.security.argon2_time_cost = 2;
+        assert_eq!(
+            password_verify(&config, "Secret123", &hash).unwrap(),
+            PasswordCheck::ValidOutdated
+        );
+        // the rehash with the current parameters is accepted as is
+        let rehash = password_hash(&config, "Secret123").unwrap();
+        assert_eq!(
+            password_verify(&config, "Secret123", &rehash).unwrap(),
+            PasswordCheck::Valid
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use crate::prelude::{AppState, SecurityAuditRepository, error};
+use mtc_common::prelude::{SecurityAuditEvent, SecurityAuditKind};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::future::Future;
+
+/// Client of the request being handled, recorded with every audit event.
+pub struct SecurityAuditContext {
+    pub ip: Cow<'static, str>,
+    pub user_agent: Cow<'static, str>,
+    denied_permission: RefCell<Option<(Cow<'static, str>, Cow<'static, str>)>>,
+}
+
+tokio::task_local! {
+    static SECURITY_AUDIT_CONTEXT: SecurityAuditContext;
+}
+
+/// Runs the request handling with the client details available to the audit events.
+pub async fn security_audit_scope<F: Future>(
+    ip: Cow<'static, str>,
+    user_agent: Cow<'static, str>,
+    future: F,
+) -> F::Output {
+    let context = SecurityAuditContext {
+        ip,
+        user_agent,
+        denied_permission: RefCell::new(None),
+    };
+    SECURITY_AUDIT_CONTEXT.scope(context, future).await
+}
+
+/// Marks the request of the user as denied for the lack of the permission, the event
+/// is written once the request is handled.
+pub fn security_audit_permission_denied(login: &str, permission: &str) {
+    let _ = SECURITY_AUDIT_CONTEXT.try_with(|context| {
+        context.denied_permission.replace(Some((
+            Cow::Owned(login.to_string()),
+            Cow::Owned(permission.to_string()),
+        )));
+    });
+}
+
+/// Takes the login and the permission the request was denied for.
+pub fn security_audit_denied_permission() -> Option<(Cow<'static, str>, Cow<'static, str>)> {
+    SECURITY_AUDIT_CONTEXT
+        .try_with(|context| context.denied_permission.take())
+        .ok()
+        .flatten()
+}
+
+/// Writes the audit event, a failed write is logged and never fails the request.
+pub async fn security_audit(
+    state: &AppState,
+    kind: SecurityAuditKind,
+    actor: &str,
+    target: &str,
+    details: Option<Value>,
+) {
+    let (ip, user_agent) = SECURITY_AUDIT_CONTEXT
+        .try_with(|context| (context.ip.clone(), context.user_agent.clone()))
+        .unwrap_or_default();
+
+    if let Err(error) = state
+        .repository
+        .create_security_audit_event(kind, actor, target, ip, user_agent, details)
+        .await
+    {
+        error!(
+            "failed to write security audit event: kind={kind:?}, actor={actor}, error={error:?}"
+        );
+    }
+}
+
+/// Renders the audit events as CSV, one event per line.
+pub fn security_audit_csv(events: &[SecurityAuditEvent]) -> String {
+    let mut csv = String::from("created_at,kind,actor,target,ip,user_agent,details\n");
+    for event in events {
+        let details = event
+            .details
+            .as_ref()
+            .map(|details| details.to_string())
+            .unwrap_or_default();
+        let fields = [
+            event.created_at.as_ref(),
+            event.kind.as_str(),
+            event.actor.as_ref(),
+            event.target.as_ref(),
+            event.ip.as_ref(),
+            event.user_agent.as_ref(),
+            details.as_str(),
+        ];
+        let line = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        let _ = writeln!(csv, "{line}");
+    }
+    csv
+}
+
+/// Quotes the field when needed, the leading formula characters are escaped so the
+/// spreadsheet applications do not evaluate the user supplied values.
+fn csv_field(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@']) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
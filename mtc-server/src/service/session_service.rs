@@ -0,0 +1,55 @@
+use crate::error::Error;
+use crate::prelude::{
+    Access, AppState, AuthState, DEVICE_ID_HEADER, GenericError, UserRepository,
+    UserSessionRepository,
+};
+use axum::http::HeaderMap;
+use std::borrow::Cow;
+
+/// Builds the session state of the user from the current roles, fails when the user
+/// is blocked or deleted.
+pub async fn session_user_state(
+    state: &AppState,
+    user_id: Cow<'static, str>,
+) -> Result<(AuthState, Access), Error> {
+    let user_state = state.repository.find_user_state(user_id).await?;
+
+    let auth_state = AuthState::from(user_state.clone());
+
+    let access = auth_state
+        .is_admin()
+        .then(Access::administrator)
+        .unwrap_or(Access::from(user_state));
+
+    Ok((auth_state, access))
+}
+
+/// Revokes the sessions of the blocked user, otherwise marks them for the state
+/// reload so the changed roles apply on the next request.
+pub async fn session_user_changed(
+    state: &AppState,
+    user_id: Cow<'static, str>,
+    blocked: bool,
+    by: Cow<'static, str>,
+) -> Result<(), Error> {
+    if blocked {
+        state
+            .repository
+            .revoke_user_sessions(user_id, None, by)
+            .await
+    } else {
+        state.repository.refresh_user_sessions(user_id).await
+    }
+}
+
+/// Returns the persistent id of the front-end device, the service worker sends it with
+/// every request and it outlives the sign-ins on the device.
+pub fn device_id(headers: &HeaderMap) -> Result<Cow<'static, str>, Error> {
+    headers
+        .get(DEVICE_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|device_id| !device_id.is_empty())
+        .map(|device_id| Cow::Owned(device_id.to_string()))
+        .ok_or(Error::GenericError(GenericError::BadRequest))
+}
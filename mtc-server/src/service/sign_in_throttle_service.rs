@@ -0,0 +1,110 @@
+use crate::prelude::{
+    AppState, Config, SignInThrottleRepository, SocketAddr, error, security_audit,
+};
+use axum::http::HeaderMap;
+use mtc_common::prelude::{SecurityAuditKind, SignInThrottleKind};
+use std::borrow::Cow;
+use std::time::Duration;
+
+/// Upper bound of the failed sign-in response delay.
+const SIGN_IN_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// Returns the client IP, taken from the `client_ip_header` set by the reverse proxy
+/// when configured. The proxies append to a forwarded chain, so the client one is found
+/// `trusted_proxy_hops` entries from the right, the entries left of it are client-supplied.
+pub fn client_ip(config: &Config, headers: &HeaderMap, address: &SocketAddr) -> Cow<'static, str> {
+    config
+        .security
+        .client_ip_header
+        .as_ref()
+        .and_then(|header| headers.get(header.as_ref()))
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| forwarded_client_ip(value, config.security.trusted_proxy_hops))
+        .map(|ip| Cow::Owned(ip.to_string()))
+        .unwrap_or_else(|| Cow::Owned(address.ip().to_string()))
+}
+
+fn forwarded_client_ip(value: &str, trusted_proxy_hops: usize) -> Option<&str> {
+    let chain = value
+        .split(',')
+        .map(str::trim)
+        .filter(|ip| !ip.is_empty())
+        .collect::<Vec<&str>>();
+    // a chain shorter than the hops is made by the trusted proxies only
+    let index = chain.len().saturating_sub(trusted_proxy_hops.max(1));
+    chain.get(index).copied()
+}
+
+/// Counts the failed sign-in for the login, when known, and for the client IP, then
+/// delays the response progressively with the number of the recent failures.
+pub async fn sign_in_failure(state: &AppState, login: &str, ip: &str) {
+    security_audit(state, SecurityAuditKind::SignInFailure, login, login, None).await;
+
+    let security = &state.config.security;
+    let throttles = [
+        (
+            SignInThrottleKind::Login,
+            login,
+            security.sign_in_max_failures,
+        ),
+        (SignInThrottleKind::Ip, ip, security.sign_in_ip_max_failures),
+    ];
+
+    let mut failures = 0;
+    for (kind, key, max_failures) in throttles {
+        if key.is_empty() {
+            continue;
+        }
+        match state
+            .repository
+            .record_sign_in_failure(kind, key, ip, max_failures, security.sign_in_lockout)
+            .await
+        {
+            Ok(failure) => failures = failures.max(failure.failures),
+            Err(error) => error!(
+                "failed to record sign-in failure: kind={kind:?}, key={key}, error={error:?}"
+            ),
+        }
+    }
+
+    tokio::time::sleep(sign_in_delay(security.sign_in_delay, failures)).await;
+}
+
+fn sign_in_delay(base_delay: u64, failures: i64) -> Duration {
+    let exponent = failures.saturating_sub(1).clamp(0, 16) as u32;
+    Duration::from_millis(base_delay.saturating_mul(1 << exponent)).min(SIGN_IN_MAX_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwarded_client_ip_skips_client_supplied_entries() {
+        assert_eq!(forwarded_client_ip("10.0.0.1", 1), Some("10.0.0.1"));
+        assert_eq!(
+            forwarded_client_ip("1.1.1.1, 10.0.0.1", 1),
+            Some("10.0.0.1")
+        );
+        assert_eq!(
+            forwarded_client_ip("1.1.1.1, 10.0.0.1, 192.168.0.1", 2),
+            Some("10.0.0.1")
+        );
+        assert_eq!(forwarded_client_ip("10.0.0.1", 3), Some("10.0.0.1"));
+        assert_eq!(forwarded_client_ip("10.0.0.1,", 0), Some("10.0.0.1"));
+        assert_eq!(forwarded_client_ip(" , ", 1), None);
+    }
+
+    #[test]
+    fn sign_in_delay_doubles_up_to_the_limit() {
+        assert_eq!(sign_in_delay(250, 0), Duration::from_millis(250));
+        assert_eq!(sign_in_delay(250, 1), Duration::from_millis(250));
+        assert_eq!(sign_in_delay(250, 2), Duration::from_millis(500));
+        assert_eq!(sign_in_delay(250, 4), Duration::from_millis(2000));
+        assert_eq!(sign_in_delay(250, 5), Duration::from_millis(4000));
+        assert_eq!(sign_in_delay(250, 6), SIGN_IN_MAX_DELAY);
+        assert_eq!(sign_in_delay(250, i64::MAX), SIGN_IN_MAX_DELAY);
+        assert_eq!(sign_in_delay(u64::MAX, 3), SIGN_IN_MAX_DELAY);
+        assert_eq!(sign_in_delay(0, 10), Duration::ZERO);
+    }
+}
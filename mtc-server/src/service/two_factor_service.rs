@@ -0,0 +1,229 @@
+use crate::error::Error;
+use crate::prelude::GenericError::InternalError;
+use crate::prelude::{
+    Argon2, Config, MagicCryptTrait, PasswordHash, PasswordVerifier, Rng, UserTwoFactor, error,
+    new_magic_crypt, password_hash,
+};
+use chrono::Utc;
+use mtc_common::prelude::{TWO_FACTOR_RECOVERY_CODES, TwoFactorSetup};
+use std::borrow::Cow;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+/// Issuer shown by the authenticator applications.
+const TWO_FACTOR_ISSUER: &str = "MTC";
+
+/// TOTP time step, seconds.
+const TWO_FACTOR_STEP: u64 = 30;
+
+/// Accepted clock drift between the server and the authenticator, steps.
+const TWO_FACTOR_SKEW: u64 = 1;
+
+const TWO_FACTOR_RECOVERY_CODE_CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+fn two_factor_totp(secret: &str, login: &str) -> Result<TOTP, Error> {
+    let secret = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|error| {
+            error!("failed to decode two-factor secret: error={error:?}");
+            InternalError
+        })?;
+    TOTP::new(
+        Algorithm::SHA1,
+        6,
+        0,
+        TWO_FACTOR_STEP,
+        secret,
+        Some(TWO_FACTOR_ISSUER.to_string()),
+        login.to_string(),
+    )
+    .map_err(|error| {
+        error!("failed to create two-factor TOTP: login={login}, error={error:?}");
+        InternalError.into()
+    })
+}
+
+/// Generates a new base32 TOTP secret with the provisioning URL and its QR code.
+pub fn two_factor_setup(login: &str) -> Result<TwoFactorSetup, Error> {
+    let Secret::Encoded(secret) = Secret::generate_secret().to_encoded() else {
+        Err(InternalError)?
+    };
+    let otpauth_url = two_factor_totp(&secret, login)?.get_url();
+    let qr_code = qrcode_generator::to_svg_to_string(
+        &otpauth_url,
+        qrcode_generator::QrCodeEcc::Medium,
+        512,
+        None::<&str>,
+    )
+    .unwrap_or_default();
+
+    Ok(TwoFactorSetup {
+        secret: Cow::Owned(secret),
+        otpauth_url: Cow::Owned(otpauth_url),
+        qr_code: Cow::Owned(qr_code),
+    })
+}
+
+/// Returns the server-only key the TOTP secrets are encrypted with.
+fn two_factor_encryption_key(config: &Config) -> Result<&str, Error> {
+    config
+        .security
+        .two_factor_encryption_key
+        .as_deref()
+        .ok_or_else(|| {
+            error!("failed to use two-factor secret: TWO_FACTOR_ENCRYPTION_KEY is missing");
+            InternalError.into()
+        })
+}
+
+pub fn two_factor_encrypt_secret(
+    config: &Config,
+    secret: &str,
+) -> Result<Cow<'static, str>, Error> {
+    let mcrypt = new_magic_crypt!(two_factor_encryption_key(config)?, 256);
+    Ok(Cow::Owned(mcrypt.encrypt_str_to_base64(secret)))
+}
+
+fn two_factor_decrypt_secret(config: &Config, secret: &str) -> Result<String, Error> {
+    let mcrypt = new_magic_crypt!(two_factor_encryption_key(config)?, 256);
+    mcrypt.decrypt_base64_to_string(secret).map_err(|error| {
+        error!("failed to decrypt two-factor secret: error={error:?}");
+        InternalError.into()
+    })
+}
+
+/// Checks the TOTP code against the current time step and its neighbours,
+/// returns the matched step if it is newer than `last_step`.
+pub fn two_factor_check_code(
+    secret: &str,
+    login: &str,
+    code: &str,
+    last_step: u64,
+) -> Result<Option<u64>, Error> {
+    let totp = two_factor_totp(secret, login)?;
+    let current_step = Utc::now().timestamp() as u64 / TWO_FACTOR_STEP;
+
+    Ok(
+        (current_step.saturating_sub(TWO_FACTOR_SKEW)..=current_step + TWO_FACTOR_SKEW)
+            .filter(|step| *step > last_step)
+            .find(|step| totp.check(code, step * TWO_FACTOR_STEP)),
+    )
+}
+
+/// Verifies the TOTP or a recovery code of the enrolled user, the used code
+/// is burned in `two_factor` which has to be stored afterwards.
+pub fn two_factor_verify(
+    config: &Config,
+    two_factor: &mut UserTwoFactor,
+    login: &str,
+    code: &str,
+) -> Result<bool, Error> {
+    let secret = two_factor_decrypt_secret(config, &two_factor.secret)?;
+    if let Some(step) = two_factor_check_code(&secret, login, code, two_factor.last_step)? {
+        two_factor.last_step = step;
+        return Ok(true);
+    }
+
+    let argon2 = Argon2::default();
+    let recovery_code_index = two_factor.recovery_codes.iter().position(|recovery_code| {
+        PasswordHash::new(recovery_code)
+            .is_ok_and(|hash| argon2.verify_password(code.as_bytes(), &hash).is_ok())
+    });
+    match recovery_code_index {
+        Some(index) => {
+            two_factor.recovery_codes.remove(index);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Generates the recovery codes, returns them along with their salted hashes.
+pub fn two_factor_recovery_codes(
+    config: &Config,
+) -> Result<(Vec<Cow<'static, str>>, Vec<Cow<'static, str>>), Error> {
+    let mut rng = rand::rng();
+
+    let recovery_codes = (0..TWO_FACTOR_RECOVERY_CODES)
+        .map(|_| {
+            let mut one_char = || {
+                TWO_FACTOR_RECOVERY_CODE_CHARSET
+                    [rng.random_range(0..TWO_FACTOR_RECOVERY_CODE_CHARSET.len())]
+                    as char
+            };
+            let first = std::iter::repeat_with(&mut one_char)
+                .take(5)
+                .collect::<String>();
+            let second = std::iter::repeat_with(&mut one_char)
+                .take(5)
+                .collect::<String>();
+            Cow::Owned(format!("{first}-{second}"))
+        })
+        .collect::<Vec<Cow<'static, str>>>();
+
+    let recovery_code_hashes = recovery_codes
+        .iter()
+        .map(|recovery_code| password_hash(config, recovery_code))
+        .collect::<Result<Vec<Cow<'static, str>>, Error>>()?;
+
+    Ok((recovery_codes, recovery_code_hashes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totp_at(secret: &str, step: u64) -> String {
+        two_factor_totp(secret, "LOGIN")
+            .unwrap()
+            .generate(step * TWO_FACTOR_STEP)
+    }
+
+    fn current_step() -> u64 {
+        Utc::now().timestamp() as u64 / TWO_FACTOR_STEP
+    }
+
+    #[test]
+    fn check_code_accepts_code_within_skew_once() {
+        let setup = two_factor_setup("LOGIN").unwrap();
+        let step = current_step();
+        let code = totp_at(&setup.secret, step);
+
+        let matched = two_factor_check_code(&setup.secret, "LOGIN", &code, 0).unwrap();
+        // the step may change between the generation and the check
+        assert!(matched.is_some_and(|matched| matched.abs_diff(step) <= TWO_FACTOR_SKEW));
+        // the accepted step is stored as the last one, the same code is a replay
+        assert_eq!(
+            two_factor_check_code(&setup.secret, "LOGIN", &code, matched.unwrap()).unwrap(),
+            None
+        );
+
+        let previous_code = totp_at(&setup.secret, current_step() - 1);
+        assert!(
+            two_factor_check_code(&setup.secret, "LOGIN", &previous_code, 0)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn check_code_rejects_stale_and_wrong_codes() {
+        let setup = two_factor_setup("LOGIN").unwrap();
+        let stale_code = totp_at(&setup.secret, current_step() - 10);
+        let current_code = totp_at(&setup.secret, current_step());
+
+        assert_eq!(
+            two_factor_check_code(&setup.secret, "LOGIN", &stale_code, 0).unwrap(),
+            None
+        );
+        // a code older than the last accepted step is a replay
+        assert_eq!(
+            two_factor_check_code(&setup.secret, "LOGIN", &current_code, current_step() + 1)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            two_factor_check_code(&setup.secret, "LOGIN", "12345X", 0).unwrap(),
+            None
+        );
+    }
+}
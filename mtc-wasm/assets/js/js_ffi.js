@@ -194,6 +194,20 @@ export async function exportJsonFile(contentJsonStr, suggestedFileName) {
     await exportFile(file, opts)
 }
 
+export async function exportBinaryFile(content, mimeType, extension, suggestedFileName) {
+    const file = new Blob([content], {type: mimeType});
+    const opts = {
+        types: [
+            {
+                description: extension.toUpperCase(),
+                accept: {[mimeType]: ['.' + extension]},
+            },
+        ],
+        suggestedName: suggestedFileName,
+    };
+    await exportFile(file, opts)
+}
+
 async function exportFile(file, opts) {
     if (window.showSaveFilePicker) {
         try {
@@ -520,8 +534,104 @@ export async function destroyHtml5QrcodeScanner() {
     }
 }
 
+export async function estimateStorage() {
+    if (navigator.storage && navigator.storage.estimate) {
+        const estimate = await navigator.storage.estimate();
+        return { usage: estimate.usage ?? 0, quota: estimate.quota ?? 0 };
+    }
+    return { usage: 0, quota: 0 };
+}
+
+// short high beeps and a single vibration for a valid pass, long low tones and a
+// repeated vibration for an invalid one, resolves after the verdict pause
+export async function playGatePassScanFeedback(valid, pauseMs) {
+    try {
+        if (navigator.vibrate) {
+            navigator.vibrate(valid ? [150] : [400, 150, 400, 150, 400]);
+        }
+        const AudioContextClass = window.AudioContext || window.webkitAudioContext;
+        if (AudioContextClass) {
+            const audioContext = new AudioContextClass();
+            const tones = valid
+                ? [[880, 0, 0.12], [1320, 0.16, 0.12]]
+                : [[220, 0, 0.35], [220, 0.5, 0.35], [220, 1, 0.35]];
+            tones.forEach(([frequency, start, duration]) => {
+                const oscillator = audioContext.createOscillator();
+                const gain = audioContext.createGain();
+                oscillator.type = valid ? 'sine' : 'square';
+                oscillator.frequency.value = frequency;
+                gain.gain.value = 0.2;
+                oscillator.connect(gain);
+                gain.connect(audioContext.destination);
+                oscillator.start(audioContext.currentTime + start);
+                oscillator.stop(audioContext.currentTime + start + duration);
+            });
+            setTimeout(() => audioContext.close(), 2000);
+        }
+    } catch (error) {
+        console.error(error);
+    }
+    await sleep(pauseMs);
+}
+
 function sleep(ms) {
     return new Promise(resolve => setTimeout(resolve, ms));
 }
 
-// ---
\ No newline at end of file
+// ---
+// --- Gate pass offline key ---
+// the offline key is persisted wrapped by a non-extractable AES-GCM key of the device,
+// the wrapping key lives in IndexedDB and its bytes cannot be read out of the browser
+const DEVICE_KEY_INDEXED_DB = 'mtc-242-device-keys';
+const DEVICE_KEY_STORE = 'device_keys';
+const OFFLINE_KEY_WRAPPING_KEY_ID = 'offline-key-wrapping';
+const OFFLINE_KEY_IV_LENGTH = 12;
+
+function deviceKeyRequest(request) {
+    return new Promise((resolve, reject) => {
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+}
+
+async function offlineKeyWrappingKey() {
+    const openRequest = indexedDB.open(DEVICE_KEY_INDEXED_DB, 1);
+    openRequest.onupgradeneeded = () => openRequest.result.createObjectStore(DEVICE_KEY_STORE);
+    const database = await deviceKeyRequest(openRequest);
+    try {
+        const store = mode => database.transaction(DEVICE_KEY_STORE, mode).objectStore(DEVICE_KEY_STORE);
+        let wrappingKey = await deviceKeyRequest(store('readonly').get(OFFLINE_KEY_WRAPPING_KEY_ID));
+        if (!wrappingKey) {
+            wrappingKey = await crypto.subtle.generateKey(
+                { name: 'AES-GCM', length: 256 }, false, ['encrypt', 'decrypt']
+            );
+            await deviceKeyRequest(store('readwrite').put(wrappingKey, OFFLINE_KEY_WRAPPING_KEY_ID));
+        }
+        return wrappingKey;
+    } finally {
+        database.close();
+    }
+}
+
+export async function wrapOfflineKey(offlineKey) {
+    const wrappingKey = await offlineKeyWrappingKey();
+    const iv = crypto.getRandomValues(new Uint8Array(OFFLINE_KEY_IV_LENGTH));
+    const encrypted = await crypto.subtle.encrypt(
+        { name: 'AES-GCM', iv }, wrappingKey, new TextEncoder().encode(offlineKey)
+    );
+    const wrapped = new Uint8Array(iv.length + encrypted.byteLength);
+    wrapped.set(iv);
+    wrapped.set(new Uint8Array(encrypted), iv.length);
+    return btoa(String.fromCharCode(...wrapped));
+}
+
+export async function unwrapOfflineKey(wrappedOfflineKey) {
+    const wrappingKey = await offlineKeyWrappingKey();
+    const wrapped = Uint8Array.from(atob(wrappedOfflineKey), char => char.charCodeAt(0));
+    const decrypted = await crypto.subtle.decrypt(
+        { name: 'AES-GCM', iv: wrapped.slice(0, OFFLINE_KEY_IV_LENGTH) },
+        wrappingKey,
+        wrapped.slice(OFFLINE_KEY_IV_LENGTH)
+    );
+    return new TextDecoder().decode(decrypted);
+}
@@ -48,6 +48,14 @@ extern "C" {
     #[wasm_bindgen(js_name = exportJsonFile)]
     pub fn jsFfiExportJsonFile(contentJsonStr: &str, suggestedFileName: &str) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = exportBinaryFile)]
+    pub fn jsFfiExportBinaryFile(
+        content: &[u8],
+        mimeType: &str,
+        extension: &str,
+        suggestedFileName: &str,
+    ) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = uploadFile)]
     pub fn jsFfiUploadFile(storageType: &str) -> js_sys::Promise;
 
@@ -108,6 +116,18 @@ extern "C" {
 
     #[wasm_bindgen(js_name = destroyHtml5QrcodeScanner)]
     pub fn jsFfiDestroyHtml5QrcodeScanner();
+
+    #[wasm_bindgen(js_name = estimateStorage)]
+    pub fn jsFfiEstimateStorage() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = playGatePassScanFeedback)]
+    pub fn jsFfiPlayGatePassScanFeedback(valid: bool, pauseMs: u32) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = wrapOfflineKey)]
+    pub fn jsFfiWrapOfflineKey(offlineKey: &str) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = unwrapOfflineKey)]
+    pub fn jsFfiUnwrapOfflineKey(wrappedOfflineKey: &str) -> js_sys::Promise;
 }
 
 // Event Handlers
@@ -16,6 +16,8 @@ mod form_toggle_field;
 mod form_entries_field;
 mod form_select_field;
 mod user_block_action;
+mod user_device_list;
+mod user_session_list;
 mod form_fields_field;
 mod form_permissions_field;
 mod form_schema_kind_field;
@@ -72,6 +74,8 @@ pub mod prelude {
         entry_info_box::*,
         editor_actions::*,
         user_block_action::*,
+        user_device_list::*,
+        user_session_list::*,
         personnel_actions::*,
         view_html_field::*,
         view_plainhtml_field::*,
@@ -44,6 +44,15 @@ pub fn ProfileController() -> Element {
                         { t!("menu-change-password") }
                     }
                 }
+                li {
+                    Link {
+                        class: "btn w-full justify-start rounded-none",
+                        onclick: move |_| state_fn!(search_engine_clear),
+                        to: route!(API_AUTH, "two-factor"),
+                        Icon { icon: Icons::DatabaseLock, class: "size-6 mr-2 text-neutral" }
+                        { t!("menu-two-factor") }
+                    }
+                }
                 li {
                     Link {
                         class: "btn w-full justify-start rounded-none",
@@ -53,6 +62,24 @@ pub fn ProfileController() -> Element {
                         { t!("menu-linking-qr-code") }
                     }
                 }
+                li {
+                    Link {
+                        class: "btn w-full justify-start rounded-none",
+                        onclick: move |_| state_fn!(search_engine_clear),
+                        to: route!(API_AUTH, "devices"),
+                        Icon { icon: Icons::Tablet, class: "size-6 mr-2 text-neutral" }
+                        { t!("menu-user-devices") }
+                    }
+                }
+                li {
+                    Link {
+                        class: "btn w-full justify-start rounded-none",
+                        onclick: move |_| state_fn!(search_engine_clear),
+                        to: route!(API_AUTH, "tokens"),
+                        Icon { icon: Icons::Lock, class: "size-6 mr-2 text-neutral" }
+                        { t!("menu-access-tokens") }
+                    }
+                }
                 if auth.is_admin() {
                     li {
                         Link {
@@ -0,0 +1,176 @@
+use super::*;
+
+/// Device key lifetimes offered in the expiry selector, days.
+const USER_DEVICE_EXPIRY_DAYS: [i64; 4] = [30, 90, 180, 365];
+
+/// Lists the devices linked to a user with the revocation and expiry controls.
+///
+/// The `path` is the devices endpoint relative to the API root, e.g.
+/// `auth/devices` for the own devices or `user/<id>/devices` for the user ones.
+#[component]
+pub fn UserDeviceList(
+    #[props(into)]
+    path: String,
+    #[props(default = true)]
+    writable: bool,
+) -> Element {
+    let path = use_memo(use_reactive!(|path| path));
+    let mut device_id_signal = use_signal(|| Cow::Borrowed(""));
+
+    let mut user_devices_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(path().as_str()))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<UserDevice>>()
+            .unwrap_or_default()
+    });
+
+    let revoke_device = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let device_id = device_id_signal();
+            if post_request!(url!(path().as_str(), device_id.as_ref(), "revocations")) {
+                success_dialog!("message-success-user-device-revoked");
+                user_devices_resource.restart();
+            }
+        });
+    });
+
+    let revoke_devices = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            if post_request!(url!(path().as_str(), "revocations")) {
+                success_dialog!("message-success-user-devices-revoked");
+                user_devices_resource.restart();
+            }
+        });
+    });
+
+    let update_expiry = move |device_id: Cow<'static, str>, expires_in_days: Option<i64>| {
+        spawn(async move {
+            let payload = json!({ "expires_in_days": expires_in_days });
+            if post_request!(url!(path().as_str(), device_id.as_ref(), "expirations"), payload) {
+                user_devices_resource.restart();
+            }
+        });
+    };
+
+    let format_date_time = |date_time_opt: Option<Cow<'static, str>>| {
+        date_time_opt
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    let user_devices = user_devices_resource().unwrap_or_default();
+    let has_active_devices = user_devices.iter().any(|user_device| user_device.active);
+
+    rsx! {
+        table { class: "entry-table",
+            thead {
+                tr {
+                    th { class: "text-wrap", {t!("user-device-field-device")} }
+                    th { class: "text-wrap", {t!("user-device-field-os")} }
+                    th { class: "text-wrap", {t!("user-device-field-linked-at")} }
+                    th { class: "text-wrap", {t!("user-device-field-last-used-at")} }
+                    th { class: "text-wrap", {t!("user-device-field-expires-at")} }
+                    th { class: "text-wrap", {t!("user-device-field-revoked-at")} }
+                    th { class: "w-12" }
+                }
+            }
+            tbody {
+                for user_device in user_devices {
+                    {
+                        let device_id = user_device.id.clone();
+                        rsx! {
+                            tr {
+                                td {
+                                    {user_device.device.as_ref()}
+                                    if user_device.current {
+                                        span {
+                                            class: "badge badge-sm badge-success ml-2",
+                                            { t!("user-device-current") }
+                                        }
+                                    }
+                                }
+                                td { {user_device.os.as_ref()} }
+                                td { {format_date_time(Some(user_device.created_at.clone()))} }
+                                td { {format_date_time(user_device.last_used_at.clone())} }
+                                td {
+                                    if writable && user_device.active {
+                                        select {
+                                            class: "select select-xs",
+                                            onchange: move |event| {
+                                                update_expiry(device_id.clone(), event.value().parse().ok());
+                                            },
+                                            option {
+                                                value: "",
+                                                initial_selected: true,
+                                                {
+                                                    user_device
+                                                        .expires_at
+                                                        .clone()
+                                                        .map(|expires_at| format_date_time(Some(expires_at)))
+                                                        .unwrap_or(t!("user-device-expiry-never").to_string())
+                                                }
+                                            }
+                                            option { value: "", { t!("user-device-expiry-never") } }
+                                            for expires_in_days in USER_DEVICE_EXPIRY_DAYS {
+                                                option {
+                                                    value: expires_in_days.to_string(),
+                                                    { format!("{} {}", expires_in_days, t!("user-device-expiry-days")) }
+                                                }
+                                            }
+                                        }
+                                    } else {
+                                        {format_date_time(user_device.expires_at.clone())}
+                                    }
+                                }
+                                td {
+                                    if user_device.revoked {
+                                        {
+                                            format!(
+                                                "{} ({})",
+                                                format_date_time(user_device.revoked_at.clone()),
+                                                user_device.revoked_by.clone().unwrap_or_default(),
+                                            )
+                                        }
+                                    }
+                                }
+                                td {
+                                    if writable && !user_device.revoked {
+                                        button {
+                                            class: "btn btn-xs btn-error",
+                                            onclick: move |_| {
+                                                device_id_signal.set(user_device.id.clone());
+                                                alert_dialog!(
+                                                    "message-confirm-user-device-revoke",
+                                                    revoke_device
+                                                );
+                                            },
+                                            { t!("action-revoke") }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if writable && has_active_devices {
+            div {
+                class: "flex w-full justify-center",
+                button {
+                    class: "btn btn-outline btn-error",
+                    onclick: move |_| {
+                        alert_dialog!("message-confirm-user-devices-revoke", revoke_devices);
+                    },
+                    Icon { icon: Icons::Trash, class: "size-6" }
+                    { t!("action-revoke-all-devices") }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+/// Lists the active sessions of a user with the revocation controls.
+///
+/// The `path` is the sessions endpoint relative to the API root, `user/<id>/sessions`.
+#[component]
+pub fn UserSessionList(
+    #[props(into)]
+    path: String,
+    #[props(default = true)]
+    writable: bool,
+) -> Element {
+    let path = use_memo(use_reactive!(|path| path));
+    let mut session_id_signal = use_signal(|| Cow::Borrowed(""));
+
+    let mut user_sessions_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(path().as_str()))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<UserSession>>()
+            .unwrap_or_default()
+    });
+
+    let revoke_session = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let session_id = session_id_signal();
+            if post_request!(url!(path().as_str(), session_id.as_ref(), "revocations")) {
+                success_dialog!("message-success-user-session-revoked");
+                user_sessions_resource.restart();
+            }
+        });
+    });
+
+    let revoke_sessions = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            if post_request!(url!(path().as_str(), "revocations")) {
+                success_dialog!("message-success-user-sessions-revoked");
+                user_sessions_resource.restart();
+            }
+        });
+    });
+
+    let format_date_time = |date_time: Option<Cow<'static, str>>| {
+        date_time
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    let user_sessions = user_sessions_resource().unwrap_or_default();
+    let has_active_sessions = user_sessions.iter().any(|user_session| user_session.active);
+
+    rsx! {
+        table { class: "entry-table",
+            thead {
+                tr {
+                    th { class: "text-wrap", {t!("user-session-field-ip")} }
+                    th { class: "text-wrap", {t!("user-session-field-user-agent")} }
+                    th { class: "text-wrap", {t!("user-session-field-created-at")} }
+                    th { class: "text-wrap", {t!("user-session-field-last-seen-at")} }
+                    th { class: "text-wrap", {t!("user-session-field-revoked-at")} }
+                    th { class: "w-12" }
+                }
+            }
+            tbody {
+                for user_session in user_sessions {
+                    tr {
+                        td {
+                            {user_session.ip.as_ref()}
+                            if user_session.current {
+                                span {
+                                    class: "badge badge-sm badge-success ml-2",
+                                    { t!("user-session-current") }
+                                }
+                            }
+                        }
+                        td { class: "text-wrap break-all", {user_session.user_agent.as_ref()} }
+                        td { {format_date_time(Some(user_session.created_at.clone()))} }
+                        td { {format_date_time(Some(user_session.last_seen_at.clone()))} }
+                        td {
+                            if user_session.revoked {
+                                {
+                                    format!(
+                                        "{} ({})",
+                                        format_date_time(user_session.revoked_at.clone()),
+                                        user_session.revoked_by.clone().unwrap_or_default(),
+                                    )
+                                }
+                            }
+                        }
+                        td {
+                            if writable && user_session.active {
+                                button {
+                                    class: "btn btn-xs btn-error",
+                                    onclick: move |_| {
+                                        session_id_signal.set(user_session.id.clone());
+                                        alert_dialog!(
+                                            "message-confirm-user-session-revoke",
+                                            revoke_session
+                                        );
+                                    },
+                                    { t!("action-revoke") }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if writable && has_active_sessions {
+            div {
+                class: "flex w-full justify-center",
+                button {
+                    class: "btn btn-outline btn-error",
+                    onclick: move |_| {
+                        alert_dialog!("message-confirm-user-sessions-revoke", revoke_sessions);
+                    },
+                    Icon { icon: Icons::SignOut, class: "size-6" }
+                    { t!("action-revoke-all-sessions") }
+                }
+            }
+        }
+    }
+}
@@ -80,6 +80,16 @@ pub fn SideMenu() -> Element {
                                 permission: PERMISSION_USERS_READ,
                                 { t!("menu-users") }
                             }
+                            MenuItem {
+                                route: route!(API_ADMINISTRATOR, "sign-in-lockouts"),
+                                permission: PERMISSION_USERS_READ,
+                                { t!("menu-sign-in-lockouts") }
+                            }
+                            MenuItem {
+                                route: route!(API_ADMINISTRATOR, API_SECURITY_AUDIT),
+                                permission: PERMISSION_SECURITY_AUDIT_READ,
+                                { t!("menu-security-audit") }
+                            }
                             MenuItem {
                                 route: route!(API_ADMINISTRATOR, "js"),
                                 { t!("menu-js-exec") }
@@ -90,6 +100,31 @@ pub fn SideMenu() -> Element {
                             permission: PERMISSION_GATE_PASS_READ,
                             { t!("menu-gate-passes") }
                         }
+                        MenuItem {
+                            route: route!(API_ADMINISTRATOR, "gate-pass-policies"),
+                            permission: PERMISSION_GATE_PASS_POLICY,
+                            { t!("menu-gate-pass-policies") }
+                        }
+                        MenuItem {
+                            route: route!(API_ADMINISTRATOR, "gate-pass-prints"),
+                            permission: PERMISSION_GATE_PASS_READ,
+                            { t!("menu-gate-pass-prints") }
+                        }
+                        MenuItem {
+                            route: route!(API_ADMINISTRATOR, "gate-pass-blocks"),
+                            permission: PERMISSION_GATE_PASS_READ,
+                            { t!("menu-gate-pass-blocks") }
+                        }
+                        MenuItem {
+                            route: route!(API_ADMINISTRATOR, "gate-pass-devices"),
+                            permission: PERMISSION_GATE_PASS_DEVICES,
+                            { t!("menu-gate-pass-devices") }
+                        }
+                        MenuItem {
+                            route: route!(API_ADMINISTRATOR, "gate-pass-dictionaries"),
+                            permission: PERMISSION_GATE_PASS_DICTIONARIES,
+                            { t!("menu-gate-pass-dictionaries") }
+                        }
                     }
                 }
             }
@@ -151,6 +186,14 @@ pub fn SideMenu() -> Element {
                             route: route!(API_AUTH, "linking-qr-code"),
                             { t!("menu-linking-qr-code") }
                         }
+                        MenuItem {
+                            route: route!(API_AUTH, "devices"),
+                            { t!("menu-user-devices") }
+                        }
+                        MenuItem {
+                            route: route!(API_AUTH, "tokens"),
+                            { t!("menu-access-tokens") }
+                        }
                     }
                 }
             }
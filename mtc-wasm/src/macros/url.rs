@@ -18,4 +18,10 @@ macro_rules! url {
             [&API_ENDPOINT, $first, $second, $third].join("/")
         }
     };
+
+    ($first:expr, $second:expr, $third:expr, $fourth:expr) => {
+        {
+            [&API_ENDPOINT, $first, $second, $third, $fourth].join("/")
+        }
+    };
 }
\ No newline at end of file
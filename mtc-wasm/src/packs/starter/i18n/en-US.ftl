@@ -2,18 +2,21 @@ site-title = Military training center
 site-short-title = MTC-CMS
 site-home-title = MTC-CMS
 site-home-description = Military training center
+site-locale = en-US
 site-copyright = 2024 © Powered by MTC-CMS
 
 action-add = Add
 action-back = Back
 action-cancel = Cancel
 action-change-password = Change password
+action-password-reset = Reset password
 action-clear = Clear
 action-clipboard-paste = Paste
 action-clipboard-copy = Copy
 action-close = Close
 action-confirm = Confirm
 action-create = Create
+action-create-access-token = Create token
 action-delete = Delete
 action-delete-downloads = Delete all materials
 action-download = Download
@@ -33,6 +36,7 @@ action-processing = Processing
 action-refresh = Refresh
 action-save = Save
 action-scan-qr-code = QR-code
+action-search = Search
 action-sign-in = Sign in
 action-sign-out = Sign out
 action-sync = Verify
@@ -41,6 +45,15 @@ action-upload = Import
 action-yes = Yes, that's right!
 action-print = Print
 action-renew = Renew
+action-revoke = Revoke
+action-revoke-all-devices = Revoke all devices
+action-revoke-all-sessions = Sign out everywhere
+action-refresh-qr-code = New QR code
+action-unlock = Unlock
+action-two-factor-enable = Enable two-factor
+action-two-factor-disable = Disable two-factor
+action-two-factor-recovery-codes = New recovery codes
+action-two-factor-reset = Reset two-factor
 
 caption-administrator-dashboard = Platform Dashboard
 caption-alert = Alert!
@@ -63,6 +76,7 @@ error-conflict = Connection conflict
 error-connection = Connection error
 error-db-already-exists = Record already exists
 error-db-delete = Error deleting record
+error-db-in-use = Record is in use and cannot be deleted
 error-db-not-found = Record not found
 error-db-something-went-wrong = Something went wrong
 error-db-update = Error updating record
@@ -77,13 +91,57 @@ error-file-delete = File deletion error
 error-generic = Unspecified error
 error-password-hash = Password verification error
 error-password-not-match = Password and confirmation do not match
+error-password-no-digit = the password has no digit
+error-password-no-lowercase = the password has no lowercase letter
+error-password-no-symbol = the password has no special character
+error-group-parent-has-users = the parent group has users, move them to another group first
+error-password-no-uppercase = the password has no uppercase letter
+error-password-reused = The new password must differ from the current one
+error-password-too-short = the password is too short
+error-user-device-expiry-invalid = The key lifetime must be from 1 to 3650 days
+error-access-token-name-invalid = The token name must be from 1 to 64 characters
+error-access-token-permissions-empty = Choose at least one permission for the token
+error-access-token-expiry-invalid = The token lifetime must be from 1 to 3650 days
 error-sw-unsupported = Your system is outdated for the Platform
 error-unsupported-media-type = Request type not supported
+error-report-font-missing = The report fonts are missing on the server
 error-user-blocked = Account blocked
+error-device-revoked = This device has been revoked, offline gate passes were removed
+error-two-factor-code-invalid = Invalid authentication code
+error-two-factor-enforced = Two-factor authentication is required by your role and cannot be disabled
+error-sign-in-locked = Too many failed sign-in attempts, try again later
 error-validation = Request validation error
 error-import = Import error
 error-export = Export error
 error-recommendation = Try again
+error-gate-pass-policy-max-validity = Pass validity exceeds the maximum allowed by the policy
+error-gate-pass-policy-invalid-date = Invalid pass validity date
+error-gate-pass-policy-validity-negative = Maximum validity cannot be negative
+error-gate-pass-policy-vin-code-required = VIN code is required for this vehicle body type
+error-gate-pass-policy-unknown-number-plate = Number plate format is not allowed by the policy
+error-gate-pass-number-plate-invalid = Number plate must have a Ukrainian format or consist of 4 to 10 letters and digits with at least one of each
+error-gate-pass-number-plate-invalid-letters = Number plate contains letters that are not used on Ukrainian number plates
+error-gate-pass-vin-code-invalid-length = VIN code must be 17 characters long
+error-gate-pass-vin-code-invalid-characters = VIN code may only contain digits and Latin letters except I, O and Q
+error-gate-pass-vin-code-invalid-check-digit = VIN code check digit does not match
+error-gate-pass-dictionary-code-invalid = Code must start with a Latin letter and contain only Latin letters and digits
+error-gate-pass-dictionary-unknown-entry = Value is missing from the gate pass dictionary
+error-gate-pass-block-reason-missing = Choose a block reason or describe it
+error-gate-pass-owner-phone-invalid = Phone number must contain 10 to 15 digits
+error-gate-pass-owner-email-invalid = Owner email is invalid
+error-gate-pass-owner-email-missing = Recipient email is not set and the owner has no email
+error-gate-pass-already-blocked = The pass is already blocked
+error-gate-pass-not-blocked = The pass is not blocked
+error-gate-pass-bulk-skipped = The gate pass was changed during the operation
+error-gate-pass-snapshot-invalid = The file is not a valid gate pass snapshot
+error-gate-pass-snapshot-import-disabled = The gate pass snapshot import is not configured
+error-gate-pass-snapshot-signature = The gate pass snapshot signature is invalid
+error-gate-pass-snapshot-not-applicable = The gate pass snapshot is older than the local data or does not continue it
+error-gate-pass-snapshot-other-device = The gate pass snapshot was exported for another device
+error-gate-pass-device-key-missing = The device has no offline key, sign in on it while it is linked to the server first
+error-gate-pass-sync = Gate pass sync failed
+error-gate-pass-offline-key = The offline gate pass key could not be stored on the device
+error-gate-pass-offline-database-purge = Failed to purge offline gate passes
 
 field-access = authorization
 field-access-level = access level
@@ -98,6 +156,7 @@ field-description = description
 field-fields = fields
 field-file = file
 field-group = group
+field-parent-group = parent group
 field-html = HTML
 field-kind = type
 field-links = links
@@ -108,6 +167,8 @@ field-page = page
 field-pages = pages
 field-password = password
 field-password-confirmation = password confirmation
+field-password-change-required = change password on next sign-in
+field-two-factor-code = authentication or recovery code
 field-permission = permission
 field-permissions = permissions
 field-rank = rank
@@ -126,6 +187,7 @@ field-text = text
 field-write = edit
 field-updated-at = updated
 field-user-all-access = access to user archive
+field-two-factor-required = two-factor authentication required
 field-yes = Yes
 field-no = No
 
@@ -142,6 +204,10 @@ menu-groups = Groups
 menu-home = Home
 menu-js-exec = Java script
 menu-linking-qr-code = Add device
+menu-user-devices = Linked devices
+menu-access-tokens = Access tokens
+menu-user-sessions = Active sessions
+menu-two-factor = Two-factor
 menu-page = Individual pages
 menu-pages = Page collections
 menu-permissions = Permissions
@@ -153,6 +219,8 @@ menu-settings = Settings
 menu-sign-in = Sign in
 menu-sign-out = Sign out
 menu-users = Users
+menu-sign-in-lockouts = Sign-in Lockouts
+menu-security-audit = Security Audit
 
 message-app-cache-title = Browser Cache
 message-app-cache-description = Saved Materials
@@ -160,12 +228,34 @@ message-app-downloads-title = Downloads
 message-app-downloads-description = Saved Files
 message-access-forbidden = Access Forbidden
 message-linking-qr-announcement = Scan the QR code in the Platform application to authorize on your device. Do not show this code to anyone!
+message-linking-qr-expiry = The code is valid for a single sign-in within a couple of minutes, request a new one if it has expired
+message-access-token-created = Copy the token now, it will not be shown again. Send it as the Authorization: Bearer header
+message-two-factor-header = Two-factor authentication
+message-two-factor-announcement = Protect your account with a one-time code from an authenticator application in addition to the password.
+message-two-factor-enroll-announcement = Your role requires two-factor authentication. Enable it to complete the sign in.
+message-two-factor-enabled-announcement = Two-factor authentication is enabled. Recovery codes left:
+message-two-factor-code-announcement = Enter the code from the authenticator application or one of the recovery codes.
+message-two-factor-recovery-codes = Save the recovery codes in a safe place. Each code works once, they are shown only now.
+message-two-factor-disabled = Two-factor authentication disabled
+message-two-factor-reset = Two-factor authentication of the user has been reset
+message-confirm-two-factor-reset = Reset two-factor authentication of the user?
 message-linking-qr-header = Add Device!
 message-change-password-form-announcement = If necessary You can change your password using this form.
 message-change-password-form-header = Welcome!
+message-password-change-required-announcement = Your password has expired or was set by the administrator. Choose a new password to complete the sign-in.
 message-confirm-cache-clear = Clearing the browser cache will delete saved articles and files in the web version of the Platform. In the application, files are stored in a separate storage.
 message-confirm-downloads-clear = Confirm the deletion of all downloaded files
 message-confirm-deletion = Confirm deletion
+message-confirm-gate-pass-device-revoke = The device will lose access to gate pass sync and its offline gate passes will be wiped on the next connection. Confirm revocation
+message-confirm-user-device-revoke = The device will be signed out and lose access. Confirm revocation
+message-confirm-access-token-revoke = The scripts using the token will lose access. Confirm revocation
+message-confirm-user-devices-revoke = All the linked devices will be signed out and lose access. Confirm revocation
+message-confirm-user-session-revoke = The session will be signed out. Confirm revocation
+message-confirm-user-sessions-revoke = All the sessions of the user will be signed out. Confirm revocation
+message-confirm-sign-in-unlock = Failed sign-in attempts will be reset and sign-in allowed again. Confirm unlocking
+message-confirm-password-reset = The current password will stop working, the user will sign in with a one-time password and choose a new one. Confirm reset
+message-confirm-gate-pass-offline-database-resync = All offline gate passes will be removed and downloaded again. Continue?
+message-confirm-gate-pass-offline-database-purge = All offline gate passes will be removed from this device. Continue?
 message-course-download-success = The training program has been downloaded
 message-init-form-announcement = Creating a platform administrator account and initializing the database to get started.
 message-init-form-header = Setting up the platform!
@@ -174,6 +264,9 @@ message-login-form-header = Authorization!
 message-no-results = Nothing found
 message-not-found = Page not found
 message-password-changed = Account password changed
+message-password-policy = The password must contain:
+message-one-time-password = One-time password, hand it over to the user:
+message-number-plate-collisions = The number plates of these passes were not normalized, as they match the plates of other passes once normalized. Please correct them:
 message-personnel-copy-successful = Credentials saved to clipboard
 message-personnel-export-successful = Credentials exported, save file...
 message-search = search
@@ -199,6 +292,17 @@ message-stat-total-users-description = registered users
 message-stat-users-title = Users
 message-stat-users-description = Active users
 message-success-deletion = Entry deleted
+message-success-gate-pass-device-revoked = Device revoked
+message-success-gate-pass-print-confirmed = Passes are marked as printed
+message-confirm-gate-pass-printed = Confirm the passes are printed, only then they are counted as printed
+message-success-user-device-revoked = Device revoked
+message-success-access-token-revoked = Token revoked
+message-success-user-devices-revoked = All devices revoked
+message-success-user-session-revoked = Session revoked
+message-success-user-sessions-revoked = All sessions revoked
+message-success-sign-in-unlocked = Sign-in unlocked
+message-success-gate-pass-offline-database-resync = Offline gate passes downloaded again
+message-success-gate-pass-offline-database-purge = Offline gate passes removed
 message-success-cache-clear = Browser cache cleared
 message-success-course-assets-build = Course file tables created
 message-success-downloads-clear = All downloads cleared
@@ -208,73 +312,39 @@ message-success-index-rebuild = Database successfully indexed
 message-success-sitemap-build = Sitemap created
 
 menu-gate-passes = Gate Passes
+menu-gate-pass-policies = Gate Pass Policies
+menu-gate-pass-prints = Gate Pass Print History
+menu-gate-pass-blocks = Blocked Gate Passes
+menu-gate-pass-devices = Scanner Devices
+menu-gate-pass-dictionaries = Gate Pass Dictionaries
 menu-gate-pass-validation = Gate Pass Validator
 menu-gate-pass-validation-scan = QR Code Validator
 menu-gate-pass-validation-scan-result = Validation Result
 
 gate-pass-action-search = Search by last name and number plate
 gate-pass-action-sync = Sync Passes
+gate-pass-action-import-snapshot = Import Passes Snapshot
+gate-pass-action-export-snapshot = Snapshot
+gate-pass-message-snapshot-imported = Imported {number} passes from the snapshot created {days} d {hours} h ago
 gate-pass-action-switch-camera = Switch camera
 gate-pass-action-offline-mode = Validate Offline
+gate-pass-action-continuous-scan-mode = Continuous scanning
+gate-pass-action-offline-database-resync = Full resync
+gate-pass-action-offline-database-purge = Purge
 gate-pass-action-block = Block
 gate-pass-action-reblock = Update Block
 gate-pass-action-unblock = Unblock
+gate-pass-action-export-blocked = Export to CSV
 gate-pass-action-edit-block = Edit Block
 gate-pass-action-send-email = Send pass
 gate-pass-action-delete-all = Delete passes
 gate-pass-action-print-all = Print passes
+gate-pass-action-report = Reports
+gate-pass-action-report-generate = Generate report
 gate-pass-action-renew-all = Renew passes
-
-gate-pass-owner-title-soldier = Soldier
-gate-pass-owner-title-seniorsoldier = Senior soldier
-gate-pass-owner-title-juniorsergeant = Junior sergeant
-gate-pass-owner-title-sergeant = Sergeant
-gate-pass-owner-title-seniorsergeant = Senior sergeant
-gate-pass-owner-title-chiefsergeant = Chief sergeant
-gate-pass-owner-title-staffsergeant = Staff sergeant
-gate-pass-owner-title-mastersergeant = Master sergeant
-gate-pass-owner-title-seniormastersergeant = Senior master-sergeant
-gate-pass-owner-title-chiefmastersergeant = Chief master-sergeant
-gate-pass-owner-title-juniorlieutenant = Junior lieutenant
-gate-pass-owner-title-lieutenant = Lieutenant
-gate-pass-owner-title-seniorlieutenant = Senior lieutenant
-gate-pass-owner-title-captain = Captain
-gate-pass-owner-title-major = Major
-gate-pass-owner-title-lieutenantcolonel = Lieutenant colonel
-gate-pass-owner-title-colonel = Colonel
-gate-pass-owner-title-brigadiergeneral = Brigadier general
-gate-pass-owner-title-majorgeneral = Major general
-gate-pass-owner-title-lieutenantgeneral = Lieutenant general
-gate-pass-owner-title-general = General
-gate-pass-owner-title-generalofthearmyofukraine = General of the army of Ukraine
-
-gate-pass-vehicle-body-type-sedan = Sedan
-gate-pass-vehicle-body-type-hatchback = Hatchback
-gate-pass-vehicle-body-type-wagon = Wagon
-gate-pass-vehicle-body-type-pickup = Pickup
-gate-pass-vehicle-body-type-suv = SUV
-gate-pass-vehicle-body-type-coupe = Coupe
-gate-pass-vehicle-body-type-truck = Truck
-gate-pass-vehicle-body-type-bus = Bus
-gate-pass-vehicle-body-type-minibus = Minibus
-gate-pass-vehicle-body-type-motorbike = Motorbike
-gate-pass-vehicle-body-type-scooter = Scooter
-
-gate-pass-vehicle-color-white = White
-gate-pass-vehicle-color-beige = Beige
-gate-pass-vehicle-color-grey = Grey
-gate-pass-vehicle-color-darkgrey = Dark Grey
-gate-pass-vehicle-color-blue = Blue
-gate-pass-vehicle-color-darkblue = Dark Blue
-gate-pass-vehicle-color-purple = Purple
-gate-pass-vehicle-color-red = Red
-gate-pass-vehicle-color-darkred = Dark Red
-gate-pass-vehicle-color-green = Green
-gate-pass-vehicle-color-darkgreen = Dark Green
-gate-pass-vehicle-color-yellow = Yellow
-gate-pass-vehicle-color-orange = Orange
-gate-pass-vehicle-color-brown = Brown
-gate-pass-vehicle-color-black = Black
+gate-pass-action-reissue = Reissue lost card
+gate-pass-action-bulk = Bulk actions
+gate-pass-action-bulk-apply = Apply to passes
 
 gate-pass-message-scan-result-valid = Pass is valid!
 gate-pass-message-scan-result-invalid = Pass is invalid!
@@ -282,11 +352,26 @@ gate-pass-message-scan-result-blocked = Pass is blocked!
 gate-pass-message-scan-result-not-found = Pass not found in database!
 gate-pass-message-scan-result-deleted = Pass has been revoked!
 gate-pass-message-scan-result-expired = Pass has expired!
+gate-pass-message-scan-result-revoked = Card has been reissued, this QR code is revoked!
+gate-pass-message-reissued = The pass has been reissued, please print a new card
 gate-pass-message-allow-any-vehicle = Any vehicle is allowed!
 gate-pass-message-validation-scan-qr-code-unknown-error = Unknown QR code, please scan the QR code from the pass.
 gate-pass-message-validation-scan-qr-code-scan-error = QR code scan error, please try again.
 gate-pass-message-last-sync = Last sync
 gate-pass-message-last-sync-not-found = not found
+gate-pass-message-continuous-scan-resume = Scanning resumes automatically
+gate-pass-scan-history-field-scanned-at = Scanned at
+gate-pass-scan-history-field-result = Result
+gate-pass-offline-database = Offline database
+gate-pass-offline-database-field-gate-passes = Stored passes
+gate-pass-offline-database-field-expired = Expired
+gate-pass-offline-database-field-blocked = Blocked
+gate-pass-offline-database-field-unreadable = Unreadable
+gate-pass-offline-database-field-storage-usage = Storage usage
+gate-pass-offline-database-field-last-sync-error = Last sync error
+gate-pass-offline-database-no-sync-error = none
+gate-pass-message-clock-skew = The device clock differs from the server clock, passes are checked against the server time. Set the correct date and time on the device
+gate-pass-message-sync-outdated = Offline passes were synced more than {hours} hours ago, sync the device
 
 gate-pass-field-expired-at = Valid until
 gate-pass-field-owner-last-name = Last Name
@@ -294,6 +379,8 @@ gate-pass-field-owner-first-name = First Name
 gate-pass-field-owner-middle-name = Middle Name
 gate-pass-field-owner-title = Title
 gate-pass-field-owner-unit = Unit
+gate-pass-field-owner-phone = Phone
+gate-pass-field-owner-email = Email
 gate-pass-field-vehicle-number-plate = Number Plate
 gate-pass-field-vehicle-number-plates = Number plates
 gate-pass-field-vehicle-vin-code = VIN Code
@@ -304,19 +391,168 @@ gate-pass-field-vehicle-body-type = Body Type
 gate-pass-field-allow-any-vehicle = Allows any vehicle
 gate-pass-field-owner-full-name = Full Name
 gate-pass-field-vehicle-info = Vehicle Information
+gate-pass-field-printed = Printed
+gate-pass-never-printed = Never printed
+gate-pass-has-contact = contact
 gate-pass-field-actions = Actions
 gate-pass-field-block-expired-at = Valid until
-gate-pass-field-block-reason = Reason
+gate-pass-field-block-reason = Reason details
+gate-pass-field-block-reason-code = Block reason
+gate-pass-field-blocked-by = Blocked by
+gate-pass-field-unblocked-by = Unblocked by
+gate-pass-field-number = Number
+gate-pass-block-history = Block history
+gate-pass-block-expired = expired
 gate-pass-field-recipient-email = Recipient email
+gate-pass-recipient-email-description = By default the pass is sent to the owner email
+gate-pass-bulk-recipient-email-description = Leave empty to send each pass to its owner email
+gate-pass-field-bulk-operation = Operation
+gate-pass-field-bulk-units = Units
+gate-pass-field-bulk-filter = Saved filter
+gate-pass-field-bulk-filter-title = Save the units as a filter named
 
 gate-pass-selected-delete-description = All selected gate passes will be deleted, count:
 gate-pass-selected-print-description = All selected gate passes will be printed, count:
+gate-pass-selected-report-description = The report will include all selected gate passes, count:
 gate-pass-selected-renew-description = All selected gate passes will be renewed, count:
+gate-pass-selected-bulk-description = The operation will be applied to all selected gate passes, count:
 
 gate-pass-print-description = By default, all passes are printed. If you only want to print particular ones, please enter the comma separated number plates.
+gate-pass-field-never-printed-only = Print only never printed passes
+gate-pass-report-description = By default, the report includes all gate passes. To narrow it down, enter comma separated units, last names or number plates.
+gate-pass-field-report-kind = Report
+gate-pass-field-report-format = Format
+gate-pass-field-report-expiring-within-days = Expiring within, days
+gate-pass-field-report-never-printed-only = Only never printed passes
+gate-pass-report-kind-register = Gate pass register
+gate-pass-report-kind-byunit = Gate passes by unit
+gate-pass-report-kind-expiring = Expiring gate passes
+gate-pass-report-kind-blocked = Blocked gate passes
+gate-pass-report-title-expiring = Gate passes expiring within {days} days
+gate-pass-report-column-row-number = No.
+gate-pass-report-column-number = Number
+gate-pass-report-column-owner-title = Rank
+gate-pass-report-column-owner-name = Full name
+gate-pass-report-column-unit = Unit
+gate-pass-report-column-number-plate = Number plate
+gate-pass-report-column-vehicle = Vehicle
+gate-pass-report-column-expired-at = Valid until
+gate-pass-report-column-days-left = Days left
+gate-pass-report-column-block-reason = Block reason
+gate-pass-report-column-block-expired-at = Blocked until
+gate-pass-report-column-blocked-by = Blocked by
+gate-pass-report-section-unit = Unit: {unit}
+gate-pass-report-generated-at = As of {date}, number of gate passes: {number}
+gate-pass-report-sheet = Gate passes
+gate-pass-report-signature-chief = Chief ______________________   ______________________   ____ ____________ 20___
+gate-pass-report-signature-executor = Executor ______________________   ______________________
+gate-pass-report-signature-hint = (signature)                        (initials, last name)
+gate-pass-print-field-created-at = Printed at
+gate-pass-print-field-created-by = Printed by
+gate-pass-print-field-number-of-gate-passes = Number of passes
+gate-pass-print-field-confirmed-by = Confirmed by
+gate-pass-device-field-login = User
+gate-pass-device-field-device = Device
+gate-pass-device-field-os = OS
+gate-pass-device-field-last-synced-at = Last sync
+gate-pass-device-field-revoked-at = Revoked
+user-device-field-device = Device
+user-device-field-os = OS
+user-device-field-linked-at = Linked
+user-device-field-last-used-at = Last used
+user-device-field-expires-at = Expires
+user-device-field-revoked-at = Revoked
+user-device-current = this device
+user-device-expiry-never = Never
+user-device-expiry-days = days
+access-token-field-name = Name
+access-token-field-permissions = Permissions
+access-token-field-created-at = Created
+access-token-field-last-used-at = Last used
+access-token-field-expires-at = Expires
+access-token-field-expires-in = Valid for
+access-token-field-revoked-at = Revoked
+user-session-field-ip = IP
+user-session-field-user-agent = Browser
+user-session-field-created-at = Signed in
+user-session-field-last-seen-at = Last activity
+user-session-field-revoked-at = Revoked
+user-session-current = current session
+sign-in-lockout-field-key = Login
+sign-in-lockout-field-ip = IP
+sign-in-lockout-field-failures = Failures
+sign-in-lockout-field-locked-at = Locked
+sign-in-lockout-field-locked-until = Locked until
+sign-in-lockout-field-unlocked-at = Unlocked
+sign-in-lockout-kind-ip = All logins from IP
+security-audit-field-created-at = Time
+security-audit-field-kind = Event
+security-audit-field-actor = Actor
+security-audit-field-target = Target
+security-audit-field-ip = IP
+security-audit-field-user-agent = Browser
+security-audit-field-details = Details
+security-audit-field-date-from = From
+security-audit-field-date-to = To
+security-audit-kind-sign-in = Sign-in
+security-audit-kind-sign-in-failure = Failed sign-in
+security-audit-kind-sign-out = Sign-out
+security-audit-kind-password-change = Password change
+security-audit-kind-password-reset = Password reset
+security-audit-kind-device-link = Device linked
+security-audit-kind-device-revoke = Device revoked
+security-audit-kind-session-revoke = Session revoked
+security-audit-kind-sign-in-unlock = Sign-in unlocked
+security-audit-kind-two-factor-enable = Two-factor enabled
+security-audit-kind-two-factor-disable = Two-factor disabled
+security-audit-kind-two-factor-reset = Two-factor reset
+security-audit-kind-permission-denied = Permission denied
+security-audit-kind-user-update = User changed
+security-audit-kind-user-delete = User deleted
+security-audit-kind-role-update = Role changed
+security-audit-kind-role-delete = Role deleted
+security-audit-kind-permission-create = Permission created
+security-audit-kind-permission-delete = Permission deleted
+security-audit-kind-group-update = Group changed
+security-audit-kind-group-delete = Group deleted
+security-audit-kind-access-token-create = Access token created
+security-audit-kind-access-token-revoke = Access token revoked
+security-audit-kind-directory-sync = Directory synchronized
+password-policy-min-length = minimum length:
+password-policy-uppercase = an uppercase letter
+password-policy-lowercase = a lowercase letter
+password-policy-digit = a digit
+password-policy-symbol = a special character
+password-policy-expiration = Password expires after days:
+gate-pass-dictionary-kind-ownertitle = Ranks
+gate-pass-dictionary-kind-vehiclecolor = Vehicle colors
+gate-pass-dictionary-kind-vehiclebodytype = Vehicle body types
+gate-pass-dictionary-kind-blockreason = Block reasons
+gate-pass-dictionary-field-code = Code
+gate-pass-dictionary-field-name = Name
+gate-pass-dictionary-field-print-name = Name on the card
+gate-pass-dictionary-field-sort-order = Order
+
 gate-pass-renew-description = By default, all passes are renewed. If you only want to renew particular ones, please enter the comma separated number plates.
+gate-pass-reissue-description = A new QR code will be issued for the pass, previously printed cards will be revoked. Continue?
 gate-pass-import-error = Gate pass import completed: succeeded {succeeded}, failed {failed}. Export failed entries?
 
+gate-pass-policy-field-max-validity-days = Maximum validity, days
+gate-pass-policy-field-unit-rules = Maximum validity by unit, one UNIT = days per line
+gate-pass-policy-field-forbid-unknown-number-plates = Forbid unknown number plate formats
+gate-pass-policy-title-rules-description = Maximum validity by title, days
+gate-pass-policy-vin-code-required-description = VIN code is required for body types
+gate-pass-bulk-description = No passes are selected. Please choose a saved filter or enter the comma separated units whose passes should be processed.
+gate-pass-bulk-filter-units = Units entered below
+gate-pass-bulk-result = Bulk operation completed: succeeded {succeeded}, failed {failed}.
+
+gate-pass-bulk-operation-block = Block
+gate-pass-bulk-operation-unblock = Unblock
+gate-pass-bulk-operation-changeunit = Change unit
+gate-pass-bulk-operation-changeexpiry = Change validity
+gate-pass-bulk-operation-sendemail = Send by email
+gate-pass-bulk-operation-export = Export
+
 two-side-print-mode = Two-sided printing mode
 two-side-print-mode-manual = Manual
 two-side-print-mode-automatic = Automatic
\ No newline at end of file
@@ -30,18 +30,28 @@ fn CustomRouter(#[props(into)] route: Vec<String>) -> Element {
         "auth/qr-sign-in" => return rsx! { QrSignIn {} },
         "auth/change-password" => return rsx! { ChangePassword {} },
         "auth/linking-qr-code" => return rsx! { LinkingQrCode {} },
+        "auth/devices" => return rsx! { UserDevices {} },
+        "auth/tokens" => return rsx! { AccessTokens {} },
+        "auth/two-factor" => return rsx! { TwoFactor {} },
         "personnel" => return rsx! { Personnel {} },
         "personnel/add" => return rsx! { PersonnelAdd {} },
         "administrator/permissions" => return rsx! { Permissions {} },
         "administrator/groups" => return rsx! { Groups {} },
         "administrator/roles" => return rsx! { Roles {} },
         "administrator/users" => return rsx! { Users {} },
+        "administrator/sign-in-lockouts" => return rsx! { SignInLockouts {} },
+        "administrator/security-audit" => return rsx! { SecurityAudit {} },
         "administrator/schemas" => return rsx! { Schemas {} },
         "administrator/permission/create" => return rsx! { PermissionCreate {} },
         "administrator/js" => return rsx! { JsExec {} },
         "application/data" => return rsx! { AppData {} },
         "administrator/loops" => return rsx! { NavigationLoop {} },
         "administrator/gate-passes" => return rsx! { GatePasses {} },
+        "administrator/gate-pass-policies" => return rsx! { GatePassPolicyEdit {} },
+        "administrator/gate-pass-prints" => return rsx! { GatePassPrints {} },
+        "administrator/gate-pass-devices" => return rsx! { GatePassDevices {} },
+        "administrator/gate-pass-blocks" => return rsx! { GatePassBlocks {} },
+        "administrator/gate-pass-dictionaries" => return rsx! { GatePassDictionaryEditor {} },
         "gate-pass-validation-scans" => return rsx! { GatePassScanView {} },
         _ => {}
     }
@@ -66,7 +76,7 @@ fn CustomRouter(#[props(into)] route: Vec<String>) -> Element {
             },
             "gate-pass-validation-scans" => match route[1].as_str() {
                 "errors" => rsx! { GatePassScanErrorView { error: route[2].clone() } },
-                "results" => rsx! { GatePassScanResultView { id: route[2].clone() } },
+                "results" => rsx! { GatePassScanResultView { qr_code: route[2].clone() } },
                 _ => rsx! { NotFound {} },
             },
 
@@ -0,0 +1,167 @@
+use super::*;
+
+/// Access token lifetimes offered on the token creation, days.
+const ACCESS_TOKEN_EXPIRY_DAYS: [i64; 4] = [30, 90, 180, 365];
+
+/// Lists the personal access tokens of the signed-in user, a new token value is shown
+/// once right after the creation.
+#[component]
+pub fn AccessTokens() -> Element {
+    if !state!(auth).is_authenticated() {
+        return rsx! { NotFound {} }
+    }
+
+    breadcrumbs!("menu-access-tokens");
+
+    let mut access_token_id_signal = use_signal(|| Cow::Borrowed(""));
+    let mut created_token_signal = use_signal(|| None::<Cow<'static, str>>);
+
+    let mut access_tokens_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(API_AUTH, "tokens"))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<AccessToken>>()
+            .unwrap_or_default()
+    });
+
+    let permissions = state!(auth)
+        .permissions
+        .iter()
+        .map(|permission| Entry {
+            id: permission.clone(),
+            slug: permission.clone(),
+            title: permission.clone(),
+            variant: None,
+        })
+        .collect::<Vec<Entry>>();
+
+    let create_access_token = move |event: Event<FormData>| {
+        event.prevent_default();
+        event.stop_propagation();
+
+        let request = AccessTokenRequest {
+            name: event.get_str("name").unwrap_or_default(),
+            permissions: event.get_str_array("permissions").unwrap_or_default(),
+            expires_in_days: event.get_i64("expires_in_days").unwrap_or_default(),
+        };
+
+        spawn(async move {
+            let response = state!(client)
+                .post(url!(API_AUTH, "tokens"))
+                .json(&request)
+                .send()
+                .await
+                .get_value()
+                .await;
+            if let Some(created) = response.self_obj::<AccessTokenCreated>() {
+                created_token_signal.set(Some(created.token));
+                access_tokens_resource.restart();
+            }
+        });
+    };
+
+    let revoke_access_token = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let access_token_id = access_token_id_signal();
+            if post_request!(url!(API_AUTH, "tokens", access_token_id.as_ref(), "revocations")) {
+                success_dialog!("message-success-access-token-revoked");
+                access_tokens_resource.restart();
+            }
+        });
+    });
+
+    let format_date_time = |date_time_opt: Option<Cow<'static, str>>| {
+        date_time_opt
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    let expiry_items = ACCESS_TOKEN_EXPIRY_DAYS
+        .iter()
+        .map(|days| (days.to_string(), format!("{} {}", days, t!("user-device-expiry-days"))))
+        .collect::<Vec<(String, String)>>();
+
+    rsx! {
+        section { class: "flex w-full grow flex-col gap-6 xl:pr-16",
+            if let Some(token) = created_token_signal() {
+                div { class: "alert alert-success flex flex-col items-start",
+                    p { { t!("message-access-token-created") } }
+                    code { class: "break-all select-all", { token.as_ref() } }
+                }
+            }
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("access-token-field-name")} }
+                        th { class: "text-wrap", {t!("access-token-field-permissions")} }
+                        th { class: "text-wrap", {t!("access-token-field-created-at")} }
+                        th { class: "text-wrap", {t!("access-token-field-last-used-at")} }
+                        th { class: "text-wrap", {t!("access-token-field-expires-at")} }
+                        th { class: "text-wrap", {t!("access-token-field-revoked-at")} }
+                        th { class: "w-12" }
+                    }
+                }
+                tbody {
+                    for access_token in access_tokens_resource().unwrap_or_default() {
+                        tr {
+                            td { {access_token.name.as_ref()} }
+                            td { class: "text-wrap", {access_token.permissions.join(", ")} }
+                            td { {format_date_time(Some(access_token.created_at.clone()))} }
+                            td { {format_date_time(access_token.last_used_at.clone())} }
+                            td { {format_date_time(Some(access_token.expires_at.clone()))} }
+                            td { {format_date_time(access_token.revoked_at.clone())} }
+                            td {
+                                if access_token.active {
+                                    button {
+                                        class: "btn btn-xs btn-error",
+                                        onclick: move |_| {
+                                            access_token_id_signal.set(access_token.id.clone());
+                                            alert_dialog!(
+                                                "message-confirm-access-token-revoke",
+                                                revoke_access_token
+                                            );
+                                        },
+                                        { t!("action-revoke") }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            form {
+                class: "flex w-full flex-col gap-3 sm:max-w-md self-center",
+                autocomplete: "off",
+                onsubmit: create_access_token,
+                FormTextField {
+                    name: "name",
+                    title: "access-token-field-name",
+                    required: true,
+                }
+                FormSimpleSelectField {
+                    name: "expires_in_days",
+                    title: "access-token-field-expires-in",
+                    required: true,
+                    selected: "90",
+                    items: expiry_items,
+                }
+                FormEntriesField {
+                    name: "permissions",
+                    title: "access-token-field-permissions",
+                    items: vec![],
+                    entries: permissions,
+                }
+                div { class: "flex justify-center",
+                    button { class: "btn btn-primary",
+                        Icon { icon: Icons::Plus, class: "size-6" }
+                        { t!("action-create-access-token") }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -15,10 +15,18 @@ pub fn Administrator() -> Element {
         migration.clone()
     } else { "0000-init.surql".into() };
 
+    let number_plate_collisions = response()
+        .key_obj::<Vec<Value>>("number_plate_collisions").unwrap_or_default()
+        .iter()
+        .filter_map(|collision| collision.key_string("number"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
     let groups_stat = response().key_obj::<Vec<GroupStat>>("groups_stat").unwrap_or_default();
+    // the top level groups already include their subgroups
     let groups_stat_total = format!("{} / {}",
-        groups_stat.iter().map(|x| x.online).sum::<i64>(),
-        groups_stat.iter().map(|x| x.total).sum::<i64>()
+        groups_stat.iter().filter(|x| x.depth == 0).map(|x| x.online).sum::<i64>(),
+        groups_stat.iter().filter(|x| x.depth == 0).map(|x| x.total).sum::<i64>()
     );
 
     let migrate = move |event: Event<MouseData>| {
@@ -61,6 +69,13 @@ pub fn Administrator() -> Element {
                 class: "flex w-full flex-wrap pb-4 sm:px-4 justify-center text-2xl font-semibold",
                 { t!("caption-administrator-dashboard") }
             }
+            if !number_plate_collisions.is_empty() {
+                div {
+                    class: "alert alert-warning flex w-full flex-col justify-center",
+                    p { { t!("message-number-plate-collisions") } }
+                    p { class: "font-mono", { number_plate_collisions } }
+                }
+            }
             div {
                 class: "flex grow flex-wrap gap-5",
 
@@ -348,6 +363,7 @@ pub fn Administrator() -> Element {
                                 for group in groups_stat.into_iter() {
                                     tr {
                                         td {
+                                            style: format!("padding-left: {}rem", 0.5 + group.depth as f32),
                                             { &*group.title }
                                         }
                                         td {
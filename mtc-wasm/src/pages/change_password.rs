@@ -2,7 +2,9 @@ use super::*;
 
 /// A component for changing the user's password.
 ///
-/// This component is only available if the user is authenticated.
+/// This component is available to the authenticated user and to the user whose
+/// sign-in waits for the password change, the latter is signed in once the
+/// password is changed. The rules of the password policy are listed above the form.
 ///
 /// It contains a form with three fields: `current-password`, `new-password` and
 /// `password-confirmation`. The `current-password` and `new-password` fields are
@@ -17,10 +19,14 @@ use super::*;
 pub fn ChangePassword() -> Element {
     breadcrumbs!("menu-change-password");
 
-    if !state!(auth).is_authenticated() {
-        navigator().push(route!(API_AUTH, API_SIGN_IN));
-        return rsx! { Loading {} }
-    }
+    let authenticated = state!(auth).is_authenticated();
+    let sync = use_coroutine_handle::<SyncAction>();
+
+    let password_policy_resource = use_resource(move || async move {
+        value_request!(url!(API_AUTH, "password-policy"))
+            .self_obj::<PasswordPolicy>()
+            .unwrap_or_default()
+    });
 
     let submit = move |event: Event<FormData>| {
         let current_password =
@@ -41,12 +47,18 @@ pub fn ChangePassword() -> Element {
         });
 
         spawn(async move {
-            if patch_request!(url!(API_AUTH), payload) {
-                success_dialog!("message-password-changed")
+            if !patch_request!(url!(API_AUTH), payload) { return; }
+
+            success_dialog!("message-password-changed");
+            if !authenticated {
+                sync.send(SyncAction::RefreshState());
+                navigator().replace(route!());
             }
         });
     };
 
+    let password_policy = password_policy_resource().unwrap_or_default();
+
     rsx!{
         div { 
             class: "div-centered",        
@@ -62,7 +74,34 @@ pub fn ChangePassword() -> Element {
                         }
                         p { 
                             class: "py-6", 
-                            { t!("message-change-password-form-announcement") }
+                            if authenticated {
+                                { t!("message-change-password-form-announcement") }
+                            } else {
+                                { t!("message-password-change-required-announcement") }
+                            }
+                        }
+                        p { { t!("message-password-policy") } }
+                        ul {
+                            class: "list-disc list-inside",
+                            li { { format!("{} {}", t!("password-policy-min-length"), password_policy.min_length) } }
+                            if password_policy.uppercase {
+                                li { { t!("password-policy-uppercase") } }
+                            }
+                            if password_policy.lowercase {
+                                li { { t!("password-policy-lowercase") } }
+                            }
+                            if password_policy.digit {
+                                li { { t!("password-policy-digit") } }
+                            }
+                            if password_policy.symbol {
+                                li { { t!("password-policy-symbol") } }
+                            }
+                        }
+                        if password_policy.expiration_days > 0 {
+                            p {
+                                class: "pt-3",
+                                { format!("{} {}", t!("password-policy-expiration"), password_policy.expiration_days) }
+                            }
                         }
                     }
                     div { 
@@ -10,6 +10,7 @@ pub fn GatePassBlockDialogView(
         return rsx! {};
     };
 
+    let block_reasons = gate_pass_dictionary_items(GatePassDictionaryKind::BlockReason);
     let mut unblock_signal = use_signal(|| false);
     let on_submit_gate_pass_block = move |event: Event<FormData>| {
         event.prevent_default();
@@ -20,6 +21,9 @@ pub fn GatePassBlockDialogView(
         } else {
             let block = json!({
                 "expired_at": event.get_str("expired_at"),
+                "reason_code": event
+                    .get_str("reason_code")
+                    .filter(|reason_code| !reason_code.is_empty()),
                 "reason": event.get_str("reason"),
             });
             json!({
@@ -73,10 +77,17 @@ pub fn GatePassBlockDialogView(
                         initial_value: gate_pass_block_signal.read().as_ref()
                             .map(|block| block.expired_at[0..10].to_string()),
                     }
+                    FormSimpleSelectField {
+                        name: "reason_code",
+                        title: "gate-pass-field-block-reason-code",
+                        selected: gate_pass_block_signal.read().as_ref()
+                            .and_then(|block| block.reason_code.clone())
+                            .unwrap_or_default(),
+                        items: block_reasons,
+                    }
                     FormTextAreaField {
                         name: "reason",
                         title: "gate-pass-field-block-reason",
-                        required: true,
                         initial_value: gate_pass_block_signal.read().as_ref()
                             .map(|block| block.reason.to_string()),
                     }
@@ -102,4 +113,78 @@ pub fn GatePassBlockDialogView(
             }
         }
     }
-}
\ No newline at end of file
+}
+/// Lifted and expired blocks of the gate pass, the latest first.
+#[component]
+pub fn GatePassBlockHistory(block_history: Vec<GatePassBlockRecord>) -> Element {
+    if block_history.is_empty() {
+        return rsx! {};
+    }
+
+    let format_date_time = |date_time_opt: Option<Cow<'static, str>>| {
+        date_time_opt
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    rsx! {
+        details {
+            class: "collapse collapse-arrow bg-base-200 rounded mt-4",
+            summary {
+                class: "collapse-title font-medium",
+                { t!("gate-pass-block-history") }
+            }
+            div {
+                class: "collapse-content p-2",
+                table { class: "entry-table",
+                    thead {
+                        tr {
+                            th { class: "text-wrap", {t!("gate-pass-field-block-reason-code")} }
+                            th { class: "text-wrap", {t!("gate-pass-field-blocked-by")} }
+                            th { class: "text-wrap", {t!("gate-pass-field-block-expired-at")} }
+                            th { class: "text-wrap", {t!("gate-pass-field-unblocked-by")} }
+                        }
+                    }
+                    tbody {
+                        for block_record in block_history.into_iter().rev() {
+                            tr {
+                                td {
+                                    {
+                                        gate_pass_block_reason(&GatePassBlock {
+                                            reason_code: block_record.reason_code.clone(),
+                                            reason: block_record.reason.clone(),
+                                            ..Default::default()
+                                        })
+                                    }
+                                }
+                                td {
+                                    {
+                                        format!(
+                                            "{} {}",
+                                            block_record.blocked_by.clone().unwrap_or_default(),
+                                            format_date_time(block_record.blocked_at.clone()),
+                                        )
+                                    }
+                                }
+                                td { {format_date_time(Some(block_record.expired_at.clone()))} }
+                                td {
+                                    {
+                                        format!(
+                                            "{} {}",
+                                            block_record
+                                                .unblocked_by
+                                                .clone()
+                                                .unwrap_or_else(|| Cow::Owned(t!("gate-pass-block-expired"))),
+                                            format_date_time(Some(block_record.unblocked_at.clone())),
+                                        )
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
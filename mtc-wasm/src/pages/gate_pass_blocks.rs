@@ -0,0 +1,103 @@
+use super::*;
+
+/// Report of the currently blocked gate passes ordered by the block expiry.
+#[component]
+pub fn GatePassBlocks() -> Element {
+    breadcrumbs!("menu-gate-pass-blocks");
+    check_permission!(PERMISSION_GATE_PASS_READ);
+
+    let blocked_gate_passes_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(API_GATE_PASSES, "blocks"))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<BlockedGatePass>>()
+            .unwrap_or_default()
+    });
+
+    let export_blocked_gate_passes = move |_| {
+        spawn(async move {
+            let blocked_gate_passes = blocked_gate_passes_resource().unwrap_or_default();
+            if let Err(error) =
+                export_blocked_gate_passes_to_csv(blocked_gate_passes, "blocked_gate_passes.csv")
+                    .await
+            {
+                let error = format!("{}: {}", t!("error-export"), error.message());
+                error_dialog!(error.as_str());
+            }
+        });
+    };
+
+    let format_date_time = |date_time_opt: Option<Cow<'static, str>>| {
+        date_time_opt
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    let blocked_gate_passes = blocked_gate_passes_resource().unwrap_or_default();
+
+    rsx! {
+        section { class: "w-full grow xl:pr-16",
+            div { class: "flex justify-end mb-2",
+                button {
+                    class: "btn btn-sm btn-primary",
+                    disabled: blocked_gate_passes.is_empty(),
+                    onclick: export_blocked_gate_passes,
+                    { t!("gate-pass-action-export-blocked") }
+                }
+            }
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("gate-pass-field-number")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-owner")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-vehicle-number-plate")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-block-reason-code")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-block-expired-at")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-blocked-by")} }
+                    }
+                }
+                tbody {
+                    for blocked_gate_pass in blocked_gate_passes {
+                        tr {
+                            td { {blocked_gate_pass.number.as_ref()} }
+                            td {
+                                {
+                                    format!(
+                                        "{} {} {}",
+                                        blocked_gate_pass.owner.last_name,
+                                        blocked_gate_pass.owner.first_name,
+                                        blocked_gate_pass.owner.middle_name,
+                                    )
+                                }
+                            }
+                            td {
+                                {
+                                    blocked_gate_pass
+                                        .vehicles
+                                        .first()
+                                        .map(|vehicle| vehicle.number_plate.to_string())
+                                        .unwrap_or_default()
+                                }
+                            }
+                            td { {gate_pass_block_reason(&blocked_gate_pass.block)} }
+                            td { {format_date_time(Some(blocked_gate_pass.block.expired_at.clone()))} }
+                            td {
+                                {
+                                    format!(
+                                        "{} {}",
+                                        blocked_gate_pass.block.blocked_by.clone().unwrap_or_default(),
+                                        format_date_time(blocked_gate_pass.block.blocked_at.clone()),
+                                    )
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use super::*;
+
+const BULK_OPERATIONS: [&str; 6] = [
+    "Block",
+    "Unblock",
+    "ChangeUnit",
+    "ChangeExpiry",
+    "SendEmail",
+    "Export",
+];
+
+#[component]
+pub fn GatePassBulkButton(gate_pass_bulk_dialog_visible_signal: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: "hover:btn-neutral join-item",
+            onclick: move |event| {
+                event.prevent_default();
+                event.stop_propagation();
+
+                gate_pass_bulk_dialog_visible_signal.set(true);
+            },
+            Icon { icon: Icons::Columns, class: "size-8" }
+            span {
+                class: "opacity-0 group-hover:opacity-100",
+                { t!("gate-pass-action-bulk") }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn GatePassBulkDialogView(
+    gate_pass_selected_ids_signal: Signal<HashSet<String>>,
+    gate_pass_bulk_dialog_visible_signal: Signal<bool>,
+) -> Element {
+    let mut operation_signal = use_signal(|| BULK_OPERATIONS[0].to_string());
+    let gate_pass_filters_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(API_GATE_PASSES, "filters"))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<GatePassFilter>>()
+            .unwrap_or_default()
+    });
+    if !gate_pass_bulk_dialog_visible_signal() {
+        return rsx! {};
+    };
+    let checked_gate_pass_bulk = !gate_pass_selected_ids_signal.read().is_empty();
+    let gate_pass_filters = gate_pass_filters_resource().unwrap_or_default();
+    let on_submit_gate_pass_bulk = move |event: Event<FormData>| {
+        event.prevent_default();
+        event.stop_propagation();
+
+        let ids = gate_pass_selected_ids_signal();
+        let units = event
+            .get_str("units")
+            .filter(|string| !string.is_empty())
+            .map(|string| split(string.as_ref(), ","));
+        let filter_id = event.get_str("filter_id").filter(|string| !string.is_empty());
+        let filter_title = event
+            .get_str("filter_title")
+            .filter(|string| !string.trim().is_empty());
+        let operation = match operation_signal().as_str() {
+            "Block" => json!({
+                "Block": {
+                    "expired_at": event.get_str("expired_at"),
+                    "reason_code": event
+                        .get_str("reason_code")
+                        .filter(|reason_code| !reason_code.is_empty()),
+                    "reason": event.get_str("reason"),
+                }
+            }),
+            "ChangeUnit" => json!({ "ChangeUnit": event.get_str("unit") }),
+            "ChangeExpiry" => json!({ "ChangeExpiry": event.get_str("expired_at") }),
+            "SendEmail" => json!({
+                "SendEmail": event
+                    .get_str("recipient_email")
+                    .filter(|recipient_email| !recipient_email.trim().is_empty())
+            }),
+            operation => json!(operation),
+        };
+        spawn(async move {
+            let filter = units.map(|units| json!({ "units": units }));
+            let filter_id = match (filter_id, &filter, filter_title) {
+                (Some(filter_id), _, _) => Some(filter_id),
+                (None, Some(filter), Some(title)) => {
+                    let payload = json!({ "title": title, "filter": filter });
+                    let Some(gate_pass_filter) =
+                        value_request!(url!(API_GATE_PASSES, "filters"), payload)
+                            .self_obj::<GatePassFilter>()
+                    else {
+                        return;
+                    };
+                    Some(gate_pass_filter.id)
+                }
+                _ => None,
+            };
+            let payload = json!({
+                "ids": ids,
+                "filter": filter,
+                "filter_id": filter_id,
+                "operation": operation,
+            });
+            let response = value_request!(url!(API_GATE_PASSES, "bulks"), payload)
+                .self_obj::<BulkGatePassResponse>();
+            let Some(response) = response else {
+                return;
+            };
+            gate_pass_bulk_dialog_visible_signal.set(false);
+            if operation_signal().eq("Export") {
+                if let Err(error) =
+                    export_gate_passes_to_csv(response.gate_passes.clone(), "gate_passes.csv")
+                        .await
+                {
+                    let error = format!("{}: {}", t!("error-export"), error.message());
+                    error_dialog!(error.as_str());
+                    return;
+                }
+            }
+            let number_of_failed = response.number_of_failures();
+            let message = t!("gate-pass-bulk-result")
+                .replace(
+                    "{succeeded}",
+                    &(response.results.len() - number_of_failed).to_string(),
+                )
+                .replace("{failed}", &number_of_failed.to_string());
+            if number_of_failed > 0 {
+                alert_dialog!(message.as_str());
+            } else {
+                success_dialog!(message.as_str());
+            }
+            navigator().push(route!(API_ADMINISTRATOR, "loops"));
+        });
+    };
+
+    rsx! {
+            section {
+                class: "modal modal-open",
+                div {
+                    class: "modal-box",
+                    div {
+                        class: "absolute top-0 right-0 join rounded-none",
+                        button {
+                            class: "btn btn-sm btn-ghost join-item hover:text-error",
+                            onclick: move |_| {
+                                gate_pass_bulk_dialog_visible_signal.set(false);
+                            },
+                            Icon { icon: Icons::Close, class: "size-4" }
+                        }
+                    }
+
+                 form {
+                    class: "flex grow flex-col items-center gap-3",
+                    id: "gate-pass-bulk-form",
+                    autocomplete: "off",
+                    onsubmit: on_submit_gate_pass_bulk,
+                    if checked_gate_pass_bulk {
+                        p { {format!("{} {}", t!("gate-pass-selected-bulk-description"), gate_pass_selected_ids_signal.read().len())} }
+                    } else {
+                        p {
+                            { t!("gate-pass-bulk-description") }
+                        }
+                        label {
+                            class: "w-full floating-label mt-4",
+                            span {
+                                "⌘ " { t!("gate-pass-field-bulk-filter") }
+                            }
+                            select {
+                                class: "select",
+                                name: "filter_id",
+                                option {
+                                    initial_selected: true,
+                                    value: "",
+                                    { t!("gate-pass-bulk-filter-units") }
+                                }
+                                for gate_pass_filter in gate_pass_filters.iter() {
+                                    option {
+                                        value: gate_pass_filter.id.to_string(),
+                                        { gate_pass_filter.title.to_string() }
+                                    }
+                                }
+                            }
+                        }
+                        FormTextAreaField {
+                            name: "units",
+                            title: "gate-pass-field-bulk-units",
+                        }
+                        FormTextField {
+                            name: "filter_title",
+                            title: "gate-pass-field-bulk-filter-title",
+                            required: false,
+                        }
+                    }
+                    label {
+                        class: "w-full floating-label mt-4",
+                        span {
+                            "⌘ " { t!("gate-pass-field-bulk-operation") }
+                        }
+                        select {
+                            class: "select",
+                            required: true,
+                            onchange: move |event| operation_signal.set(event.value()),
+                            for operation in BULK_OPERATIONS {
+                                option {
+                                    initial_selected: operation_signal().eq(operation),
+                                    value: operation,
+                                    { t!(format!("gate-pass-bulk-operation-{}", operation).to_lowercase().as_str()) }
+                                }
+                            }
+                        }
+                    }
+                    match operation_signal().as_str() {
+                        "Block" => rsx! {
+                            FormDateField {
+                                name: "expired_at",
+                                title: "gate-pass-field-block-expired-at",
+                                required: true,
+                                initial_value: default_expired_at(),
+                            }
+                            FormSimpleSelectField {
+                                name: "reason_code",
+                                title: "gate-pass-field-block-reason-code",
+                                selected: "",
+                                items: gate_pass_dictionary_items(GatePassDictionaryKind::BlockReason),
+                            }
+                            FormTextAreaField {
+                                name: "reason",
+                                title: "gate-pass-field-block-reason",
+                            }
+                        },
+                        "ChangeUnit" => rsx! {
+                            FormTextField {
+                                name: "unit",
+                                title: "gate-pass-field-owner-unit",
+                                required: true,
+                            }
+                        },
+                        "ChangeExpiry" => rsx! {
+                            FormDateField {
+                                name: "expired_at",
+                                title: "gate-pass-field-expired-at",
+                                required: true,
+                                initial_value: default_expired_at(),
+                            }
+                        },
+                        "SendEmail" => rsx! {
+                            FormTextField {
+                                name: "recipient_email",
+                                title: "gate-pass-field-recipient-email",
+                                required: false,
+                                r#type: "email",
+                            }
+                            p { class: "text-sm", { t!("gate-pass-bulk-recipient-email-description") } }
+                        },
+                        _ => rsx! {},
+                    }
+                    div {style: "justify-content:center", class: "flex gap-3",
+                        button {
+                            class: "btn btn-primary",
+                            { t!("gate-pass-action-bulk-apply") }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+#[component]
+pub fn GatePassDevices() -> Element {
+    breadcrumbs!("menu-gate-pass-devices");
+    check_permission!(PERMISSION_GATE_PASS_DEVICES);
+
+    let mut device_id_signal = use_signal(|| Cow::Borrowed(""));
+
+    let mut gate_pass_devices_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(API_GATE_PASSES, "devices"))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<GatePassDevice>>()
+            .unwrap_or_default()
+    });
+
+    let revoke_device = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let device_id = device_id_signal();
+            if post_request!(url!(API_GATE_PASSES, "devices", device_id.as_ref(), "revocations")) {
+                success_dialog!("message-success-gate-pass-device-revoked");
+                gate_pass_devices_resource.restart();
+            }
+        });
+    });
+
+    let format_date_time = |date_time_opt: Option<Cow<'static, str>>| {
+        date_time_opt
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    rsx! {
+        section { class: "w-full grow xl:pr-16",
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("gate-pass-device-field-login")} }
+                        th { class: "text-wrap", {t!("gate-pass-device-field-device")} }
+                        th { class: "text-wrap", {t!("gate-pass-device-field-os")} }
+                        th { class: "text-wrap", {t!("gate-pass-device-field-last-synced-at")} }
+                        th { class: "text-wrap", {t!("gate-pass-device-field-revoked-at")} }
+                        th { class: "w-12" }
+                    }
+                }
+                tbody {
+                    for gate_pass_device in gate_pass_devices_resource().unwrap_or_default() {
+                        tr {
+                            td { {gate_pass_device.login.as_ref()} }
+                            td { {gate_pass_device.device.as_ref()} }
+                            td { {gate_pass_device.os.as_ref()} }
+                            td { {format_date_time(gate_pass_device.last_synced_at.clone())} }
+                            td {
+                                if gate_pass_device.revoked {
+                                    {
+                                        format!(
+                                            "{} ({})",
+                                            format_date_time(gate_pass_device.revoked_at.clone()),
+                                            gate_pass_device.revoked_by.clone().unwrap_or_default(),
+                                        )
+                                    }
+                                }
+                            }
+                            td { class: "flex gap-2",
+                                if !gate_pass_device.revoked {
+                                    GatePassSnapshotExportButton { device_id: gate_pass_device.id.clone() }
+                                    button {
+                                        class: "btn btn-xs btn-error",
+                                        onclick: move |_| {
+                                            device_id_signal.set(gate_pass_device.id.clone());
+                                            alert_dialog!(
+                                                "message-confirm-gate-pass-device-revoke",
+                                                revoke_device
+                                            );
+                                        },
+                                        { t!("action-revoke") }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::*;
+
+static DICTIONARY_LOCALES: [&str; 2] = ["en-US", "uk-UA"];
+
+#[component]
+pub fn GatePassDictionaryEditor() -> Element {
+    breadcrumbs!("menu-gate-pass-dictionaries");
+    check_permission!(PERMISSION_GATE_PASS_DICTIONARIES);
+
+    let mut entry_id_signal = use_signal(|| Cow::Borrowed(""));
+
+    let mut gate_pass_dictionaries_resource = use_resource(move || async move {
+        let gate_pass_dictionaries = state!(client)
+            .get(url!(API_GATE_PASSES, "dictionaries"))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<GatePassDictionaries>()
+            .unwrap_or_default();
+        state!(set_gate_pass_dictionaries, gate_pass_dictionaries.clone());
+        gate_pass_dictionaries
+    });
+
+    let on_submit_entry = move |event: Event<FormData>| {
+        let entry_id = event.get_str("id").unwrap_or_default();
+        let translations = DICTIONARY_LOCALES
+            .iter()
+            .filter_map(|locale| {
+                event
+                    .get_str(&format!("name_{locale}"))
+                    .filter(|name| !name.trim().is_empty())
+                    .map(|name| (Cow::Borrowed(*locale), Cow::Owned(name.trim().to_string())))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let payload = json!(GatePassDictionaryEntryRequest {
+            kind: event
+                .get_str("kind")
+                .and_then(|kind| serde_json::from_value(json!(kind)).ok())
+                .unwrap_or_default(),
+            code: Cow::Owned(event.get_str("code").unwrap_or_default().trim().to_string()),
+            translations,
+            print_name: Cow::Owned(
+                event.get_str("print_name").unwrap_or_default().trim().to_string()
+            ),
+            sort_order: event.get_i64("sort_order").unwrap_or_default(),
+            ..Default::default()
+        });
+
+        spawn(async move {
+            let saved = if entry_id.is_empty() {
+                post_request!(url!(API_GATE_PASSES, "dictionaries"), payload)
+            } else {
+                patch_request!(url!(API_GATE_PASSES, "dictionaries", entry_id.as_ref()), payload)
+            };
+            if saved {
+                success_dialog!("message-success-post");
+                gate_pass_dictionaries_resource.restart();
+            }
+        });
+    };
+
+    let delete_entry = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let entry_id = entry_id_signal();
+            if delete_request!(url!(API_GATE_PASSES, "dictionaries", entry_id.as_ref())) {
+                success_dialog!("message-success-deletion");
+                gate_pass_dictionaries_resource.restart();
+            }
+        });
+    });
+
+    let gate_pass_dictionaries = gate_pass_dictionaries_resource().unwrap_or_default();
+
+    rsx! {
+        section { class: "w-full grow xl:pr-16",
+            for kind in GatePassDictionaryKind::values() {
+                details {
+                    class: "collapse collapse-arrow bg-base-200 rounded mb-4",
+                    summary {
+                        class: "collapse-title font-medium",
+                        { gate_pass_dictionary_kind_name(&kind) }
+                    }
+                    div {
+                        class: "collapse-content flex flex-col gap-2 p-2",
+                        for entry in gate_pass_dictionaries.entries(kind).cloned() {
+                            GatePassDictionaryEntryForm {
+                                key: "{entry.id}",
+                                kind,
+                                entry: Some(entry),
+                                on_submit: on_submit_entry,
+                                on_delete: move |entry_id: Cow<'static, str>| {
+                                    entry_id_signal.set(entry_id);
+                                    alert_dialog!("message-confirm-deletion", delete_entry);
+                                },
+                            }
+                        }
+                        GatePassDictionaryEntryForm {
+                            kind,
+                            entry: None,
+                            on_submit: on_submit_entry,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn GatePassDictionaryEntryForm(
+    kind: GatePassDictionaryKind,
+    entry: Option<GatePassDictionaryEntry>,
+    on_submit: EventHandler<Event<FormData>>,
+    on_delete: Option<EventHandler<Cow<'static, str>>>,
+) -> Element {
+    let new_entry = entry.is_none();
+    let entry = entry.unwrap_or_default();
+
+    rsx! {
+        form {
+            class: "flex flex-wrap items-end gap-2",
+            autocomplete: "off",
+            onsubmit: move |event| on_submit.call(event),
+            input { r#type: "hidden", name: "id", value: "{entry.id}" }
+            input { r#type: "hidden", name: "kind", value: format!("{:?}", kind) }
+            label { class: "form-control w-40",
+                span { class: "label-text", { t!("gate-pass-dictionary-field-code") } }
+                input {
+                    class: "input input-sm input-bordered",
+                    name: "code",
+                    required: true,
+                    readonly: !new_entry,
+                    initial_value: "{entry.code}",
+                }
+            }
+            for locale in DICTIONARY_LOCALES {
+                label { class: "form-control w-48",
+                    span { class: "label-text", { format!("{} ({locale})", t!("gate-pass-dictionary-field-name")) } }
+                    input {
+                        class: "input input-sm input-bordered",
+                        name: "name_{locale}",
+                        required: true,
+                        initial_value: entry.translations.get(locale).cloned().unwrap_or_default(),
+                    }
+                }
+            }
+            label { class: "form-control w-48",
+                span { class: "label-text", { t!("gate-pass-dictionary-field-print-name") } }
+                input {
+                    class: "input input-sm input-bordered",
+                    name: "print_name",
+                    required: true,
+                    initial_value: "{entry.print_name}",
+                }
+            }
+            label { class: "form-control w-24",
+                span { class: "label-text", { t!("gate-pass-dictionary-field-sort-order") } }
+                input {
+                    class: "input input-sm input-bordered",
+                    r#type: "number",
+                    name: "sort_order",
+                    initial_value: "{entry.sort_order}",
+                }
+            }
+            button {
+                class: "btn btn-sm btn-primary",
+                r#type: "submit",
+                if new_entry { { t!("action-create") } } else { { t!("action-save") } }
+            }
+            if let Some(on_delete) = on_delete {
+                button {
+                    class: "btn btn-sm btn-error",
+                    r#type: "button",
+                    onclick: move |_| on_delete.call(entry.id.clone()),
+                    { t!("action-delete") }
+                }
+            }
+        }
+    }
+}
+
+pub fn gate_pass_dictionary_kind_name(kind: &GatePassDictionaryKind) -> String {
+    let key = format!("gate-pass-dictionary-kind-{:?}", kind).to_lowercase();
+    t!(key.as_str())
+}
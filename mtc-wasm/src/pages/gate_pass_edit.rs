@@ -28,27 +28,17 @@ fn GatePassEditorView(
     new_gate_pass_memo: Memo<bool>,
     gate_pass_value: Value,
 ) -> Element {
-    let titles = GatePassOwnerTitle::values()
-        .into_iter()
-        .map(|title| (format!("{:?}", title), gate_pass_owner_title_name(&title)))
-        .collect::<Vec<(String, String)>>();
-
-    let body_types = VehicleBodyType::values()
-        .into_iter()
-        .map(|body_type| {
-            (
-                format!("{:?}", body_type),
-                gate_pass_vehicle_body_type_name(&body_type),
-            )
-        })
-        .collect::<Vec<(String, String)>>();
-
-    let colors = VehicleColor::values()
-        .into_iter()
-        .map(|color| (format!("{:?}", color), gate_pass_vehicle_color_name(&color)))
-        .collect::<Vec<(String, String)>>();
+    let titles = gate_pass_dictionary_items(GatePassDictionaryKind::OwnerTitle);
+    let body_types = gate_pass_dictionary_items(GatePassDictionaryKind::VehicleBodyType);
+    let colors = gate_pass_dictionary_items(GatePassDictionaryKind::VehicleColor);
 
     let owner = gate_pass_value.get("owner").unwrap_or_default();
+    let block_history = gate_pass_value
+        .get("block_history")
+        .and_then(|block_history| {
+            serde_json::from_value::<Vec<GatePassBlockRecord>>(block_history.clone()).ok()
+        })
+        .unwrap_or_default();
     let vehicle = gate_pass_value
         .get("vehicles")
         .and_then(|vehicles| vehicles.as_array())
@@ -62,6 +52,8 @@ fn GatePassEditorView(
             "last_name": event.get_str("owner_last_name"),
             "title": event.get_str("owner_title"),
             "unit": event.get_str("owner_unit"),
+            "phone": event.get_str("owner_phone"),
+            "email": event.get_str("owner_email"),
         });
         let vehicle = json!({
             "number_plate": event.get_str("vehicle_number_plate"),
@@ -148,6 +140,20 @@ fn GatePassEditorView(
                 required: true,
                 initial_value: owner.key_string("unit"),
             }
+            FormTextField {
+                name: "owner_phone",
+                title: "gate-pass-field-owner-phone",
+                r#type: "tel",
+                required: false,
+                initial_value: owner.key_string("phone"),
+            }
+            FormTextField {
+                name: "owner_email",
+                title: "gate-pass-field-owner-email",
+                r#type: "email",
+                required: false,
+                initial_value: owner.key_string("email"),
+            }
 
             // vehicle
             FormTextField {
@@ -194,6 +200,7 @@ fn GatePassEditorView(
                 initial_checked: gate_pass_value.key_bool("allow_any_vehicle"),
             }
         }
+        GatePassBlockHistory { block_history }
         EntryInfoBox {
             created_by: gate_pass_value.key_string("created_by"),
             created_at: gate_pass_value.key_datetime("created_at"),
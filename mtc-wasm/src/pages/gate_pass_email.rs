@@ -4,6 +4,7 @@ use dioxus::prelude::*;
 #[component]
 pub fn GatePassSendEmailDialogView(
     gate_pass_id_send_email_signal: Signal<Option<String>>,
+    gate_pass_owner_email_signal: Signal<Option<String>>,
 ) -> Element {
     if gate_pass_id_send_email_signal.read().is_none() {
         return rsx! {};
@@ -14,7 +15,9 @@ pub fn GatePassSendEmailDialogView(
         event.stop_propagation();
 
         let payload = json!({
-            "recipient_email": event.get_str("recipient_email"),
+            "recipient_email": event
+                .get_str("recipient_email")
+                .filter(|recipient_email| !recipient_email.trim().is_empty()),
         });
 
         spawn(async move {
@@ -57,7 +60,11 @@ pub fn GatePassSendEmailDialogView(
                         r#type: "email",
                         name: "recipient_email",
                         title: "gate-pass-field-recipient-email",
-                        required: true,
+                        required: gate_pass_owner_email_signal.read().is_none(),
+                        initial_value: gate_pass_owner_email_signal(),
+                    }
+                    if gate_pass_owner_email_signal.read().is_some() {
+                        p { class: "text-sm", { t!("gate-pass-recipient-email-description") } }
                     }
                     button {
                         class: "btn btn-primary",
@@ -87,23 +87,18 @@ struct ImportMappers {
 impl ImportMappers {
     fn new() -> Self {
         ImportMappers {
-            title_name_to_title: GatePassOwnerTitle::values()
-                .into_iter()
-                .map(|value| (gate_pass_owner_title_name(&value).to_lowercase(), value))
-                .collect::<HashMap<_, _>>(),
-            color_name_to_color: VehicleColor::values()
-                .into_iter()
-                .map(|value| (gate_pass_vehicle_color_name(&value).to_lowercase(), value))
-                .collect::<HashMap<_, _>>(),
-            body_type_name_to_body_type: VehicleBodyType::values()
-                .into_iter()
-                .map(|value| {
-                    (
-                        gate_pass_vehicle_body_type_name(&value).to_lowercase(),
-                        value,
-                    )
-                })
-                .collect::<HashMap<_, _>>(),
+            title_name_to_title: dictionary_name_to_code(
+                GatePassDictionaryKind::OwnerTitle,
+                GatePassOwnerTitle,
+            ),
+            color_name_to_color: dictionary_name_to_code(
+                GatePassDictionaryKind::VehicleColor,
+                VehicleColor,
+            ),
+            body_type_name_to_body_type: dictionary_name_to_code(
+                GatePassDictionaryKind::VehicleBodyType,
+                VehicleBodyType,
+            ),
             allow_any_vehicle_name_to_allow_any_vehicle: vec![true, false]
                 .into_iter()
                 .map(|value| {
@@ -139,6 +134,29 @@ impl ImportMappers {
     }
 }
 
+/// Maps the code, the print name and every translation of the dictionary entries
+/// to the entry code, so imported files may use any of them.
+fn dictionary_name_to_code<T>(
+    kind: GatePassDictionaryKind,
+    to_value: impl Fn(Cow<'static, str>) -> T,
+) -> HashMap<String, T>
+where
+    T: Clone,
+{
+    let mut name_to_code = HashMap::new();
+    for entry in state!(gate_pass_dictionaries).entries(kind) {
+        let value = to_value(entry.code.clone());
+        for name in entry
+            .translations
+            .values()
+            .chain([&entry.print_name, &entry.code])
+        {
+            name_to_code.insert(name.trim().to_lowercase(), value.clone());
+        }
+    }
+    name_to_code
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
 pub struct CreateGatePassRequestCsvRecord {
     #[validate(length(min = 1))]
@@ -165,6 +183,10 @@ pub struct CreateGatePassRequestCsvRecord {
     pub color: Cow<'static, str>,
     #[validate(length(min = 1))]
     pub body_type: Cow<'static, str>,
+    #[serde(default)]
+    pub phone: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub email: Option<Cow<'static, str>>,
 }
 
 async fn read_from_csv_file(files: Vec<FileData>) -> Result<Vec<CreateGatePassRequest>, Error> {
@@ -228,6 +250,8 @@ fn convert_to_create_gate_pass_request(
             middle_name: record.middle_name.clone(),
             title: title.unwrap().clone(),
             unit: record.unit.clone(),
+            phone: record.phone.clone(),
+            email: record.email.clone(),
         },
         vehicles: vec![GatePassVehicle {
             number_plate: record.number_plate.clone(),
@@ -266,6 +290,8 @@ fn convert_to_create_gate_pass_csv_request_record(
         )),
         body_type: Cow::Owned(gate_pass_vehicle_body_type_name(&vehicle.body_type)),
         vin_code: vehicle.vin_code.clone(),
+        phone: owner.phone.clone(),
+        email: owner.email.clone(),
     }
 }
 
@@ -346,6 +372,49 @@ pub fn GatePassExportButton() -> Element {
     }
 }
 
+/// Exports the snapshot for the linked device, only that device can read it.
+#[component]
+pub fn GatePassSnapshotExportButton(device_id: Cow<'static, str>) -> Element {
+    if !state!(auth).has_permission(PERMISSION_GATE_PASS_SNAPSHOT) {
+        return rsx! {};
+    }
+    let export_snapshot = move |_| {
+        let device_id = device_id.clone();
+        async move {
+            let Some(snapshot_file) = state!(client)
+                .post(url!(API_GATE_PASSES, "devices", device_id.as_ref(), "snapshots"))
+                .json(&SyncGatePassRequest::default())
+                .send()
+                .await
+                .get_value()
+                .await
+                .self_obj::<GatePassSnapshotFile>()
+            else {
+                return;
+            };
+            let file_name = format!(
+                "gate-pass-snapshot-{}.json",
+                Local::now().format("%Y%m%d-%H%M")
+            );
+            let snapshot_file_json = serde_json::to_string(&snapshot_file).unwrap_or_default();
+            if JsFuture::from(jsFfiExportJsonFile(&snapshot_file_json, &file_name))
+                .await
+                .is_err()
+            {
+                error!("failed to invoke jsFfiExportJsonFile");
+            }
+        }
+    };
+
+    rsx! {
+        button {
+            class: "btn btn-xs btn-neutral",
+            onclick: export_snapshot,
+            { t!("gate-pass-action-export-snapshot") }
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
 pub struct GatePassCsvRecord {
     #[validate(length(min = 1))]
@@ -376,6 +445,45 @@ pub struct GatePassCsvRecord {
     pub color: Cow<'static, str>,
     #[validate(length(min = 1))]
     pub body_type: Cow<'static, str>,
+    #[serde(default)]
+    pub phone: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub email: Option<Cow<'static, str>>,
+}
+
+pub async fn export_gate_passes_to_csv(
+    gate_passes: Vec<GatePass>,
+    file_name: &str,
+) -> Result<(), Error> {
+    write_to_csv_file(gate_passes, convert_to_gate_pass_csv_record, file_name).await
+}
+
+/// Row of the blocked gate passes report.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BlockedGatePassCsvRecord {
+    pub number: Cow<'static, str>,
+    pub expired_at: Cow<'static, str>,
+    pub last_name: Cow<'static, str>,
+    pub first_name: Cow<'static, str>,
+    pub middle_name: Cow<'static, str>,
+    pub unit: Cow<'static, str>,
+    pub number_plate: Cow<'static, str>,
+    pub block_reason: Cow<'static, str>,
+    pub block_expired_at: Cow<'static, str>,
+    pub blocked_at: Option<Cow<'static, str>>,
+    pub blocked_by: Option<Cow<'static, str>>,
+}
+
+pub async fn export_blocked_gate_passes_to_csv(
+    blocked_gate_passes: Vec<BlockedGatePass>,
+    file_name: &str,
+) -> Result<(), Error> {
+    write_to_csv_file(
+        blocked_gate_passes,
+        convert_to_blocked_gate_pass_csv_record,
+        file_name,
+    )
+    .await
 }
 
 async fn get_gate_passes() -> Result<Vec<GatePass>, Error> {
@@ -429,6 +537,29 @@ where
     String::from_utf8(csv_bytes).map_err(|error| Error::Generic(Cow::Owned(error.to_string())))
 }
 
+fn convert_to_blocked_gate_pass_csv_record(
+    blocked_gate_pass: &BlockedGatePass,
+) -> BlockedGatePassCsvRecord {
+    let owner = &blocked_gate_pass.owner;
+    BlockedGatePassCsvRecord {
+        number: blocked_gate_pass.number.clone(),
+        expired_at: blocked_gate_pass.expired_at.clone(),
+        last_name: owner.last_name.clone(),
+        first_name: owner.first_name.clone(),
+        middle_name: owner.middle_name.clone(),
+        unit: owner.unit.clone(),
+        number_plate: blocked_gate_pass
+            .vehicles
+            .first()
+            .map(|vehicle| vehicle.number_plate.clone())
+            .unwrap_or_default(),
+        block_reason: Cow::Owned(gate_pass_block_reason(&blocked_gate_pass.block)),
+        block_expired_at: blocked_gate_pass.block.expired_at.clone(),
+        blocked_at: blocked_gate_pass.block.blocked_at.clone(),
+        blocked_by: blocked_gate_pass.block.blocked_by.clone(),
+    }
+}
+
 fn convert_to_gate_pass_csv_record(gate_pass: &GatePass) -> GatePassCsvRecord {
     let owner = &gate_pass.owner;
     let vehicle = &gate_pass.require_first_vehicle();
@@ -450,5 +581,7 @@ fn convert_to_gate_pass_csv_record(gate_pass: &GatePass) -> GatePassCsvRecord {
         )),
         body_type: Cow::Owned(gate_pass_vehicle_body_type_name(&vehicle.body_type)),
         vin_code: vehicle.vin_code.clone(),
+        phone: owner.phone.clone(),
+        email: owner.email.clone(),
     }
 }
@@ -0,0 +1,235 @@
+use super::*;
+use crate::pages::gate_pass_scan::{
+    gate_pass_offline_key, gate_pass_sync_error, last_synced_at_details, refresh_sync_warnings,
+    sync_indexed_db_gate_passes,
+};
+use wasm_bindgen_futures::JsFuture;
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct StorageEstimate {
+    usage: f64,
+    quota: f64,
+}
+
+/// Status of the offline gate pass database with the resync and purge actions.
+#[component]
+pub fn GatePassOfflineDatabasePanel(
+    last_synced_at_details_signal: Signal<String>,
+    sync_warnings_signal: Signal<Vec<String>>,
+    offline_mode_signal: Signal<bool>,
+) -> Element {
+    let indexed_db_signal = use_state().indexed_db();
+    let mut processing_signal = use_signal(|| false);
+
+    let mut gate_pass_storage_stats_resource = use_resource(move || async move {
+        // reloads whenever the scanner syncs or imports gate passes
+        last_synced_at_details_signal.read();
+        let indexed_db_opt = indexed_db_signal.read();
+        let indexed_db_ref = indexed_db_opt.as_ref()?;
+        let offline_key = gate_pass_offline_key().await.ok()?;
+        let now = get_sync_entry(indexed_db_ref, SyncEntryId::GatePassSync)
+            .await
+            .map(|sync_entry| sync_entry.server_now())
+            .unwrap_or_else(|_| Utc::now());
+        gate_pass_storage_stats(indexed_db_ref, &offline_key, now)
+            .await
+            .ok()
+    });
+
+    let mut storage_estimate_resource = use_resource(move || async move {
+        JsFuture::from(jsFfiEstimateStorage())
+            .await
+            .ok()
+            .and_then(|value| serde_wasm_bindgen::from_value::<StorageEstimate>(value).ok())
+    });
+
+    let purge = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            processing_signal.set(true);
+            let cleared = match indexed_db_signal.read().as_ref() {
+                Some(indexed_db_ref) => clear_gate_passes(indexed_db_ref).await,
+                None => Err(Error::None),
+            };
+            match cleared {
+                Ok(_) => {
+                    last_synced_at_details_signal.set(last_synced_at_details(None).to_string());
+                    success_dialog!("message-success-gate-pass-offline-database-purge");
+                }
+                Err(error) => {
+                    error!("failed to purge offline gate passes: error={:?}", error);
+                    error_dialog!("error-gate-pass-offline-database-purge");
+                }
+            }
+            refresh_sync_warnings(
+                sync_warnings_signal,
+                indexed_db_signal,
+                offline_mode_signal(),
+            )
+            .await;
+            gate_pass_storage_stats_resource.restart();
+            storage_estimate_resource.restart();
+            processing_signal.set(false);
+        });
+    });
+
+    let resync = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            processing_signal.set(true);
+            let cleared = match indexed_db_signal.read().as_ref() {
+                Some(indexed_db_ref) => clear_gate_passes(indexed_db_ref).await,
+                None => Err(Error::None),
+            };
+            let synced = match cleared {
+                Ok(_) => {
+                    sync_indexed_db_gate_passes(last_synced_at_details_signal, indexed_db_signal)
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+            match synced {
+                Ok(_) => success_dialog!("message-success-gate-pass-offline-database-resync"),
+                Err(error) => error!("failed to resync offline gate passes: error={:?}", error),
+            }
+            refresh_sync_warnings(
+                sync_warnings_signal,
+                indexed_db_signal,
+                offline_mode_signal(),
+            )
+            .await;
+            gate_pass_storage_stats_resource.restart();
+            storage_estimate_resource.restart();
+            processing_signal.set(false);
+        });
+    });
+
+    let gate_pass_storage_stats_opt = gate_pass_storage_stats_resource().flatten();
+    let storage_estimate_opt = storage_estimate_resource().flatten();
+    let sync_error_opt = gate_pass_sync_error();
+
+    rsx! {
+        details {
+            class: "collapse collapse-arrow bg-base-200 rounded mt-4",
+            summary {
+                class: "collapse-title font-medium",
+                { t!("gate-pass-offline-database") }
+            }
+            div {
+                class: "collapse-content p-2",
+                table { class: "entry-table",
+                    tbody {
+                        tr {
+                            td { {t!("gate-pass-offline-database-field-gate-passes")} }
+                            td {
+                                {
+                                    gate_pass_storage_stats_opt
+                                        .as_ref()
+                                        .map(|stats| stats.number_of_gate_passes.to_string())
+                                        .unwrap_or_default()
+                                }
+                            }
+                        }
+                        tr {
+                            td { {t!("gate-pass-offline-database-field-expired")} }
+                            td {
+                                {
+                                    gate_pass_storage_stats_opt
+                                        .as_ref()
+                                        .map(|stats| stats.number_of_expired.to_string())
+                                        .unwrap_or_default()
+                                }
+                            }
+                        }
+                        tr {
+                            td { {t!("gate-pass-offline-database-field-blocked")} }
+                            td {
+                                {
+                                    gate_pass_storage_stats_opt
+                                        .as_ref()
+                                        .map(|stats| stats.number_of_blocked.to_string())
+                                        .unwrap_or_default()
+                                }
+                            }
+                        }
+                        if gate_pass_storage_stats_opt
+                            .as_ref()
+                            .is_some_and(|stats| stats.number_of_unreadable > 0)
+                        {
+                            tr {
+                                td { {t!("gate-pass-offline-database-field-unreadable")} }
+                                td {
+                                    class: "text-error",
+                                    {
+                                        gate_pass_storage_stats_opt
+                                            .as_ref()
+                                            .map(|stats| stats.number_of_unreadable.to_string())
+                                            .unwrap_or_default()
+                                    }
+                                }
+                            }
+                        }
+                        tr {
+                            td { {t!("gate-pass-offline-database-field-storage-usage")} }
+                            td {
+                                {
+                                    storage_estimate_opt
+                                        .as_ref()
+                                        .map(|estimate| {
+                                            format!(
+                                                "{} / {}",
+                                                human_bytes(estimate.usage),
+                                                human_bytes(estimate.quota),
+                                            )
+                                        })
+                                        .unwrap_or_default()
+                                }
+                            }
+                        }
+                        tr {
+                            td { {t!("gate-pass-offline-database-field-last-sync-error")} }
+                            td {
+                                class: if sync_error_opt.is_some() { "text-error" },
+                                {
+                                    sync_error_opt
+                                        .as_ref()
+                                        .map(|sync_error| {
+                                            format!(
+                                                "{} ({})",
+                                                sync_error.message,
+                                                sync_error
+                                                    .occurred_at
+                                                    .parse::<DateTime<Local>>()
+                                                    .map(|occurred_at| {
+                                                        occurred_at.format("%H:%M %d/%m/%Y").to_string()
+                                                    })
+                                                    .unwrap_or_default(),
+                                            )
+                                        })
+                                        .unwrap_or_else(|| t!("gate-pass-offline-database-no-sync-error"))
+                                }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "flex flex-wrap justify-center gap-2 mt-2",
+                    button {
+                        class: "btn btn-sm btn-primary",
+                        disabled: processing_signal(),
+                        onclick: move |_| {
+                            alert_dialog!("message-confirm-gate-pass-offline-database-resync", resync);
+                        },
+                        { t!("gate-pass-action-offline-database-resync") }
+                    }
+                    button {
+                        class: "btn btn-sm btn-error",
+                        disabled: processing_signal(),
+                        onclick: move |_| {
+                            alert_dialog!("message-confirm-gate-pass-offline-database-purge", purge);
+                        },
+                        { t!("gate-pass-action-offline-database-purge") }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use super::*;
+
+#[component]
+pub fn GatePassPolicyEdit() -> Element {
+    breadcrumbs!("menu-gate-pass-policies");
+    check_permission!(PERMISSION_GATE_PASS_POLICY);
+
+    let resource = value_future!(url!(API_GATE_PASSES, "policies"));
+    let policy_value = resource.suspend()?;
+    check_response!(policy_value, resource);
+    let policy = policy_value()
+        .self_obj::<GatePassPolicy>()
+        .unwrap_or_default();
+
+    rsx! {
+        GatePassPolicyEditorView { policy }
+    }
+}
+
+#[component]
+fn GatePassPolicyEditorView(policy: GatePassPolicy) -> Element {
+    let on_submit_gate_pass_policy = move |event: Event<FormData>| {
+        let title_rules = gate_pass_dictionary_items(GatePassDictionaryKind::OwnerTitle)
+            .into_iter()
+            .filter_map(|(code, _)| {
+                event
+                    .get_i64(&format!("title_rule_{}", code))
+                    .map(|max_validity_days| GatePassTitleValidityRule {
+                        title: GatePassOwnerTitle(code.into()),
+                        max_validity_days,
+                    })
+            })
+            .collect::<Vec<_>>();
+        let unit_rules = event
+            .get_str("unit_rules")
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let (unit, max_validity_days) = line.split_once('=')?;
+                Some(GatePassUnitValidityRule {
+                    unit: Cow::Owned(unit.trim().to_string()),
+                    max_validity_days: max_validity_days.trim().parse::<i64>().ok()?,
+                })
+            })
+            .collect::<Vec<_>>();
+        let vin_code_required_body_types =
+            gate_pass_dictionary_items(GatePassDictionaryKind::VehicleBodyType)
+                .into_iter()
+                .filter(|(code, _)| event.get_bool(&format!("vin_code_required_{}", code)))
+                .map(|(code, _)| VehicleBodyType(code.into()))
+                .collect::<Vec<_>>();
+        let payload = json!(GatePassPolicy {
+            max_validity_days: event.get_i64("max_validity_days"),
+            title_rules,
+            unit_rules,
+            vin_code_required_body_types,
+            forbid_unknown_number_plates: event.get_bool("forbid_unknown_number_plates"),
+        });
+
+        spawn(async move {
+            if patch_request!(url!(API_GATE_PASSES, "policies"), payload) {
+                success_dialog!("message-success-post");
+            }
+        });
+    };
+
+    let unit_rules = policy
+        .unit_rules
+        .iter()
+        .map(|rule| format!("{} = {}", rule.unit, rule.max_validity_days))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    rsx! {
+        form {
+            class: "flex grow flex-col items-center gap-3",
+            id: "gate-pass-policy-edit-form",
+            autocomplete: "off",
+            onsubmit: on_submit_gate_pass_policy,
+            FormNumField {
+                name: "max_validity_days",
+                title: "gate-pass-policy-field-max-validity-days",
+                min: "1",
+                initial_value: policy.max_validity_days.map(|days| days.to_string()),
+            }
+            p { { t!("gate-pass-policy-title-rules-description") } }
+            for (code, name) in gate_pass_dictionary_items(GatePassDictionaryKind::OwnerTitle) {
+                FormNumField {
+                    name: format!("title_rule_{}", code),
+                    title: name,
+                    min: "1",
+                    initial_value: policy
+                        .title_rules
+                        .iter()
+                        .find(|rule| rule.title.code() == code)
+                        .map(|rule| rule.max_validity_days.to_string()),
+                }
+            }
+            FormTextAreaField {
+                name: "unit_rules",
+                title: "gate-pass-policy-field-unit-rules",
+                initial_value: unit_rules,
+            }
+            p { { t!("gate-pass-policy-vin-code-required-description") } }
+            for (code, name) in gate_pass_dictionary_items(GatePassDictionaryKind::VehicleBodyType) {
+                FormCheckBoxField {
+                    name: format!("vin_code_required_{}", code),
+                    title: name,
+                    initial_checked: policy
+                        .vin_code_required_body_types
+                        .iter()
+                        .any(|body_type| body_type.code() == code),
+                }
+            }
+            FormCheckBoxField {
+                name: "forbid_unknown_number_plates",
+                title: "gate-pass-policy-field-forbid-unknown-number-plates",
+                initial_checked: policy.forbid_unknown_number_plates,
+            }
+        }
+        EditorActions {
+            form: "gate-pass-policy-edit-form",
+            permission: PERMISSION_GATE_PASS_POLICY,
+        }
+    }
+}
@@ -22,6 +22,15 @@ pub fn GatePassPrintDialogView(
     gate_pass_selected_ids_signal: Signal<HashSet<String>>,
     gate_pass_print_dialog_visible_signal: Signal<bool>,
 ) -> Element {
+    let gate_pass_print_id_signal = use_signal(String::new);
+    let confirm_gate_pass_print = Callback::new(move |_: MouseEvent| {
+        spawn(async move {
+            let print_id = gate_pass_print_id_signal();
+            if post_request!(url!(API_GATE_PASSES, "prints", &print_id, "confirmations")) {
+                success_dialog!("message-success-gate-pass-print-confirmed");
+            }
+        });
+    });
     if !gate_pass_print_dialog_visible_signal() {
         return rsx! {};
     };
@@ -44,15 +53,21 @@ pub fn GatePassPrintDialogView(
             "ids": ids,
             "number_plates": number_plates,
             "two_side_print_mode": event.get_str("two_side_print_mode"),
+            "never_printed": event.get_bool("never_printed"),
         });
 
         loading_spinner_hidden_signal.set(false);
         spawn(async move {
             match generate_print_gate_pass_html(payload).await {
-                Ok(html) => {
+                Ok((html, print_id)) => {
                     jsFfiOpenHtml(html.as_str());
                     loading_spinner_hidden_signal.set(true);
                     gate_pass_print_dialog_visible_signal.set(false);
+                    // the passes are counted as printed only when the user confirms it
+                    if let Some(print_id) = print_id {
+                        gate_pass_print_id_signal.set(print_id);
+                        alert_dialog!("message-confirm-gate-pass-printed", confirm_gate_pass_print);
+                    }
                 }
                 Err(error) => {
                     loading_spinner_hidden_signal.set(true);
@@ -96,6 +111,10 @@ pub fn GatePassPrintDialogView(
                         selected: two_side_print_mode_name(&TwoSidePrintMode::default()),
                         items: two_side_print_modes,
                     }
+                    FormCheckBoxField {
+                        name: "never_printed",
+                        title: "gate-pass-field-never-printed-only",
+                    }
                     div { style: "justify-content:center", class: "flex gap-3",
                         button { class: "btn btn-primary",
                             span {
@@ -111,17 +130,28 @@ pub fn GatePassPrintDialogView(
     }
 }
 
-async fn generate_print_gate_pass_html(print_gate_pass_requests: Value) -> Result<String, Error> {
-    let bytes = state!(client)
+/// Returns the print HTML with the id of the print to confirm, `None` when nothing is printed.
+async fn generate_print_gate_pass_html(
+    print_gate_pass_requests: Value,
+) -> Result<(String, Option<String>), Error> {
+    let response = state!(client)
         .post(url!(API_GATE_PASSES, "prints"))
         .json(&print_gate_pass_requests)
         .send()
         .await
-        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?
+        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?;
+    let print_id = response
+        .headers()
+        .get(GATE_PASS_PRINT_ID_HEADER)
+        .and_then(|print_id| print_id.to_str().ok())
+        .map(str::to_string);
+    let bytes = response
         .bytes()
         .await
         .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?;
-    String::from_utf8(bytes.to_vec()).map_err(|error| Error::Generic(Cow::Owned(error.to_string())))
+    let html = String::from_utf8(bytes.to_vec())
+        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?;
+    Ok((html, print_id))
 }
 
 pub fn two_side_print_mode_name(mode: &TwoSidePrintMode) -> String {
@@ -0,0 +1,77 @@
+use super::*;
+use crate::pages::gate_pass_print::two_side_print_mode_name;
+
+#[component]
+pub fn GatePassPrints() -> Element {
+    breadcrumbs!("menu-gate-pass-prints");
+    check_permission!(PERMISSION_GATE_PASS_READ);
+
+    // pagination
+    let page_index_signal = use_signal(|| 0);
+    let page_size_signal = use_signal(|| 40);
+    let mut number_of_pages_signal = use_signal(|| 0);
+
+    let gate_pass_prints_resource = use_resource(move || async move {
+        let request = SearchGatePassPrintRequest {
+            gate_pass_id: None,
+            page_request: Some(PageRequest::new(
+                page_size_signal(),
+                page_index_signal(),
+                vec![],
+            )),
+        };
+        let page = state!(client)
+            .post(url!(API_GATE_PASSES, "prints", "searches"))
+            .json(&request)
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<PageResponse<GatePassPrint>>()
+            .unwrap_or_default();
+        number_of_pages_signal.set(page.number_of_pages);
+        page.page_rows
+    });
+
+    rsx! {
+        section { class: "w-full grow xl:pr-16",
+            PaginationBar{
+                page_size_signal, page_index_signal, number_of_pages_signal
+            }
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("gate-pass-print-field-created-at")} }
+                        th { class: "text-wrap", {t!("gate-pass-print-field-created-by")} }
+                        th { class: "text-wrap", {t!("two-side-print-mode")} }
+                        th { class: "text-wrap", {t!("gate-pass-print-field-number-of-gate-passes")} }
+                        th { class: "text-wrap", {t!("gate-pass-print-field-confirmed-by")} }
+                    }
+                }
+                tbody {
+                    for gate_pass_print in gate_pass_prints_resource().unwrap_or_default() {
+                        tr {
+                            td {
+                                {
+                                    gate_pass_print
+                                        .created_at
+                                        .parse::<DateTime<Local>>()
+                                        .unwrap_or_default()
+                                        .format("%H:%M %d/%m/%Y")
+                                        .to_string()
+                                }
+                            }
+                            td { {gate_pass_print.created_by.as_ref()} }
+                            td { {two_side_print_mode_name(&gate_pass_print.two_side_print_mode)} }
+                            td { {gate_pass_print.number_of_gate_passes.to_string()} }
+                            td { {gate_pass_print.confirmed_by.as_deref().unwrap_or("-")} }
+                        }
+                    }
+                }
+            }
+            PaginationBar{
+                page_size_signal, page_index_signal, number_of_pages_signal
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use super::*;
+use wasm_bindgen_futures::JsFuture;
+
+#[component]
+pub fn GatePassReportButton(gate_pass_report_dialog_visible_signal: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: "hover:btn-neutral join-item",
+            onclick: move |event| {
+                event.prevent_default();
+                event.stop_propagation();
+
+                gate_pass_report_dialog_visible_signal.set(true);
+            },
+            Icon { icon: Icons::Description, class: "size-8" }
+            span { class: "opacity-0 group-hover:opacity-100", {t!("gate-pass-action-report")} }
+        }
+    }
+}
+
+#[component]
+pub fn GatePassReportDialogView(
+    gate_pass_selected_ids_signal: Signal<HashSet<String>>,
+    gate_pass_report_dialog_visible_signal: Signal<bool>,
+) -> Element {
+    let mut kind_signal = use_signal(GatePassReportKind::default);
+    let mut loading_spinner_hidden_signal = use_signal(|| true);
+    if !gate_pass_report_dialog_visible_signal() {
+        return rsx! {};
+    };
+    let report_kinds = GatePassReportKind::values()
+        .into_iter()
+        .map(|kind| (format!("{:?}", kind), gate_pass_report_kind_name(&kind)))
+        .collect::<Vec<(String, String)>>();
+    let report_formats = [GatePassReportFormat::Xlsx, GatePassReportFormat::Pdf]
+        .into_iter()
+        .map(|format| (format!("{:?}", format), format.extension().to_uppercase()))
+        .collect::<Vec<(String, String)>>();
+    let selected_gate_pass_report = !gate_pass_selected_ids_signal.read().is_empty();
+    let on_submit_gate_pass_report = move |event: Event<FormData>| {
+        event.prevent_default();
+        event.stop_propagation();
+
+        let list = |name: &str| {
+            event
+                .get_str(name)
+                .filter(|string| !string.is_empty())
+                .map(|string| {
+                    split(string.as_ref(), ",")
+                        .into_iter()
+                        .map(Cow::from)
+                        .collect::<Vec<_>>()
+                })
+        };
+        let ids = gate_pass_selected_ids_signal()
+            .into_iter()
+            .map(Cow::from)
+            .collect::<Vec<_>>();
+        let format = event
+            .get_str("format")
+            .and_then(|format| serde_json::from_value(json!(format)).ok())
+            .unwrap_or_default();
+        let request = GatePassReportRequest {
+            kind: kind_signal(),
+            format,
+            filter: Some(SearchGatePassRequest {
+                ids: Some(ids).filter(|ids| !ids.is_empty()),
+                last_names: list("last_names"),
+                number_plates: list("number_plates"),
+                units: list("units"),
+                never_printed: Some(event.get_bool("never_printed"))
+                    .filter(|never_printed| *never_printed),
+                page_request: None,
+            }),
+            expiring_within_days: event.get_i64("expiring_within_days"),
+            i18n: GATE_PASS_REPORT_I18N_KEYS
+                .iter()
+                .map(|key| (Cow::Borrowed(*key), Cow::Owned(t!(*key))))
+                .collect(),
+        };
+
+        loading_spinner_hidden_signal.set(false);
+        spawn(async move {
+            let content = state!(client)
+                .post(url!(API_GATE_PASSES, "reports"))
+                .json(&request)
+                .send()
+                .await
+                .get_bytes()
+                .await;
+            loading_spinner_hidden_signal.set(true);
+            let Some(content) = content else {
+                return;
+            };
+            let file_name = format!(
+                "gate-pass-report-{}.{}",
+                Local::now().format("%Y%m%d-%H%M"),
+                request.format.extension()
+            );
+            if JsFuture::from(jsFfiExportBinaryFile(
+                &content,
+                request.format.content_type(),
+                request.format.extension(),
+                &file_name,
+            ))
+            .await
+            .is_err()
+            {
+                error!("failed to invoke jsFfiExportBinaryFile");
+            }
+            gate_pass_report_dialog_visible_signal.set(false);
+        });
+    };
+
+    rsx! {
+        section { class: "modal modal-open",
+            div { class: "modal-box",
+                div { class: "absolute top-0 right-0 join rounded-none",
+                    button {
+                        class: "btn btn-sm btn-ghost join-item hover:text-error",
+                        onclick: move |_| {
+                            gate_pass_report_dialog_visible_signal.set(false);
+                        },
+                        Icon { icon: Icons::Close, class: "size-4" }
+                    }
+                }
+
+                form {
+                    class: "flex grow flex-col items-center gap-3",
+                    id: "gate-pass-report-form",
+                    autocomplete: "off",
+                    onsubmit: on_submit_gate_pass_report,
+                    label {
+                        class: "w-full floating-label mt-4",
+                        span {
+                            "⌘ " { t!("gate-pass-field-report-kind") }
+                        }
+                        select {
+                            class: "select",
+                            required: true,
+                            onchange: move |event| {
+                                if let Ok(kind) = serde_json::from_value(json!(event.value())) {
+                                    kind_signal.set(kind);
+                                }
+                            },
+                            for (kind, kind_name) in report_kinds {
+                                option {
+                                    initial_selected: format!("{:?}", kind_signal()).eq(&kind),
+                                    value: kind,
+                                    { kind_name }
+                                }
+                            }
+                        }
+                    }
+                    FormSimpleSelectField {
+                        name: "format",
+                        title: "gate-pass-field-report-format",
+                        required: true,
+                        selected: format!("{:?}", GatePassReportFormat::default()),
+                        items: report_formats,
+                    }
+                    if kind_signal() == GatePassReportKind::Expiring {
+                        FormNumField {
+                            name: "expiring_within_days",
+                            title: "gate-pass-field-report-expiring-within-days",
+                            required: true,
+                            initial_value: GATE_PASS_REPORT_EXPIRING_DAYS.to_string(),
+                            min: "1",
+                            max: "366",
+                        }
+                    }
+                    if selected_gate_pass_report {
+                        p { {format!("{} {}", t!("gate-pass-selected-report-description"), gate_pass_selected_ids_signal.read().len())} }
+                    } else {
+                        p { {t!("gate-pass-report-description")} }
+                        FormTextAreaField {
+                            name: "units",
+                            title: "gate-pass-field-bulk-units",
+                        }
+                        FormTextAreaField {
+                            name: "last_names",
+                            title: "gate-pass-field-owner-last-name",
+                        }
+                        FormTextAreaField {
+                            name: "number_plates",
+                            title: "gate-pass-field-vehicle-number-plates",
+                        }
+                        FormCheckBoxField {
+                            name: "never_printed",
+                            title: "gate-pass-field-report-never-printed-only",
+                        }
+                    }
+                    div { style: "justify-content:center", class: "flex gap-3",
+                        button { class: "btn btn-primary",
+                            span {
+                                class: "loading loading-spinner",
+                                hidden: loading_spinner_hidden_signal(),
+                            }
+                            {t!("gate-pass-action-report-generate")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn gate_pass_report_kind_name(kind: &GatePassReportKind) -> String {
+    let key = format!("gate-pass-report-kind-{:?}", kind).to_lowercase();
+    t!(key.as_str())
+}
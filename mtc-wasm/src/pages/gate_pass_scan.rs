@@ -1,13 +1,23 @@
 use super::*;
+use base64::{Engine as _, engine::general_purpose};
+use dioxus::html::FileData;
+use ed25519_dalek::{Signature, VerifyingKey};
 use indexed_db::Database;
+use reqwest::StatusCode;
 use serde_wasm_bindgen::from_value;
 use std::collections::HashMap;
 use wasm_bindgen_futures::JsFuture;
 
-static MTC_QR_CODE_SUFFIX: &str = "MTC:GATE-PASS:";
 static MTC_OFFLINE_MODE_KEY: &str = "mtc_offline_mode_key";
 static MTC_CAMERA_SETTINGS_KEY: &str = "mtc_camera_settings_key";
+static MTC_CONTINUOUS_SCAN_MODE_KEY: &str = "mtc_continuous_scan_mode_key";
+static MTC_SCAN_HISTORY_KEY: &str = "mtc_gate_pass_scan_history";
+static MTC_GATE_PASS_SYNC_ERROR_KEY: &str = "mtc_gate_pass_sync_error";
+static GATE_PASS_SCAN_HISTORY_SIZE: usize = 20;
+static GATE_PASS_CONTINUOUS_SCAN_VALID_PAUSE_MS: u32 = 2000;
+static GATE_PASS_CONTINUOUS_SCAN_INVALID_PAUSE_MS: u32 = 4000;
 static ROUT_GATE_PASS_VALIDATION_SCANS: &str = "gate-pass-validation-scans";
+static GATE_PASS_SNAPSHOT_IMPORT_ELEMENT_ID: &str = "gate-pass-snapshot-import";
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 struct CameraSettings {
@@ -39,6 +49,22 @@ impl CameraSettings {
     }
 }
 
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GatePassSyncError {
+    pub message: String,
+    pub occurred_at: String,
+}
+
+/// Scan kept in the session history, so the guard can re-check a car.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct GatePassScanHistoryEntry {
+    qr_code: String,
+    owner_name: Option<String>,
+    number_plate: Option<String>,
+    validation_result: GatePassValidationResult,
+    scanned_at: String,
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 struct Camera {
     label: String,
@@ -53,8 +79,24 @@ pub fn GatePassScanView() -> Element {
 
     let indexed_db_signal = use_state().indexed_db();
     let mut last_synced_at_details_signal = use_signal(|| String::new());
+    let sync_warnings_signal = use_signal(Vec::<String>::new);
     let mut loading_spinner_hidden_signal = use_signal(|| true);
 
+    let mut offline_mode_ls = use_local_storage(MTC_OFFLINE_MODE_KEY, || Value::Bool(false));
+    let mut offline_mode_signal =
+        use_signal(|| offline_mode_ls.get().self_bool().unwrap_or_default());
+    let switch_offline_mode = move |event: Event<MouseData>| async move {
+        let updated_offline_mode = !offline_mode_ls.get().self_bool().unwrap_or_default();
+        offline_mode_ls.set(Value::Bool(updated_offline_mode));
+        offline_mode_signal.set(updated_offline_mode);
+        refresh_sync_warnings(
+            sync_warnings_signal,
+            indexed_db_signal,
+            updated_offline_mode,
+        )
+        .await;
+    };
+
     let sync_gate_passes = move |event: Event<MouseData>| async move {
         loading_spinner_hidden_signal.set(false);
         if let Err(error) =
@@ -62,16 +104,61 @@ pub fn GatePassScanView() -> Element {
         {
             error!("failed to sync gate passes: error={:?}", error);
         };
+        refresh_sync_warnings(
+            sync_warnings_signal,
+            indexed_db_signal,
+            offline_mode_signal(),
+        )
+        .await;
         loading_spinner_hidden_signal.set(true);
     };
 
-    let mut offline_mode_ls = use_local_storage(MTC_OFFLINE_MODE_KEY, || Value::Bool(false));
-    let mut offline_mode_signal =
-        use_signal(|| offline_mode_ls.get().self_bool().unwrap_or_default());
-    let switch_offline_mode = move |event: Event<MouseData>| {
-        let updated_offline_mode = !offline_mode_ls.get().self_bool().unwrap_or_default();
-        offline_mode_ls.set(Value::Bool(updated_offline_mode));
-        offline_mode_signal.set(updated_offline_mode);
+    let import_gate_pass_snapshot = move |event: Event<FormData>| async move {
+        event.prevent_default();
+        event.stop_propagation();
+        loading_spinner_hidden_signal.set(false);
+        match import_indexed_db_gate_pass_snapshot(
+            event.files(),
+            last_synced_at_details_signal,
+            indexed_db_signal,
+        )
+        .await
+        {
+            Ok(snapshot) => {
+                let age = snapshot.age().unwrap_or_default();
+                let message = t!("gate-pass-message-snapshot-imported")
+                    .replace("{number}", &snapshot.gate_passes.len().to_string())
+                    .replace("{days}", &age.num_days().to_string())
+                    .replace("{hours}", &(age.num_hours() % 24).to_string());
+                success_dialog!(message.as_str());
+            }
+            Err(error) => {
+                error!("failed to import gate pass snapshot: error={:?}", error);
+                let error = format!("{}: {}", t!("error-import"), error.message());
+                error_dialog!(error.as_str());
+            }
+        };
+        refresh_sync_warnings(
+            sync_warnings_signal,
+            indexed_db_signal,
+            offline_mode_signal(),
+        )
+        .await;
+        loading_spinner_hidden_signal.set(true);
+    };
+
+    let scan_history_resource = use_resource(move || async move { scan_history().await });
+    let scan_history = scan_history_resource().unwrap_or_default();
+
+    let mut continuous_scan_mode_ls =
+        use_local_storage(MTC_CONTINUOUS_SCAN_MODE_KEY, || Value::Bool(false));
+    let mut continuous_scan_mode_signal =
+        use_signal(|| continuous_scan_mode_ls.get().self_bool().unwrap_or_default());
+    let switch_continuous_scan_mode = move |event: Event<MouseData>| {
+        let updated_continuous_scan_mode =
+            !continuous_scan_mode_ls.get().self_bool().unwrap_or_default();
+        continuous_scan_mode_ls.set(Value::Bool(updated_continuous_scan_mode));
+        continuous_scan_mode_signal.set(updated_continuous_scan_mode);
     };
 
     let switch_camera = move |event: Event<MouseData>| async move {
@@ -103,6 +190,9 @@ pub fn GatePassScanView() -> Element {
 
     use_effect(move || {
         spawn(async move {
+            if let Err(error) = gate_pass_offline_key().await {
+                error!("failed to get gate pass offline key: error={:?}", error);
+            }
             let indexed_db_opt = indexed_db_signal.read();
             let indexed_db_ref = indexed_db_opt.as_ref().unwrap();
             let last_synced_at_opt = get_sync_entry(indexed_db_ref, SyncEntryId::GatePassSync)
@@ -111,6 +201,12 @@ pub fn GatePassScanView() -> Element {
                 .map(|sync_entry| sync_entry.last_synced_at);
             last_synced_at_details_signal
                 .set(last_synced_at_details(last_synced_at_opt).to_string());
+            refresh_sync_warnings(
+                sync_warnings_signal,
+                indexed_db_signal,
+                offline_mode_signal(),
+            )
+            .await;
 
             let camera_settings_ls = use_camera_settings_local_storage().await;
             let camera_settings_opt =
@@ -133,6 +229,13 @@ pub fn GatePassScanView() -> Element {
             style: "display: block; text-align: center;",
             {last_synced_at_details_signal}
         }
+        for sync_warning in sync_warnings_signal() {
+            span {
+                class: "text-error",
+                style: "display: block; text-align: center;",
+                {sync_warning}
+            }
+        }
         div {
             id: qr_code_scanner_element_id,
             style: "max-width: 250px; max-heigh: 250px; margin-left: auto; margin-right: auto;",
@@ -151,6 +254,26 @@ pub fn GatePassScanView() -> Element {
                     }
                 }
             }
+            if snapshot_verifying_key().is_some() {
+                input {
+                    class: "hidden",
+                    id: GATE_PASS_SNAPSHOT_IMPORT_ELEMENT_ID,
+                    r#type: "file",
+                    accept: ".json",
+                    multiple: false,
+                    onchange: import_gate_pass_snapshot,
+                }
+                button {
+                    class: "btn btn-xl btn-ghost",
+                    title: t!("gate-pass-action-import-snapshot"),
+                    onclick: |event| {
+                        event.prevent_default();
+                        event.stop_propagation();
+                        jsFfiClickElement(GATE_PASS_SNAPSHOT_IMPORT_ELEMENT_ID);
+                    },
+                    Icon { icon: Icons::Upload, class: "size-8" }
+                }
+            }
             button {
                 class: "btn btn-xl btn-ghost",
                 title: t!("gate-pass-action-offline-mode"),
@@ -161,6 +284,16 @@ pub fn GatePassScanView() -> Element {
                     Icon { icon: Icons::Online, class: "size-8" }
                 }
             }
+            button {
+                class: "btn btn-xl btn-ghost",
+                title: t!("gate-pass-action-continuous-scan-mode"),
+                onclick: switch_continuous_scan_mode,
+                if continuous_scan_mode_signal() {
+                    Icon { icon: Icons::QrScan, class: "size-8" }
+                } else {
+                    Icon { icon: Icons::QrCode, class: "size-8" }
+                }
+            }
             button {
                 class: "btn btn-xl btn-ghost",
                 title: t!("gate-pass-action-switch-camera"),
@@ -179,6 +312,56 @@ pub fn GatePassScanView() -> Element {
                 }
             }
         }
+        if !scan_history.is_empty() {
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("gate-pass-scan-history-field-scanned-at")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-vehicle-number-plate")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-owner-full-name")} }
+                        th { class: "text-wrap", {t!("gate-pass-scan-history-field-result")} }
+                    }
+                }
+                tbody {
+                    for scan_history_entry in scan_history {
+                        tr {
+                            class: "cursor-pointer",
+                            onclick: move |_| {
+                                navigator().push(route!(
+                                    ROUT_GATE_PASS_VALIDATION_SCANS,
+                                    "results",
+                                    scan_history_entry.qr_code.as_str()
+                                ));
+                            },
+                            td {
+                                {
+                                    scan_history_entry
+                                        .scanned_at
+                                        .parse::<DateTime<Local>>()
+                                        .map(|scanned_at| scanned_at.format("%H:%M:%S").to_string())
+                                        .unwrap_or_default()
+                                }
+                            }
+                            td { {scan_history_entry.number_plate.clone().unwrap_or_default()} }
+                            td { {scan_history_entry.owner_name.clone().unwrap_or_default()} }
+                            td {
+                                class: if scan_history_entry.validation_result == GatePassValidationResult::Valid {
+                                    "text-success"
+                                } else {
+                                    "text-error"
+                                },
+                                {validation_result_details(&scan_history_entry.validation_result)}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        GatePassOfflineDatabasePanel {
+            last_synced_at_details_signal,
+            sync_warnings_signal,
+            offline_mode_signal,
+        }
     }
 }
 
@@ -228,47 +411,74 @@ async fn init_camera_settings() -> Option<CameraSettings> {
     }
 }
 
-async fn sync_indexed_db_gate_passes(
+/// Syncs the offline gate passes and keeps the error of a failed sync for the
+/// offline database panel.
+pub async fn sync_indexed_db_gate_passes(
+    last_synced_at_details_signal: Signal<String>,
+    indexed_db_signal: Signal<Option<Database<Error>>>,
+) -> Result<(), Error> {
+    let result =
+        sync_indexed_db_gate_pass_batches(last_synced_at_details_signal, indexed_db_signal).await;
+    match &result {
+        Ok(_) => LocalStorage::delete(MTC_GATE_PASS_SYNC_ERROR_KEY),
+        Err(error) => {
+            let sync_error = GatePassSyncError {
+                message: error.message().to_string(),
+                occurred_at: Local::now().to_rfc3339(),
+            };
+            if let Err(error) = LocalStorage::set(MTC_GATE_PASS_SYNC_ERROR_KEY, sync_error) {
+                error!("failed to store gate pass sync error: {error:?}");
+            }
+        }
+    }
+    result
+}
+
+pub fn gate_pass_sync_error() -> Option<GatePassSyncError> {
+    LocalStorage::get::<GatePassSyncError>(MTC_GATE_PASS_SYNC_ERROR_KEY).ok()
+}
+
+async fn sync_indexed_db_gate_pass_batches(
     mut last_synced_at_details_signal: Signal<String>,
     indexed_db_signal: Signal<Option<Database<Error>>>,
 ) -> Result<(), Error> {
     let database_opt = indexed_db_signal.read();
     let database_ref = database_opt.as_ref().unwrap();
-    let now = Utc::now();
-    let now_before = |date_time: Option<Cow<'static, str>>| {
-        date_time.is_none_or(|date_time| {
-            date_time
-                .parse::<DateTime<Utc>>()
-                .ok()
-                .map(|date_time| date_time <= now)
-                .unwrap_or(true)
-        })
-    };
 
+    let offline_key = gate_pass_offline_key().await?;
     let mut last_synced_at_opt = get_sync_entry(database_ref, SyncEntryId::GatePassSync)
         .await
         .ok()
         .map(|sync_entry| sync_entry.last_synced_at);
-    while now_before(last_synced_at_opt.clone()) {
+    loop {
         let request = SyncGatePassRequest {
             last_synced_at: last_synced_at_opt.clone(),
         };
 
-        match state!(client)
+        let response = state!(client)
             .post(url!(API_GATE_PASSES, "syncs"))
             .json(&request)
             .send()
-            .await
-            .get_value()
-            .await
-            .self_obj::<SyncGatePassResponse>()
+            .await;
+        if response
+            .as_ref()
+            .is_ok_and(|response| response.status() == StatusCode::GONE)
         {
+            clear_gate_passes(database_ref).await?;
+            last_synced_at_details_signal.set(last_synced_at_details(None).to_string());
+        }
+        match response.get_value().await.self_obj::<SyncGatePassResponse>() {
             Some(sync_response) => {
-                process_gate_passes(database_ref, sync_response.gate_passes).await?;
+                process_gate_passes(database_ref, &offline_key, sync_response.gate_passes).await?;
 
+                let server_time_opt = sync_response.server_time.parse::<DateTime<Utc>>().ok();
                 let sync_entry = SyncEntry {
                     id: SyncEntryId::GatePassSync.name(),
                     last_synced_at: sync_response.last_synced_at.clone(),
+                    synced_at: Some(sync_response.server_time.clone()),
+                    clock_offset_seconds: server_time_opt
+                        .map(|server_time| (server_time - Utc::now()).num_seconds())
+                        .unwrap_or_default(),
                 };
                 put_sync_entry(database_ref, sync_entry).await?;
 
@@ -276,17 +486,188 @@ async fn sync_indexed_db_gate_passes(
 
                 last_synced_at_details_signal
                     .set(last_synced_at_details(last_synced_at_opt.clone()).to_string());
+
+                // a response covers a limited period, sync until the server time is
+                // reached, the device clock is not trusted here
+                let synced = sync_response
+                    .last_synced_at
+                    .parse::<DateTime<Utc>>()
+                    .ok()
+                    .zip(server_time_opt)
+                    .is_none_or(|(last_synced_at, server_time)| last_synced_at >= server_time);
+                if synced {
+                    break;
+                }
             }
             None => {
                 error!("failed to post sync request");
-                break;
+                return Err(Error::Response(Cow::Borrowed("error-gate-pass-sync")));
             }
         }
     }
     Ok(())
 }
 
-fn last_synced_at_details(last_synced_at_opt: Option<Cow<'static, str>>) -> Cow<'static, str> {
+async fn import_indexed_db_gate_pass_snapshot(
+    files: Vec<FileData>,
+    mut last_synced_at_details_signal: Signal<String>,
+    indexed_db_signal: Signal<Option<Database<Error>>>,
+) -> Result<GatePassSnapshot, Error> {
+    let Some(file) = files.into_iter().next() else {
+        return Err(Error::Generic(Cow::Borrowed("error-gate-pass-snapshot-invalid")));
+    };
+    let bytes = file
+        .read_bytes()
+        .await
+        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?;
+    let offline_key = gate_pass_offline_key().await?;
+    let snapshot = read_gate_pass_snapshot(&bytes, &offline_key)?;
+
+    let database_opt = indexed_db_signal.read();
+    let database_ref = database_opt.as_ref().unwrap();
+    let sync_entry_opt = get_sync_entry(database_ref, SyncEntryId::GatePassSync)
+        .await
+        .ok();
+    let last_synced_at_opt = sync_entry_opt
+        .as_ref()
+        .map(|sync_entry| sync_entry.last_synced_at.clone());
+    if !snapshot.applicable(last_synced_at_opt.as_deref()) {
+        return Err(Error::Generic(Cow::Borrowed(
+            "error-gate-pass-snapshot-not-applicable",
+        )));
+    }
+
+    process_gate_passes(database_ref, &offline_key, snapshot.gate_passes.clone()).await?;
+    // a snapshot carries no server time, the clock offset of the last sync is kept
+    let sync_entry = SyncEntry {
+        id: SyncEntryId::GatePassSync.name(),
+        last_synced_at: snapshot.last_synced_at.clone(),
+        synced_at: Some(snapshot.created_at.clone()),
+        clock_offset_seconds: sync_entry_opt
+            .map(|sync_entry| sync_entry.clock_offset_seconds)
+            .unwrap_or_default(),
+    };
+    put_sync_entry(database_ref, sync_entry).await?;
+    last_synced_at_details_signal
+        .set(last_synced_at_details(Some(snapshot.last_synced_at.clone())).to_string());
+    Ok(snapshot)
+}
+
+/// Returns the snapshot verifying key the application is built with, the snapshot
+/// import is disabled without it.
+fn snapshot_verifying_key() -> Option<&'static str> {
+    option_env!("SNAPSHOT_VERIFYING_KEY").filter(|verifying_key| !verifying_key.is_empty())
+}
+
+/// Verifies the snapshot file signature with the verifying key the application
+/// is built with and only then decrypts the snapshot with the key derived from
+/// the offline key of the device, a snapshot exported for another device fails.
+fn read_gate_pass_snapshot(
+    bytes: &[u8],
+    offline_key: &GatePassOfflineKey,
+) -> Result<GatePassSnapshot, Error> {
+    let Some(verifying_key) = snapshot_verifying_key() else {
+        return Err(Error::Generic(Cow::Borrowed(
+            "error-gate-pass-snapshot-import-disabled",
+        )));
+    };
+    let invalid_snapshot = || Error::Generic(Cow::Borrowed("error-gate-pass-snapshot-invalid"));
+    let snapshot_file = serde_json::from_slice::<GatePassSnapshotFile>(bytes)
+        .ok()
+        .filter(|snapshot_file| snapshot_file.version == GATE_PASS_SNAPSHOT_VERSION)
+        .ok_or_else(invalid_snapshot)?;
+    let verifying_key = general_purpose::STANDARD
+        .decode(verifying_key)
+        .ok()
+        .and_then(|key_bytes| <[u8; 32]>::try_from(key_bytes).ok())
+        .and_then(|key_bytes| VerifyingKey::from_bytes(&key_bytes).ok())
+        .ok_or_else(invalid_snapshot)?;
+    let signature = general_purpose::STANDARD
+        .decode(snapshot_file.signature.as_bytes())
+        .ok()
+        .and_then(|signature_bytes| Signature::from_slice(&signature_bytes).ok())
+        .ok_or_else(invalid_snapshot)?;
+    verifying_key
+        .verify_strict(snapshot_file.payload.as_bytes(), &signature)
+        .map_err(|_| Error::Generic(Cow::Borrowed("error-gate-pass-snapshot-signature")))?;
+
+    let mcrypt = new_magic_crypt!(gate_pass_snapshot_key(&offline_key.key), 256);
+    let payload = mcrypt
+        .decrypt_base64_to_string(snapshot_file.payload.as_ref())
+        .map_err(|_| Error::Generic(Cow::Borrowed("error-gate-pass-snapshot-other-device")))?;
+    serde_json::from_str::<GatePassSnapshot>(&payload).map_err(|_| invalid_snapshot())
+}
+
+/// Returns the offline key persisted on the device, requesting it from the server when
+/// the device has none yet. The gate passes stored with another key can not be read, so
+/// they are wiped together with the sync cursor before the new key is persisted.
+pub async fn gate_pass_offline_key() -> Result<GatePassOfflineKey, Error> {
+    if let Some(offline_key) = stored_gate_pass_offline_key().await {
+        return Ok(offline_key);
+    }
+    let response = state!(client)
+        .get(url!(API_GATE_PASSES, "offline-keys"))
+        .send()
+        .await?;
+    if response.status() == StatusCode::GONE {
+        return Err(wipe_revoked_device().await);
+    }
+    let offline_key = response
+        .error_for_status()?
+        .json::<GatePassOfflineKey>()
+        .await?;
+    clear_gate_passes(&indexed_db().await?).await?;
+    store_gate_pass_offline_key(&offline_key).await?;
+    Ok(offline_key)
+}
+
+pub async fn refresh_sync_warnings(
+    mut sync_warnings_signal: Signal<Vec<String>>,
+    indexed_db_signal: Signal<Option<Database<Error>>>,
+    offline_mode: bool,
+) {
+    let sync_entry_opt = {
+        let indexed_db_opt = indexed_db_signal.read();
+        let Some(indexed_db_ref) = indexed_db_opt.as_ref() else {
+            return;
+        };
+        get_sync_entry(indexed_db_ref, SyncEntryId::GatePassSync)
+            .await
+            .ok()
+    };
+    sync_warnings_signal.set(sync_warnings(sync_entry_opt.as_ref(), offline_mode));
+}
+
+/// Warnings shown to the guard when the device clock drifts from the server clock
+/// or the offline gate passes are outdated.
+fn sync_warnings(sync_entry_opt: Option<&SyncEntry>, offline_mode: bool) -> Vec<String> {
+    let mut warnings = vec![];
+    if sync_entry_opt.is_some_and(|sync_entry| sync_entry.clock_skewed()) {
+        warnings.push(t!("gate-pass-message-clock-skew"));
+    }
+    if offline_mode && sync_entry_opt.is_none_or(|sync_entry| sync_entry.outdated()) {
+        warnings.push(
+            t!("gate-pass-message-sync-outdated")
+                .replace("{hours}", &GATE_PASS_SYNC_MAX_AGE_HOURS.to_string()),
+        );
+    }
+    warnings
+}
+
+/// Purges the offline gate passes of a device the administrator has revoked.
+async fn wipe_revoked_device() -> Error {
+    error!("gate pass device is revoked, wiping offline gate passes");
+    let cleared = match indexed_db().await {
+        Ok(database) => clear_gate_passes(&database).await,
+        Err(error) => Err(error),
+    };
+    if let Err(error) = cleared {
+        error!("failed to wipe offline gate passes: error={:?}", error);
+    }
+    Error::Response(Cow::Borrowed("error-device-revoked"))
+}
+
+pub fn last_synced_at_details(last_synced_at_opt: Option<Cow<'static, str>>) -> Cow<'static, str> {
     let last_synced_at_str = last_synced_at_opt
         .and_then(|date_time| date_time.parse::<DateTime<Utc>>().ok())
         .map(|dt_utc| DateTime::<Local>::from(dt_utc))
@@ -315,12 +696,11 @@ async fn scan_gate_pass(qr_code_scanner_element_id: &str, camera: &Camera, torch
     {
         Ok(text_opt) => match text_opt {
             Some(text) => {
-                if text.starts_with(MTC_QR_CODE_SUFFIX) {
-                    let gate_pass_id = text.replace(MTC_QR_CODE_SUFFIX, "");
+                if let Some(qr_code) = text.strip_prefix(GATE_PASS_QR_CODE_PREFIX) {
                     navigator().push(route!(
                         ROUT_GATE_PASS_VALIDATION_SCANS,
                         "results",
-                        gate_pass_id
+                        qr_code
                     ));
                 } else {
                     navigator().push(route!(
@@ -363,23 +743,87 @@ pub fn GatePassScanErrorView(#[props(into)] error: String) -> Element {
 }
 
 #[component]
-pub fn GatePassScanResultView(#[props(into)] id: String) -> Element {
+pub fn GatePassScanResultView(#[props(into)] qr_code: String) -> Element {
     breadcrumbs!("menu-gate-pass-validation-scan-result");
     check_permission!(PERMISSION_GATE_PASS_VALIDATE);
 
-    let id = use_memo(use_reactive!(|id| id));
+    let qr_code = use_memo(use_reactive!(|qr_code| {
+        GatePassQrCode::parse(&format!("{GATE_PASS_QR_CODE_PREFIX}{qr_code}")).unwrap_or_default()
+    }));
     let offline_mode = use_local_storage(MTC_OFFLINE_MODE_KEY, || Value::Bool(false));
     let gate_pass_resource = use_resource(move || async move {
         get_sync_gate_pass(
-            Cow::Owned(id()),
+            qr_code().id,
             offline_mode.get().self_bool().unwrap_or_default(),
         )
         .await
     });
+    let sync_entry_resource = use_resource(move || async move {
+        let indexed_db_signal = use_state().indexed_db();
+        let indexed_db_opt = indexed_db_signal.read();
+        let indexed_db_ref = indexed_db_opt.as_ref()?;
+        get_sync_entry(indexed_db_ref, SyncEntryId::GatePassSync)
+            .await
+            .ok()
+    });
+    let continuous_scan_mode = use_local_storage(MTC_CONTINUOUS_SCAN_MODE_KEY, || Value::Bool(false))
+        .get()
+        .self_bool()
+        .unwrap_or_default();
+    use_effect(move || {
+        let (Some(gate_pass_opt), Some(sync_entry_opt)) =
+            (gate_pass_resource(), sync_entry_resource())
+        else {
+            return;
+        };
+        let validation_result = validate_gate_pass(&gate_pass_opt, &qr_code(), &sync_entry_opt);
+        let valid = validation_result == GatePassValidationResult::Valid;
+        let scan_history_entry = GatePassScanHistoryEntry {
+            qr_code: qr_code
+                .peek()
+                .to_string()
+                .trim_start_matches(GATE_PASS_QR_CODE_PREFIX)
+                .to_string(),
+            owner_name: owner_details(&gate_pass_opt),
+            number_plate: gate_pass_opt
+                .as_ref()
+                .and_then(|gate_pass| gate_pass.vehicles.first())
+                .map(|vehicle| vehicle.number_plate.to_string()),
+            validation_result,
+            scanned_at: Local::now().to_rfc3339(),
+        };
+        spawn(async move {
+            push_scan_history(scan_history_entry).await;
+        });
+        if continuous_scan_mode {
+            spawn(async move {
+                let pause_ms = if valid {
+                    GATE_PASS_CONTINUOUS_SCAN_VALID_PAUSE_MS
+                } else {
+                    GATE_PASS_CONTINUOUS_SCAN_INVALID_PAUSE_MS
+                };
+                if JsFuture::from(jsFfiPlayGatePassScanFeedback(valid, pause_ms))
+                    .await
+                    .is_err()
+                {
+                    error!("failed to invoke jsFfiPlayGatePassScanFeedback");
+                }
+                navigator().go_back();
+            });
+        }
+    });
     let gate_pass_opt = gate_pass_resource.suspend()?();
-    let validation_result = validate_gate_pass(&gate_pass_opt);
+    let sync_entry_opt = sync_entry_resource.suspend()?();
+    let sync_warnings = sync_warnings(
+        sync_entry_opt.as_ref(),
+        offline_mode.get().self_bool().unwrap_or_default(),
+    );
+    let validation_result = validate_gate_pass(&gate_pass_opt, &qr_code(), &sync_entry_opt);
     let valid = validation_result == GatePassValidationResult::Valid;
-    let deleted = validation_result == GatePassValidationResult::Deleted;
+    let deleted = matches!(
+        validation_result,
+        GatePassValidationResult::Deleted | GatePassValidationResult::Revoked
+    );
     let validation_result_not_found = validation_result == GatePassValidationResult::NotFound;
     let allow_any_vehicle = gate_pass_opt
         .as_ref()
@@ -387,10 +831,13 @@ pub fn GatePassScanResultView(#[props(into)] id: String) -> Element {
         .unwrap_or(false);
     rsx! {
         div { class: "text-center text-3xl font-bold",
+            for sync_warning in sync_warnings {
+                div { class: "text-error text-base font-normal", {sync_warning} }
+            }
             h1 {
                 if GatePassValidationResult::Valid == validation_result {
                     div { style: "color: green;", {t!("gate-pass-message-scan-result-valid")} }
-                } else if let GatePassValidationResult::Blocked(ref reason) = validation_result {
+                } else if let GatePassValidationResult::Blocked(_) = validation_result {
                     div { style: "color: red;", {t!("gate-pass-message-scan-result-blocked")} }
                 } else {
                     div { style: "color: red;", {t!("gate-pass-message-scan-result-invalid")} }
@@ -399,9 +846,10 @@ pub fn GatePassScanResultView(#[props(into)] id: String) -> Element {
             h2 {
                 match validation_result {
                     GatePassValidationResult::Valid => "".to_string(),
-                    GatePassValidationResult::Blocked(ref reason) => reason.to_string(),
+                    GatePassValidationResult::Blocked(ref block) => gate_pass_block_reason(block),
                     GatePassValidationResult::Expired => t!("gate-pass-message-scan-result-expired"),
                     GatePassValidationResult::Deleted => t!("gate-pass-message-scan-result-deleted"),
+                    GatePassValidationResult::Revoked => t!("gate-pass-message-scan-result-revoked"),
                     GatePassValidationResult::NotFound => {
                         t!("gate-pass-message-scan-result-not-found")
                     }
@@ -420,6 +868,9 @@ pub fn GatePassScanResultView(#[props(into)] id: String) -> Element {
                         {vehicle_details(&gate_pass_opt)}
                     }                }
             }
+            if continuous_scan_mode {
+                div { class: "text-base font-normal", {t!("gate-pass-message-continuous-scan-resume")} }
+            }
             button {
                 class: "btn btn-xl btn-ghost",
                 onclick: move |_| {
@@ -476,6 +927,11 @@ async fn get_sync_gate_pass(
             .send()
             .await
         {
+            Ok(response) if response.status() == StatusCode::GONE => {
+                wipe_revoked_device().await;
+                error_dialog!("error-device-revoked");
+                None
+            }
             Ok(response) => response.json().await.ok().self_obj::<SyncGatePass>(),
             Err(error) => {
                 if !error.status().unwrap_or_default().is_client_error() {
@@ -495,28 +951,77 @@ async fn get_indexed_db_gate_pass(
 ) -> Option<SyncGatePass> {
     let indexed_db_opt = indexed_db_signal.read();
     let indexed_db_ref = indexed_db_opt.as_ref().unwrap();
-    get_gate_pass(indexed_db_ref, gate_pass_id).await.ok()
+    let offline_key = gate_pass_offline_key().await.ok()?;
+    get_gate_pass(indexed_db_ref, &offline_key, gate_pass_id).await.ok()
 }
 
-fn validate_gate_pass(gate_pass: &Option<SyncGatePass>) -> GatePassValidationResult {
-    match gate_pass {
-        Some(sync_gate_pass) => {
-            if sync_gate_pass.deleted {
-                return GatePassValidationResult::Deleted;
-            }
-            if sync_gate_pass.expired() == true {
-                return GatePassValidationResult::Expired;
-            }
-            if sync_gate_pass.blocked() == true {
-                let reason = gate_pass
-                    .as_ref()
-                    .and_then(|gate_pass| gate_pass.block.as_ref())
-                    .map(|block| block.reason.clone())
-                    .unwrap_or_default();
-                return GatePassValidationResult::Blocked(reason);
-            }
-            GatePassValidationResult::Valid
+/// Reads the scan history, it is encrypted with the offline key of the device like the
+/// offline gate passes.
+async fn scan_history() -> Vec<GatePassScanHistoryEntry> {
+    let Ok(offline_key) = gate_pass_offline_key().await else {
+        return vec![];
+    };
+    let mcrypt = new_magic_crypt!(offline_key.key.as_ref(), 256);
+    SessionStorage::get::<String>(MTC_SCAN_HISTORY_KEY)
+        .ok()
+        .and_then(|payload| mcrypt.decrypt_base64_to_string(payload).ok())
+        .and_then(|json| serde_json::from_str::<Vec<GatePassScanHistoryEntry>>(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Puts the scan on top of the history, a re-check of the same gate pass replaces its
+/// previous entry.
+async fn push_scan_history(scan_history_entry: GatePassScanHistoryEntry) {
+    let Ok(offline_key) = gate_pass_offline_key().await else {
+        error!("failed to store gate pass scan history: the offline key is missing");
+        return;
+    };
+    let mut scan_history = scan_history().await;
+    scan_history.retain(|entry| entry.qr_code != scan_history_entry.qr_code);
+    scan_history.insert(0, scan_history_entry);
+    scan_history.truncate(GATE_PASS_SCAN_HISTORY_SIZE);
+
+    let mcrypt = new_magic_crypt!(offline_key.key.as_ref(), 256);
+    let stored = serde_json::to_string(&scan_history)
+        .map_err(|error| error.to_string())
+        .and_then(|json| {
+            SessionStorage::set(MTC_SCAN_HISTORY_KEY, mcrypt.encrypt_str_to_base64(json))
+                .map_err(|error| error.to_string())
+        });
+    if let Err(error) = stored {
+        error!("failed to store gate pass scan history: {error:?}");
+    }
+}
+
+fn validation_result_details(validation_result: &GatePassValidationResult) -> String {
+    match validation_result {
+        GatePassValidationResult::Valid => t!("gate-pass-message-scan-result-valid"),
+        GatePassValidationResult::Blocked(block) => {
+            format!(
+                "{} {}",
+                t!("gate-pass-message-scan-result-blocked"),
+                gate_pass_block_reason(block)
+            )
         }
+        GatePassValidationResult::Expired => t!("gate-pass-message-scan-result-expired"),
+        GatePassValidationResult::Deleted => t!("gate-pass-message-scan-result-deleted"),
+        GatePassValidationResult::Revoked => t!("gate-pass-message-scan-result-revoked"),
+        GatePassValidationResult::NotFound => t!("gate-pass-message-scan-result-not-found"),
+    }
+}
+
+/// Validates against the server-adjusted time when the device has synced before.
+fn validate_gate_pass(
+    gate_pass: &Option<SyncGatePass>,
+    qr_code: &GatePassQrCode,
+    sync_entry_opt: &Option<SyncEntry>,
+) -> GatePassValidationResult {
+    let now = sync_entry_opt
+        .as_ref()
+        .map(|sync_entry| sync_entry.server_now())
+        .unwrap_or_else(Utc::now);
+    match gate_pass {
+        Some(sync_gate_pass) => sync_gate_pass.validate_qr_code_by(qr_code, now),
         None => GatePassValidationResult::NotFound,
     }
 }
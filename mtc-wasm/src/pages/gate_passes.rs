@@ -1,8 +1,10 @@
 use super::*;
+use crate::pages::gate_pass_bulk::{GatePassBulkButton, GatePassBulkDialogView};
 use crate::pages::gate_pass_delete::{GatePassDeleteButton, GatePassDeleteDialogView};
 use crate::pages::gate_pass_email::GatePassSendEmailDialogView;
 use crate::pages::gate_pass_print::{GatePassPrintButton, GatePassPrintDialogView};
 use crate::pages::gate_pass_renew::{GatePassRenewButton, GatePassRenewDialogView};
+use crate::pages::gate_pass_report::{GatePassReportButton, GatePassReportDialogView};
 use chrono::{Datelike, NaiveDate};
 use dioxus::prelude::*;
 use tokio::join;
@@ -21,10 +23,13 @@ pub fn GatePasses() -> Element {
     let mut gate_pass_selected_ids_signal = use_signal(HashSet::new);
     let gate_pass_delete_dialog_visible_signal = use_signal(|| false);
     let gate_pass_print_dialog_visible_signal = use_signal(|| false);
+    let gate_pass_report_dialog_visible_signal = use_signal(|| false);
     let gate_pass_renew_dialog_visible_signal = use_signal(|| false);
+    let gate_pass_bulk_dialog_visible_signal = use_signal(|| false);
     let gate_pass_id_block_signal = use_signal(|| None::<String>);
     let gate_pass_block_signal = use_signal(|| None::<GatePassBlock>);
     let gate_pass_id_send_email_signal = use_signal(|| None::<String>);
+    let gate_pass_owner_email_signal = use_signal(|| None::<String>);
 
     let gate_passes_resource = use_resource(move || async move {
         let search = search_signal();
@@ -79,12 +84,23 @@ pub fn GatePasses() -> Element {
                 gate_pass_selected_ids_signal,
                 gate_pass_print_dialog_visible_signal,
             }
+            GatePassReportDialogView {
+                gate_pass_selected_ids_signal,
+                gate_pass_report_dialog_visible_signal,
+            }
             GatePassRenewDialogView {
                 gate_pass_selected_ids_signal,
                 gate_pass_renew_dialog_visible_signal,
             }
+            GatePassBulkDialogView {
+                gate_pass_selected_ids_signal,
+                gate_pass_bulk_dialog_visible_signal,
+            }
             GatePassBlockDialogView { gate_pass_id_block_signal, gate_pass_block_signal }
-            GatePassSendEmailDialogView { gate_pass_id_send_email_signal }
+            GatePassSendEmailDialogView {
+                gate_pass_id_send_email_signal,
+                gate_pass_owner_email_signal,
+            }
             PaginationBar{
                 page_size_signal, page_index_signal, number_of_pages_signal
             }
@@ -92,9 +108,12 @@ pub fn GatePasses() -> Element {
                 gate_passes_resource,
                 gate_pass_delete_dialog_visible_signal,
                 gate_pass_print_dialog_visible_signal,
+                gate_pass_report_dialog_visible_signal,
                 gate_pass_renew_dialog_visible_signal,
+                gate_pass_bulk_dialog_visible_signal,
                 gate_pass_id_block_signal,
                 gate_pass_id_send_email_signal,
+                gate_pass_owner_email_signal,
                 gate_pass_block_signal,
                 gate_pass_selected_ids_signal,
             }
@@ -149,9 +168,12 @@ pub fn GatePassListView(
     gate_passes_resource: Resource<Vec<GatePass>>,
     gate_pass_delete_dialog_visible_signal: Signal<bool>,
     gate_pass_print_dialog_visible_signal: Signal<bool>,
+    gate_pass_report_dialog_visible_signal: Signal<bool>,
     gate_pass_renew_dialog_visible_signal: Signal<bool>,
+    gate_pass_bulk_dialog_visible_signal: Signal<bool>,
     gate_pass_id_block_signal: Signal<Option<String>>,
     gate_pass_id_send_email_signal: Signal<Option<String>>,
+    gate_pass_owner_email_signal: Signal<Option<String>>,
     gate_pass_block_signal: Signal<Option<GatePassBlock>>,
     gate_pass_selected_ids_signal: Signal<HashSet<String>>,
 ) -> Element {
@@ -187,6 +209,7 @@ pub fn GatePassListView(
                         th { class: "text-wrap", {t!("gate-pass-field-owner-full-name")} }
                         th { class: "text-wrap", {t!("gate-pass-field-vehicle-number-plate")} }
                         th { class: "text-wrap", {t!("gate-pass-field-vehicle-info")} }
+                        th { class: "text-wrap", {t!("gate-pass-field-printed")} }
                         th { class: "text-wrap", {t!("gate-pass-field-actions")} }
                     }
                 }
@@ -229,7 +252,16 @@ pub fn GatePassListView(
                                             },
                                         }
                                     }
-                                    td { {format!("{} {} {}", &*owner.last_name, &*owner.first_name, &*owner.middle_name)} }
+                                    td {
+                                        {format!("{} {} {}", &*owner.last_name, &*owner.first_name, &*owner.middle_name)}
+                                        if owner.has_contact() {
+                                            span {
+                                                class: "badge badge-sm badge-ghost ml-2",
+                                                title: gate_pass_owner_contacts(owner),
+                                                { t!("gate-pass-has-contact") }
+                                            }
+                                        }
+                                    }
                                     td { {vehicle.number_plate.as_ref()} }
                                     td {
                                         {
@@ -242,6 +274,17 @@ pub fn GatePassListView(
                                             )
                                         }
                                     }
+                                    td {
+                                        if let Some(last_printed_at) = gate_pass
+                                            .last_printed_at
+                                            .as_ref()
+                                            .and_then(|value| value.parse::<DateTime<Local>>().ok())
+                                        {
+                                            {format!("{} ({})", gate_pass.print_count, last_printed_at.format("%d.%m.%y"))}
+                                        } else {
+                                            {t!("gate-pass-never-printed")}
+                                        }
+                                    }
                                     td {
                                         button {
                                             class: "btn btn-ghost",
@@ -270,15 +313,41 @@ pub fn GatePassListView(
                                             title: t!("gate-pass-action-send-email"),
                                             onclick: {
                                                 let gate_pass_id = gate_pass.id.to_string();
+                                                let owner_email = owner.email.as_ref().map(|email| email.to_string());
                                                 move |event| {
                                                     event.prevent_default();
                                                     event.stop_propagation();
 
+                                                    gate_pass_owner_email_signal.set(owner_email.clone());
                                                     gate_pass_id_send_email_signal.set(Some(gate_pass_id.clone()));
                                                 }
                                             },
                                             Icon { icon: Icons::SendEmail, class: "size-6" }
                                         }
+
+                                        button {
+                                            class: "btn btn-ghost",
+                                            title: t!("gate-pass-action-reissue"),
+                                            onclick: {
+                                                let gate_pass_id = gate_pass.id.to_string();
+                                                move |event: MouseEvent| {
+                                                    event.prevent_default();
+                                                    event.stop_propagation();
+
+                                                    let gate_pass_id = gate_pass_id.clone();
+                                                    let reissue_gate_pass = Callback::new(move |_: MouseEvent| {
+                                                        let gate_pass_id = gate_pass_id.clone();
+                                                        spawn(async move {
+                                                            if post_request!(url!(API_GATE_PASSES, gate_pass_id.as_str(), "reissues")) {
+                                                                success_dialog!("gate-pass-message-reissued");
+                                                            }
+                                                        });
+                                                    });
+                                                    alert_dialog!("gate-pass-reissue-description", reissue_gate_pass);
+                                                }
+                                            },
+                                            Icon { icon: Icons::QrCode, class: "size-6" }
+                                        }
                                     }
                                 }
                             }
@@ -298,9 +367,15 @@ pub fn GatePassListView(
                     rsx! {
                         GatePassRenewButton { gate_pass_renew_dialog_visible_signal }
                     },
+                    rsx! {
+                        GatePassBulkButton { gate_pass_bulk_dialog_visible_signal }
+                    },
                     rsx! {
                         GatePassPrintButton { gate_pass_print_dialog_visible_signal }
                     },
+                    rsx! {
+                        GatePassReportButton { gate_pass_report_dialog_visible_signal }
+                    },
                     GatePassExportButton(),
                     GatePassImportButton(),
                 ],
@@ -310,18 +385,54 @@ pub fn GatePassListView(
 }
 
 pub fn gate_pass_owner_title_name(title: &GatePassOwnerTitle) -> String {
-    let key = format!("gate-pass-owner-title-{:?}", title).to_lowercase();
-    t!(key.as_str())
+    gate_pass_dictionary_name(GatePassDictionaryKind::OwnerTitle, title.code())
 }
 
 pub fn gate_pass_vehicle_color_name(color: &VehicleColor) -> String {
-    let key = format!("gate-pass-vehicle-color-{:?}", color).to_lowercase();
-    t!(key.as_str())
+    gate_pass_dictionary_name(GatePassDictionaryKind::VehicleColor, color.code())
 }
 
 pub fn gate_pass_vehicle_body_type_name(body_type: &VehicleBodyType) -> String {
-    let key = format!("gate-pass-vehicle-body-type-{:?}", body_type).to_lowercase();
-    t!(key.as_str())
+    gate_pass_dictionary_name(GatePassDictionaryKind::VehicleBodyType, body_type.code())
+}
+
+pub fn gate_pass_dictionary_name(kind: GatePassDictionaryKind, code: &str) -> String {
+    state!(gate_pass_dictionaries)
+        .name(kind, code, &t!("site-locale"))
+        .to_string()
+}
+
+/// Returns `(code, name)` pairs of a gate pass dictionary in the sort order.
+pub fn gate_pass_dictionary_items(kind: GatePassDictionaryKind) -> Vec<(String, String)> {
+    let locale = t!("site-locale");
+    state!(gate_pass_dictionaries)
+        .entries(kind)
+        .map(|entry| (entry.code.to_string(), entry.name(&locale).to_string()))
+        .collect()
+}
+
+/// Catalog reason in the site language followed by the free text, if any.
+pub fn gate_pass_block_reason(block: &GatePassBlock) -> String {
+    block
+        .reason_code
+        .as_ref()
+        .map(|reason_code| {
+            gate_pass_dictionary_name(GatePassDictionaryKind::BlockReason, reason_code)
+        })
+        .into_iter()
+        .chain(Some(block.reason.to_string()))
+        .filter(|reason| !reason.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(": ")
+}
+
+pub fn gate_pass_owner_contacts(owner: &GatePassOwner) -> String {
+    [&owner.phone, &owner.email]
+        .into_iter()
+        .flatten()
+        .map(|contact| contact.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 pub fn gate_pass_allow_any_vehicle_name(allow_any_vehicle: &bool) -> String {
@@ -21,11 +21,28 @@ pub fn GroupEdit(
     let response = future.suspend()?;
     check_response!(response, future);
 
+    // the group can't be moved under itself or its subgroups
+    let groups = state!(groups);
+    let subtree = GroupNode::subtree(&groups, &id());
+    let parents = groups
+        .iter()
+        .filter(|group| !subtree.contains(&group.id))
+        .map(|group| Entry {
+            title: format!(
+                "{}{}",
+                "\u{a0}".repeat(4 * GroupNode::of(group).depth),
+                group.title
+            ).into(),
+            ..group.clone()
+        })
+        .collect::<Vec<Entry>>();
+
     let submit = move |event: Event<FormData>| {
         let payload = json!({
             "id": event.get_str("id"),
             "slug": event.get_str("slug"),
-            "title": event.get_str("title")
+            "title": event.get_str("title"),
+            "parent": event.get_str("parent").unwrap_or_default()
         });
 
         spawn(async move {
@@ -71,6 +88,12 @@ pub fn GroupEdit(
                     required: true,
                     initial_value: response().key_string("title")
                 }
+                FormSelectField {
+                    name: "parent",
+                    title: "field-parent-group",
+                    selected: response().key_string("parent").unwrap_or_default(),
+                    items: parents
+                }
             }
         }
         EntryInfoBox {
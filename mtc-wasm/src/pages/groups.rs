@@ -1,6 +1,6 @@
 use super::*;
 
-/// Page displaying a list of all groups, with links to each group's page.
+/// Page displaying the tree of all groups, with links to each group's page.
 ///
 /// # Authorization
 ///
@@ -32,6 +32,7 @@ pub fn Groups() -> Element {
                     .self_obj::<Vec<Entry>>()
                     .unwrap_or_default().iter() {{
                         let id = group.id.to_owned();
+                        let depth = GroupNode::of(group).depth;
 
                         rsx! {
                             tr {
@@ -40,6 +41,8 @@ pub fn Groups() -> Element {
                                     .push(route!(API_ADMINISTRATOR, API_GROUP, &id));
                                 },
                                 td {
+                                    style: format!("padding-left: {}rem", 0.75 + 1.5 * depth as f32),
+                                    if depth > 0 { "└ " }
                                     { group.slug.as_ref() }
                                 }
                                 td {
@@ -16,6 +16,8 @@ use super::*;
 /// - A header with bold text and an announcement message.
 /// - A card containing the QR code image, loaded with specific attributes
 ///   for performance and cross-origin security.
+/// - A refresh button, the QR code carries a short-lived one-time token so a new
+///   one is requested on every refresh.
 #[component]
 pub fn LinkingQrCode() -> Element {
     if !state!(auth).is_authenticated() {
@@ -24,6 +26,8 @@ pub fn LinkingQrCode() -> Element {
 
     breadcrumbs!("menu-linking-qr-code");
 
+    let mut qr_code_version_signal = use_signal(|| 0_u32);
+
     rsx! {
         div {
             class: "div-centered",
@@ -41,18 +45,28 @@ pub fn LinkingQrCode() -> Element {
                             class: "py-6",
                             { t!("message-linking-qr-announcement") }
                         }
+                        p {
+                            class: "text-sm opacity-70",
+                            { t!("message-linking-qr-expiry") }
+                        }
                     }
                     div {
                         class: "card w-full max-w-sm shrink-0 border rounded",
                         div {
                             class: "card-body",
                             img {
-                                src: url!(API_AUTH, "qr"),
+                                src: format!("{}?v={}", url!(API_AUTH, "qr"), qr_code_version_signal()),
                                 crossorigin: "use-credentials",
                                 decoding: "async",
                                 loading: "lazy",
                                 alt: "QR-Code"
                             }
+                            button {
+                                class: "btn btn-outline",
+                                onclick: move |_| qr_code_version_signal += 1,
+                                Icon { icon: Icons::Refresh, class: "size-6" }
+                                { t!("action-refresh-qr-code") }
+                            }
                         }
                     }
                 }
@@ -22,7 +22,9 @@ mod course_edit;
 mod personnel;
 mod personnel_add;
 mod linking_qr_code;
+mod user_devices;
 mod qr_sign_in;
+mod two_factor;
 mod js_exec;
 mod app_data;
 mod gate_passes;
@@ -33,8 +35,19 @@ mod gate_pass_email;
 mod gate_pass_impex;
 mod navigation_loop;
 mod gate_pass_print;
+mod gate_pass_report;
 mod gate_pass_renew;
 mod gate_pass_delete;
+mod gate_pass_bulk;
+mod gate_pass_policy;
+mod gate_pass_prints;
+mod gate_pass_devices;
+mod sign_in_lockouts;
+mod security_audit;
+mod access_tokens;
+mod gate_pass_blocks;
+mod gate_pass_dictionaries;
+mod gate_pass_offline_database;
 
 pub mod prelude {
     pub use super::{
@@ -60,7 +73,10 @@ pub mod prelude {
         personnel::*,
         personnel_add::*,
         linking_qr_code::*,
+        user_devices::*,
+        access_tokens::*,
         qr_sign_in::*,
+        two_factor::*,
         js_exec::*,
         app_data::*,
         gate_passes::*,
@@ -69,6 +85,14 @@ pub mod prelude {
         gate_pass_block::*,
         gate_pass_email::*,
         gate_pass_impex::*,
+        gate_pass_policy::*,
+        gate_pass_prints::*,
+        gate_pass_devices::*,
+        sign_in_lockouts::*,
+        security_audit::*,
+        gate_pass_blocks::*,
+        gate_pass_dictionaries::*,
+        gate_pass_offline_database::*,
         navigation_loop::*,
     };
 }
\ No newline at end of file
@@ -105,7 +105,7 @@ pub fn Personnel() -> Element {
                                         name: "group",
                                         title: "field-group",
                                         selected: "",
-                                        items: state!(groups)
+                                        items: GroupNode::leaves(&state!(groups))
                                     }
                                 }
                                 if confirmation() {
@@ -29,7 +29,7 @@ pub fn QrSignIn() -> Element {
                         let mcrypt = new_magic_crypt!(env!("CRYPT_KEY"), 256);
                         if let Ok(decrypted_qr) = mcrypt.decrypt_base64_to_string(&message) {
                             let payload = json!({
-                            "api_key": decrypted_qr.as_str(),
+                            "qr_token": decrypted_qr.as_str(),
                         });
 
                             if !post_request!(url!(API_AUTH), payload) {
@@ -24,6 +24,7 @@ pub fn RoleEdit(
             "slug": event.get_str("slug"),
             "title": event.get_str("title"),
             "user_access_all": event.get_bool("user_access_all"),
+            "two_factor_required": event.get_bool("two_factor_required"),
             "user_access_level": event.get_i64("user_access_level").unwrap_or(999),
             "permissions": event.get_str_array("permissions")
         });
@@ -88,6 +89,12 @@ pub fn RoleEdit(
                     checked: response()
                         .key_bool("user_access_all").unwrap_or_default()
                 }
+                FormToggleField {
+                    name: "two_factor_required",
+                    title: "field-two-factor-required",
+                    checked: response()
+                        .key_bool("two_factor_required").unwrap_or_default()
+                }
                 FormEntriesField {
                     name: "permissions",
                     title: "field-permissions",
@@ -0,0 +1,197 @@
+use super::*;
+use wasm_bindgen_futures::JsFuture;
+
+#[component]
+pub fn SecurityAudit() -> Element {
+    breadcrumbs!("menu-security-audit");
+    check_permission!(PERMISSION_SECURITY_AUDIT_READ);
+
+    // pagination
+    let mut page_index_signal = use_signal(|| 0);
+    let page_size_signal = use_signal(|| 40);
+    let mut number_of_pages_signal = use_signal(|| 0);
+
+    let mut filter_signal = use_signal(SearchSecurityAuditRequest::default);
+    let mut loading_spinner_hidden_signal = use_signal(|| true);
+
+    let security_audit_resource = use_resource(move || async move {
+        let request = SearchSecurityAuditRequest {
+            page_request: Some(PageRequest::new(
+                page_size_signal(),
+                page_index_signal(),
+                vec![],
+            )),
+            ..filter_signal()
+        };
+        let page = state!(client)
+            .post(url!(API_SECURITY_AUDIT, "searches"))
+            .json(&request)
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<PageResponse<SecurityAuditEvent>>()
+            .unwrap_or_default();
+        number_of_pages_signal.set(page.number_of_pages);
+        page.page_rows
+    });
+
+    let on_submit_filter = move |event: Event<FormData>| {
+        event.prevent_default();
+        event.stop_propagation();
+
+        let kinds = event
+            .get_str("kind")
+            .and_then(|kind| serde_json::from_value::<SecurityAuditKind>(json!(kind)).ok())
+            .map(|kind| vec![kind]);
+        page_index_signal.set(0);
+        filter_signal.set(SearchSecurityAuditRequest {
+            kinds,
+            actor: event.get_str("actor"),
+            target: event.get_str("target"),
+            ip: event.get_str("ip"),
+            date_from: event.get_str("date_from"),
+            date_to: event.get_str("date_to"),
+            page_request: None,
+        });
+    };
+
+    let export = move |_| {
+        loading_spinner_hidden_signal.set(false);
+        spawn(async move {
+            let content = state!(client)
+                .post(url!(API_SECURITY_AUDIT, "exports"))
+                .json(&filter_signal())
+                .send()
+                .await
+                .get_bytes()
+                .await;
+            loading_spinner_hidden_signal.set(true);
+            let Some(content) = content else {
+                return;
+            };
+            let file_name = format!("security-audit-{}.csv", Local::now().format("%Y%m%d-%H%M"));
+            if JsFuture::from(jsFfiExportBinaryFile(&content, "text/csv", "csv", &file_name))
+                .await
+                .is_err()
+            {
+                error!("failed to invoke jsFfiExportBinaryFile");
+            }
+        });
+    };
+
+    let kinds = SecurityAuditKind::ALL
+        .iter()
+        .map(|kind| (kind.as_str().to_string(), security_audit_kind_name(kind)))
+        .collect::<Vec<(String, String)>>();
+
+    rsx! {
+        section { class: "w-full grow xl:pr-16",
+            form {
+                class: "grid w-full grid-cols-1 gap-x-3 sm:grid-cols-3",
+                autocomplete: "off",
+                onsubmit: on_submit_filter,
+                FormSimpleSelectField {
+                    name: "kind",
+                    title: "security-audit-field-kind",
+                    selected: "",
+                    items: kinds,
+                }
+                FormTextField {
+                    name: "actor",
+                    title: "security-audit-field-actor",
+                }
+                FormTextField {
+                    name: "target",
+                    title: "security-audit-field-target",
+                }
+                FormTextField {
+                    name: "ip",
+                    title: "security-audit-field-ip",
+                }
+                FormDateField {
+                    name: "date_from",
+                    title: "security-audit-field-date-from",
+                }
+                FormDateField {
+                    name: "date_to",
+                    title: "security-audit-field-date-to",
+                }
+                div { class: "flex gap-3 justify-center mt-4 mb-6 sm:col-span-3",
+                    button { class: "btn btn-primary",
+                        Icon { icon: Icons::Search, class: "size-6" }
+                        { t!("action-search") }
+                    }
+                    button {
+                        class: "btn btn-outline",
+                        r#type: "button",
+                        onclick: export,
+                        span {
+                            class: "loading loading-spinner",
+                            hidden: loading_spinner_hidden_signal(),
+                        }
+                        { t!("action-export") }
+                    }
+                }
+            }
+            PaginationBar{
+                page_size_signal, page_index_signal, number_of_pages_signal
+            }
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("security-audit-field-created-at")} }
+                        th { class: "text-wrap", {t!("security-audit-field-kind")} }
+                        th { class: "text-wrap", {t!("security-audit-field-actor")} }
+                        th { class: "text-wrap", {t!("security-audit-field-target")} }
+                        th { class: "text-wrap", {t!("security-audit-field-ip")} }
+                        th { class: "text-wrap", {t!("security-audit-field-user-agent")} }
+                        th { class: "text-wrap", {t!("security-audit-field-details")} }
+                    }
+                }
+                tbody {
+                    for event in security_audit_resource().unwrap_or_default() {
+                        tr {
+                            td {
+                                {
+                                    event
+                                        .created_at
+                                        .parse::<DateTime<Local>>()
+                                        .unwrap_or_default()
+                                        .format("%H:%M:%S %d/%m/%Y")
+                                        .to_string()
+                                }
+                            }
+                            td { {t!(security_audit_kind_name(&event.kind).as_str())} }
+                            td { {event.actor.as_ref()} }
+                            td { {event.target.as_ref()} }
+                            td { {event.ip.as_ref()} }
+                            td { class: "text-wrap break-all", {event.user_agent.as_ref()} }
+                            td {
+                                class: "text-wrap break-all",
+                                {event.details.map(|details| details.to_string()).unwrap_or_default()}
+                            }
+                        }
+                    }
+                }
+            }
+            PaginationBar{
+                page_size_signal, page_index_signal, number_of_pages_signal
+            }
+        }
+    }
+}
+
+/// Returns the translation key of the event kind, `SignInFailure` is
+/// `security-audit-kind-sign-in-failure`.
+fn security_audit_kind_name(kind: &SecurityAuditKind) -> String {
+    kind.as_str()
+        .chars()
+        .fold(String::from("security-audit-kind"), |mut name, char| {
+            if char.is_uppercase() {
+                name.push('-');
+            }
+            name.push(char.to_ascii_lowercase());
+            name
+        })
+}
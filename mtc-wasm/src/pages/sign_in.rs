@@ -48,6 +48,7 @@ pub fn SignIn() -> Element {
     let platform = state!(platform);
 
     let sync = use_coroutine_handle::<SyncAction>();
+    let mut two_factor_signal = use_signal(|| false);
 
     let submit = move |event: Event<FormData>| {
         let payload = json!({
@@ -55,18 +56,31 @@ pub fn SignIn() -> Element {
             "password": event.get_str("password")
         });
         spawn(async move {
-            if !post_request!(url!(API_AUTH), payload) { return; };
+            let Some(response) = value_request!(url!(API_AUTH), payload)
+                .self_obj::<SignInResponse>() else { return; };
 
-            sync.send(SyncAction::RefreshState());
-
-            if navigator().can_go_back() {
-                navigator().go_back()
-            } else {
-                navigator().replace(route!());
+            match response.two_factor {
+                Some(TwoFactorStep::Verify) => two_factor_signal.set(true),
+                Some(TwoFactorStep::Enroll) => {
+                    navigator().replace(route!(API_AUTH, "two-factor"));
+                }
+                None => complete_sign_in(sync, response.password_change_required),
             }
         });
     };
 
+    let submit_two_factor = move |event: Event<FormData>| {
+        let payload = json!(TwoFactorCodeRequest {
+            code: event.get_str("code").unwrap_or_default(),
+        });
+        spawn(async move {
+            let Some(response) = value_request!(url!(API_AUTH, "two-factor"), payload)
+                .self_obj::<SignInResponse>() else { return; };
+
+            complete_sign_in(sync, response.password_change_required);
+        });
+    };
+
     if platform.eq("android") {
         spawn( async move {
             if JsFuture::from(jsFfiStopBarcodeScanner()).await.is_err() {
@@ -93,53 +107,87 @@ pub fn SignIn() -> Element {
                             { t!("message-login-form-announcement") }
                         }
                     }
-                    div {
-                        class: "card bg-base-100 card-border w-full max-w-sm shrink-0 shadow-xl",
-                        form {
-                            class: "card-body pt-4",
-                            id: "sign-in-form",
-                            autocomplete: "off",
-                            onsubmit: submit,
-
-                            FormTextField {
-                                name: "login",
-                                title: "field-login",
-                                required: true
-                            }
-                            FormTextField {
-                                r#type: "password",
-                                name: "password",
-                                title: "field-password",
-                                required: true
-                            }
-                        }
+                    if two_factor_signal() {
+                        div {
+                            class: "card bg-base-100 card-border w-full max-w-sm shrink-0 shadow-xl",
+                            form {
+                                class: "card-body pt-4",
+                                id: "two-factor-form",
+                                autocomplete: "off",
+                                onsubmit: submit_two_factor,
 
-                        if platform.eq("android") {
+                                p { { t!("message-two-factor-code-announcement") } }
+                                FormTextField {
+                                    name: "code",
+                                    title: "field-two-factor-code",
+                                    required: true
+                                }
+                            }
                             div {
                                 class: "card-actions grid grid-cols-2 gap-10 px-8 pb-8",
                                 button {
-                                    form: "sign-in-form",
+                                    form: "two-factor-form",
                                     class: "btn w-full btn-primary",
                                     Icon { icon: Icons::SignIn, class: "size-6" }
-                                    { t!("action-sign-in") }
+                                    { t!("action-confirm") }
                                 }
                                 button {
                                     class: "btn w-full",
-                                    onclick: move |_| {
-                                        navigator().push(route!(API_AUTH, "qr-sign-in"));
-                                    },
-                                    Icon { icon: Icons::QrScan, class: "size-6" }
-                                    { t!("action-scan-qr-code") }
+                                    onclick: move |_| two_factor_signal.set(false),
+                                    Icon { icon: Icons::Close, class: "size-6" }
+                                    { t!("action-cancel") }
                                 }
                             }
-                        } else {
-                            div {
-                                class: "card-actions px-6 pb-8",
-                                button {
-                                    form: "sign-in-form",
-                                    class: "btn w-full btn-primary",
-                                    Icon { icon: Icons::SignIn, class: "size-6" }
-                                    { t!("action-sign-in") }
+                        }
+                    } else {
+                        div {
+                            class: "card bg-base-100 card-border w-full max-w-sm shrink-0 shadow-xl",
+                            form {
+                                class: "card-body pt-4",
+                                id: "sign-in-form",
+                                autocomplete: "off",
+                                onsubmit: submit,
+
+                                FormTextField {
+                                    name: "login",
+                                    title: "field-login",
+                                    required: true
+                                }
+                                FormTextField {
+                                    r#type: "password",
+                                    name: "password",
+                                    title: "field-password",
+                                    required: true
+                                }
+                            }
+
+                            if platform.eq("android") {
+                                div {
+                                    class: "card-actions grid grid-cols-2 gap-10 px-8 pb-8",
+                                    button {
+                                        form: "sign-in-form",
+                                        class: "btn w-full btn-primary",
+                                        Icon { icon: Icons::SignIn, class: "size-6" }
+                                        { t!("action-sign-in") }
+                                    }
+                                    button {
+                                        class: "btn w-full",
+                                        onclick: move |_| {
+                                            navigator().push(route!(API_AUTH, "qr-sign-in"));
+                                        },
+                                        Icon { icon: Icons::QrScan, class: "size-6" }
+                                        { t!("action-scan-qr-code") }
+                                    }
+                                }
+                            } else {
+                                div {
+                                    class: "card-actions px-6 pb-8",
+                                    button {
+                                        form: "sign-in-form",
+                                        class: "btn w-full btn-primary",
+                                        Icon { icon: Icons::SignIn, class: "size-6" }
+                                        { t!("action-sign-in") }
+                                    }
                                 }
                             }
                         }
@@ -149,3 +197,20 @@ pub fn SignIn() -> Element {
         }
     }
 }
+
+/// Refreshes the application state after all the sign-in steps are passed, or sends
+/// the user to change the password when the sign-in waits for it.
+fn complete_sign_in(sync: Coroutine<SyncAction>, password_change_required: bool) {
+    if password_change_required {
+        navigator().replace(route!(API_AUTH, "change-password"));
+        return;
+    }
+
+    sync.send(SyncAction::RefreshState());
+
+    if navigator().can_go_back() {
+        navigator().go_back()
+    } else {
+        navigator().replace(route!());
+    }
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+#[component]
+pub fn SignInLockouts() -> Element {
+    breadcrumbs!("menu-sign-in-lockouts");
+    check_permission!(PERMISSION_USERS_READ);
+
+    let mut lockout_id_signal = use_signal(|| Cow::Borrowed(""));
+
+    let mut sign_in_lockouts_resource = use_resource(move || async move {
+        state!(client)
+            .get(url!(API_AUTH, "lockouts"))
+            .send()
+            .await
+            .get_value()
+            .await
+            .self_obj::<Vec<SignInLockout>>()
+            .unwrap_or_default()
+    });
+
+    let unlock = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let lockout_id = lockout_id_signal();
+            if post_request!(url!(API_AUTH, "lockouts", lockout_id.as_ref(), "unlocks")) {
+                success_dialog!("message-success-sign-in-unlocked");
+                sign_in_lockouts_resource.restart();
+            }
+        });
+    });
+
+    let format_date_time = |date_time_opt: Option<Cow<'static, str>>| {
+        date_time_opt
+            .and_then(|date_time| date_time.parse::<DateTime<Local>>().ok())
+            .map(|date_time| date_time.format("%H:%M %d/%m/%Y").to_string())
+            .unwrap_or_default()
+    };
+
+    rsx! {
+        section { class: "w-full grow xl:pr-16",
+            table { class: "entry-table",
+                thead {
+                    tr {
+                        th { class: "text-wrap", {t!("sign-in-lockout-field-key")} }
+                        th { class: "text-wrap", {t!("sign-in-lockout-field-ip")} }
+                        th { class: "text-wrap", {t!("sign-in-lockout-field-failures")} }
+                        th { class: "text-wrap", {t!("sign-in-lockout-field-locked-at")} }
+                        th { class: "text-wrap", {t!("sign-in-lockout-field-locked-until")} }
+                        th { class: "text-wrap", {t!("sign-in-lockout-field-unlocked-at")} }
+                        th { class: "w-12" }
+                    }
+                }
+                tbody {
+                    for sign_in_lockout in sign_in_lockouts_resource().unwrap_or_default() {
+                        tr {
+                            td {
+                                if sign_in_lockout.kind == SignInThrottleKind::Ip {
+                                    {format!("{} {}", t!("sign-in-lockout-kind-ip"), sign_in_lockout.key)}
+                                } else {
+                                    {sign_in_lockout.key.as_ref()}
+                                }
+                            }
+                            td { {sign_in_lockout.ip.as_ref()} }
+                            td { {sign_in_lockout.failures.to_string()} }
+                            td { {format_date_time(Some(sign_in_lockout.locked_at.clone()))} }
+                            td { {format_date_time(Some(sign_in_lockout.locked_until.clone()))} }
+                            td {
+                                if sign_in_lockout.unlocked_at.is_some() {
+                                    {
+                                        format!(
+                                            "{} ({})",
+                                            format_date_time(sign_in_lockout.unlocked_at.clone()),
+                                            sign_in_lockout.unlocked_by.clone().unwrap_or_default(),
+                                        )
+                                    }
+                                }
+                            }
+                            td {
+                                if sign_in_lockout.active {
+                                    button {
+                                        class: "btn btn-xs btn-warning",
+                                        onclick: move |_| {
+                                            lockout_id_signal.set(sign_in_lockout.id.clone());
+                                            alert_dialog!("message-confirm-sign-in-unlock", unlock);
+                                        },
+                                        { t!("action-unlock") }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
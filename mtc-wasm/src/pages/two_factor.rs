@@ -0,0 +1,209 @@
+use super::*;
+
+/// Two-factor authentication page.
+///
+/// A signed-in user enables the TOTP second factor here, replaces the recovery
+/// codes or disables the second factor unless the roles require it. A user whose
+/// roles require two-factor is sent here by the sign-in page, confirming the
+/// enrollment completes the sign-in.
+///
+/// The recovery codes are shown once, right after they are generated.
+#[component]
+pub fn TwoFactor() -> Element {
+    breadcrumbs!("menu-two-factor");
+
+    let authenticated = state!(auth).is_authenticated();
+    let sync = use_coroutine_handle::<SyncAction>();
+
+    let mut setup_signal = use_signal(|| None::<TwoFactorSetup>);
+    let mut recovery_codes_signal = use_signal(Vec::<Cow<'static, str>>::new);
+    let mut disable_signal = use_signal(|| false);
+    let mut password_change_signal = use_signal(|| false);
+
+    let mut status_resource = use_resource(move || async move {
+        if !authenticated {
+            return TwoFactorStatus::default();
+        }
+        value_request!(url!(API_AUTH, "two-factor"))
+            .self_obj::<TwoFactorStatus>()
+            .unwrap_or_default()
+    });
+
+    let start_setup = move |_: MouseEvent| {
+        spawn(async move {
+            let setup = state!(client)
+                .post(url!(API_AUTH, "two-factor", "setups"))
+                .send()
+                .await
+                .get_value()
+                .await
+                .self_obj::<TwoFactorSetup>();
+            recovery_codes_signal.set(vec![]);
+            setup_signal.set(setup);
+        });
+    };
+
+    let confirm_setup = move |event: Event<FormData>| {
+        let payload = json!(TwoFactorCodeRequest {
+            code: event.get_str("code").unwrap_or_default(),
+        });
+        spawn(async move {
+            let Some(response) = value_request!(url!(API_AUTH, "two-factor", "confirmations"), payload)
+                .self_obj::<TwoFactorRecoveryCodes>() else { return; };
+
+            setup_signal.set(None);
+            password_change_signal.set(response.password_change_required);
+            recovery_codes_signal.set(response.recovery_codes);
+            if !authenticated {
+                sync.send(SyncAction::RefreshState());
+            }
+            status_resource.restart();
+        });
+    };
+
+    let manage = move |event: Event<FormData>| {
+        let payload = json!(TwoFactorCodeRequest {
+            code: event.get_str("code").unwrap_or_default(),
+        });
+        spawn(async move {
+            if disable_signal() {
+                if delete_request!(url!(API_AUTH, "two-factor"), payload) {
+                    recovery_codes_signal.set(vec![]);
+                    success_dialog!("message-two-factor-disabled");
+                    status_resource.restart();
+                }
+                return;
+            }
+
+            let Some(response) = value_request!(url!(API_AUTH, "two-factor", "recovery-codes"), payload)
+                .self_obj::<TwoFactorRecoveryCodes>() else { return; };
+            recovery_codes_signal.set(response.recovery_codes);
+            status_resource.restart();
+        });
+    };
+
+    let status = status_resource().unwrap_or_default();
+    let setup = setup_signal().unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "div-centered",
+            div {
+                class: "hero",
+                div {
+                    class: "hero-content flex-col lg:flex-row-reverse",
+                    div {
+                        class: "text-center lg:text-left w-full sm:max-w-sm",
+                        h1 {
+                            class: "text-3xl font-bold",
+                            { t!("message-two-factor-header") }
+                        }
+                        p {
+                            class: "py-6",
+                            if !authenticated {
+                                { t!("message-two-factor-enroll-announcement") }
+                            } else if status.enabled {
+                                { format!("{} {}", t!("message-two-factor-enabled-announcement"), status.recovery_codes_left) }
+                            } else {
+                                { t!("message-two-factor-announcement") }
+                            }
+                        }
+                    }
+                    div {
+                        class: "card bg-base-100 card-border w-full max-w-sm shrink-0 shadow-xl",
+                        if !recovery_codes_signal().is_empty() {
+                            div {
+                                class: "card-body",
+                                p { { t!("message-two-factor-recovery-codes") } }
+                                ul {
+                                    class: "grid grid-cols-2 gap-2 font-mono text-center",
+                                    for recovery_code in recovery_codes_signal() {
+                                        li { { recovery_code } }
+                                    }
+                                }
+                                button {
+                                    class: "btn w-full btn-primary mt-6",
+                                    onclick: move |_| {
+                                        recovery_codes_signal.set(vec![]);
+                                        if password_change_signal() {
+                                            navigator().replace(route!(API_AUTH, "change-password"));
+                                        } else {
+                                            navigator().replace(route!());
+                                        }
+                                    },
+                                    { t!("action-close") }
+                                }
+                            }
+                        } else if setup_signal().is_some() {
+                            form {
+                                class: "card-body",
+                                id: "two-factor-setup-form",
+                                autocomplete: "off",
+                                onsubmit: confirm_setup,
+
+                                div {
+                                    class: "w-full",
+                                    dangerous_inner_html: "{setup.qr_code}",
+                                }
+                                p {
+                                    class: "font-mono text-center break-all",
+                                    { setup.secret }
+                                }
+                                FormTextField {
+                                    name: "code",
+                                    title: "field-two-factor-code",
+                                    required: true
+                                }
+                                button {
+                                    class: "btn w-full btn-primary mt-6",
+                                    r#type: "submit",
+                                    Icon { icon: Icons::Lock, class: "size-6" }
+                                    { t!("action-confirm") }
+                                }
+                            }
+                        } else if status.enabled {
+                            form {
+                                class: "card-body",
+                                id: "two-factor-form",
+                                autocomplete: "off",
+                                onsubmit: manage,
+
+                                FormTextField {
+                                    name: "code",
+                                    title: "field-two-factor-code",
+                                    required: true
+                                }
+                                button {
+                                    class: "btn w-full btn-primary mt-6",
+                                    r#type: "submit",
+                                    onclick: move |_| disable_signal.set(false),
+                                    Icon { icon: Icons::Lock, class: "size-6" }
+                                    { t!("action-two-factor-recovery-codes") }
+                                }
+                                if !status.required {
+                                    button {
+                                        class: "btn w-full btn-error",
+                                        r#type: "submit",
+                                        onclick: move |_| disable_signal.set(true),
+                                        Icon { icon: Icons::Close, class: "size-6" }
+                                        { t!("action-two-factor-disable") }
+                                    }
+                                }
+                            }
+                        } else {
+                            div {
+                                class: "card-body",
+                                button {
+                                    class: "btn w-full btn-primary",
+                                    onclick: start_setup,
+                                    Icon { icon: Icons::Lock, class: "size-6" }
+                                    { t!("action-two-factor-enable") }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+/// Lists the devices linked to the signed-in user account.
+#[component]
+pub fn UserDevices() -> Element {
+    if !state!(auth).is_authenticated() {
+        return rsx! { NotFound {} }
+    }
+
+    breadcrumbs!("menu-user-devices");
+
+    rsx! {
+        section { class: "flex w-full grow flex-col gap-6 xl:pr-16",
+            UserDeviceList { path: format!("{API_AUTH}/devices") }
+        }
+    }
+}
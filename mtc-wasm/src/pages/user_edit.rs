@@ -21,10 +21,12 @@ pub fn UserEdit(
     check_role!(ROLE_ADMINISTRATOR);
     check_permission!(PERMISSION_USERS_READ);
 
-    let future = value_future!(url!(API_USER, &id()));
+    let mut future = value_future!(url!(API_USER, &id()));
     let response = future.suspend()?;
     check_response!(response, future);
 
+    let mut one_time_password_signal = use_signal(|| None::<Cow<'static, str>>);
+
     let personnel = state!(personnel);
     let login = response().key_string("login").unwrap_or_default();
 
@@ -35,6 +37,7 @@ pub fn UserEdit(
             "password": event.get_str("password"),
             "group": event.get_str("group"),
             "blocked": event.get_bool("blocked"),
+            "password_change_required": event.get_bool("password_change_required"),
             "roles": event.get_str_array("roles").unwrap_or(vec![])
         });
 
@@ -53,6 +56,31 @@ pub fn UserEdit(
         });
     };
 
+    let reset_two_factor = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            if delete_request!(url!(API_USER, &id(), "two-factor")) {
+                success_dialog!("message-two-factor-reset");
+                future.restart();
+            }
+        });
+    });
+
+    let reset_password = Callback::new(move |event: MouseEvent| {
+        spawn(async move {
+            let one_time_password = state!(client)
+                .post(url!(API_USER, &id(), "password-resets"))
+                .send()
+                .await
+                .get_value()
+                .await
+                .self_obj::<OneTimePassword>();
+            if one_time_password.is_some() {
+                future.restart();
+            }
+            one_time_password_signal.set(one_time_password.map(|response| response.password));
+        });
+    });
+
     rsx! {
         section {
             class: "flex grow select-none flex-col gap-6 px-3 pr-20 sm:pr-16",
@@ -84,11 +112,17 @@ pub fn UserEdit(
                     name: "password",
                     title: "field-password",
                 }
+                FormToggleField {
+                    name: "password_change_required",
+                    title: "field-password-change-required",
+                    checked: response()
+                        .key_bool("password_change_required").unwrap_or_default()
+                }
                 FormSelectField {
                     name: "group",
                     title: "field-group",
                     selected: response().key_string("group").unwrap_or_default(),
-                    items: state!(groups)
+                    items: GroupNode::leaves(&state!(groups))
                 }
                 FormEntriesField {
                     name: "roles",
@@ -98,6 +132,57 @@ pub fn UserEdit(
                     entries: state!(roles)
                 }
             }
+            if let Some(one_time_password) = one_time_password_signal() {
+                div {
+                    class: "alert alert-warning flex w-full flex-col justify-center",
+                    p { { t!("message-one-time-password") } }
+                    p { class: "font-mono text-xl", { one_time_password } }
+                }
+            }
+            if !response().key_string("id").unwrap_or_default().is_empty() {
+                div {
+                    class: "flex w-full justify-center",
+                    button {
+                        class: "btn btn-outline btn-warning",
+                        onclick: move |_| {
+                            alert_dialog!("message-confirm-password-reset", reset_password);
+                        },
+                        Icon { icon: Icons::Lock, class: "size-6" }
+                        { t!("action-password-reset") }
+                    }
+                }
+            }
+            if response().key_bool("two_factor_enabled").unwrap_or_default() {
+                div {
+                    class: "flex w-full justify-center",
+                    button {
+                        class: "btn btn-outline btn-error",
+                        onclick: move |_| {
+                            alert_dialog!("message-confirm-two-factor-reset", reset_two_factor);
+                        },
+                        Icon { icon: Icons::DatabaseLock, class: "size-6" }
+                        { t!("action-two-factor-reset") }
+                    }
+                }
+            }
+            if !id().eq(ID_CREATE) {
+                h3 {
+                    class: "w-full text-center text-xl font-semibold",
+                    { t!("menu-user-devices") }
+                }
+                UserDeviceList {
+                    path: format!("{API_USER}/{}/devices", id()),
+                    writable: state!(auth).has_permission(PERMISSION_USERS_WRITE),
+                }
+                h3 {
+                    class: "w-full text-center text-xl font-semibold",
+                    { t!("menu-user-sessions") }
+                }
+                UserSessionList {
+                    path: format!("{API_USER}/{}/sessions", id()),
+                    writable: state!(auth).has_permission(PERMISSION_USERS_WRITE),
+                }
+            }
         }
         EntryInfoBox {
             created_by: response().key_string("created_by"),
@@ -2,7 +2,8 @@ use super::*;
 
 /// Page for showing and managing all users.
 ///
-/// Shows a table with the login, rank, name and group of each user.
+/// Shows a table with the login, rank, name and group of each user, the users can be
+/// filtered by a group along with its subgroups.
 ///
 /// The component also provides a button for deleting each user.
 ///
@@ -19,9 +20,14 @@ pub fn Users() -> Element {
 
     let mut search = use_signal(String::new);
     let mut archive = use_signal(|| false);
+    let mut group = use_signal(String::new);
     let personnel = state!(personnel);
 
-    let future = value_future!(url!(API_USERS, &search(), &archive().to_string()));
+    let future = value_future!(format!(
+        "{}?group={}",
+        url!(API_USERS, &search(), &archive().to_string()),
+        group()
+    ));
     let response = future.suspend()?;
     check_response!(response, future);
 
@@ -34,6 +40,7 @@ pub fn Users() -> Element {
                 onsubmit: move |event| {
                     search.set(event.get_str("login").unwrap_or_default().to_string());
                     archive.set(event.get_bool("archive"));
+                    group.set(event.get_str("group").unwrap_or_default().to_string());
                 },
                 label {
                     class: "input input-sm flex grow",
@@ -47,6 +54,27 @@ pub fn Users() -> Element {
                     }
                     div {
                         class: "relative -right-3 join",
+                        select {
+                            class: "select select-sm select-ghost join-item w-48",
+                            name: "group",
+                            option {
+                                initial_selected: true,
+                                value: "",
+                                { t!("field-group") }
+                            }
+                            for item in state!(groups) {
+                                option {
+                                    value: &*item.id,
+                                    {
+                                        format!(
+                                            "{}{}",
+                                            "\u{a0}".repeat(2 * GroupNode::of(&item).depth),
+                                            item.title
+                                        )
+                                    }
+                                }
+                            }
+                        }
                         label {
                             class: "swap join-item",
                             input {
@@ -1,55 +1,170 @@
 use crate::error::Error;
-use crate::prelude::{Deserialize, Serialize};
+use crate::prelude::{
+    Deserialize, MagicCryptTrait, Serialize, jsFfiUnwrapOfflineKey, jsFfiWrapOfflineKey,
+    new_magic_crypt,
+};
+use chrono::{DateTime, Duration, Utc};
+use gloo_storage::{LocalStorage, Storage};
 use indexed_db::{Database, Factory};
-use mtc_common::prelude::SyncGatePass;
+use mtc_common::prelude::{
+    GATE_PASS_CLOCK_SKEW_THRESHOLD_SECONDS, GATE_PASS_SYNC_MAX_AGE_HOURS, GatePassOfflineKey,
+    SyncGatePass,
+};
 use std::borrow::Cow;
 use tracing::error;
 use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
 
 static MTC_242_INDEXED_DB: &str = "mtc-242";
 static GATE_PASS_INDEXED_DB_STORE: &str = "gate_passes";
 static SYNC_ENTRY_INDEXED_DB_STORE: &str = "sync_entries";
+static GATE_PASS_OFFLINE_KEY_STORAGE_KEY: &str = "mtc_gate_pass_offline_key";
 
 pub async fn indexed_db() -> Result<Database<Error>, Error> {
     let indexed_db = Factory::<Error>::get()?
-        .open(MTC_242_INDEXED_DB, 1, |event| async move {
-            event
-                .database()
-                .build_object_store(GATE_PASS_INDEXED_DB_STORE)
-                .key_path("id")
-                .create()?;
-            event
-                .database()
-                .build_object_store(SYNC_ENTRY_INDEXED_DB_STORE)
-                .key_path("id")
-                .create()?;
+        .open(MTC_242_INDEXED_DB, 2, |event| async move {
+            // gate passes stored before version 2 are not encrypted, drop them with
+            // the sync cursor, so the next sync stores them encrypted
+            let database = event.database();
+            for store_name in [GATE_PASS_INDEXED_DB_STORE, SYNC_ENTRY_INDEXED_DB_STORE] {
+                if database
+                    .object_store_names()
+                    .iter()
+                    .any(|name| name == store_name)
+                {
+                    database.delete_object_store(store_name)?;
+                }
+                database
+                    .build_object_store(store_name)
+                    .key_path("id")
+                    .create()?;
+            }
             Ok(())
         })
         .await?;
     Ok(indexed_db)
 }
 
+/// Gate pass as it is stored in IndexedDB: only the id is kept in plain text.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct EncryptedSyncGatePass {
+    id: Cow<'static, str>,
+    payload: Cow<'static, str>,
+}
+
+/// Returns the offline key of the device persisted in the local storage, it is wrapped by
+/// a non-extractable key of the device, so it is available offline after a restart.
+pub async fn stored_gate_pass_offline_key() -> Option<GatePassOfflineKey> {
+    let wrapped_offline_key =
+        LocalStorage::get::<String>(GATE_PASS_OFFLINE_KEY_STORAGE_KEY).ok()?;
+    let offline_key = JsFuture::from(jsFfiUnwrapOfflineKey(&wrapped_offline_key))
+        .await
+        .map_err(|error| error!("failed to unwrap gate pass offline key: {error:?}"))
+        .ok()?
+        .as_string()?;
+    serde_json::from_str::<GatePassOfflineKey>(&offline_key).ok()
+}
+
+pub async fn store_gate_pass_offline_key(offline_key: &GatePassOfflineKey) -> Result<(), Error> {
+    let offline_key = serde_json::to_string(offline_key)
+        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?;
+    let wrapped_offline_key = JsFuture::from(jsFfiWrapOfflineKey(&offline_key))
+        .await
+        .ok()
+        .and_then(|value| value.as_string())
+        .ok_or(Error::Generic(Cow::Borrowed("error-gate-pass-offline-key")))?;
+    LocalStorage::set(GATE_PASS_OFFLINE_KEY_STORAGE_KEY, wrapped_offline_key)
+        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))
+}
+
+pub fn remove_stored_gate_pass_offline_key() {
+    LocalStorage::delete(GATE_PASS_OFFLINE_KEY_STORAGE_KEY);
+}
+
+/// Wipes the offline gate passes together with the sync cursor, used when the
+/// server reports the device as revoked.
+pub async fn clear_gate_passes(indexed_db: &Database<Error>) -> Result<(), Error> {
+    indexed_db
+        .transaction(&[GATE_PASS_INDEXED_DB_STORE, SYNC_ENTRY_INDEXED_DB_STORE])
+        .rw()
+        .run(|transaction| async move {
+            transaction
+                .object_store(GATE_PASS_INDEXED_DB_STORE)?
+                .clear()
+                .await?;
+            transaction
+                .object_store(SYNC_ENTRY_INDEXED_DB_STORE)?
+                .clear()
+                .await?;
+            Ok(())
+        })
+        .await?;
+    remove_stored_gate_pass_offline_key();
+    Ok(())
+}
+
+fn encrypt_gate_pass(
+    offline_key: &GatePassOfflineKey,
+    gate_pass: &SyncGatePass,
+) -> Result<EncryptedSyncGatePass, Error> {
+    let json = serde_json::to_string(gate_pass)
+        .map_err(|error| Error::Generic(Cow::Owned(error.to_string())))?;
+    let mcrypt = new_magic_crypt!(offline_key.key.as_ref(), 256);
+    Ok(EncryptedSyncGatePass {
+        id: gate_pass.id.clone(),
+        payload: Cow::Owned(mcrypt.encrypt_str_to_base64(json)),
+    })
+}
+
+fn decrypt_gate_pass(
+    offline_key: &GatePassOfflineKey,
+    encrypted_gate_pass: EncryptedSyncGatePass,
+) -> Result<SyncGatePass, Error> {
+    let mcrypt = new_magic_crypt!(offline_key.key.as_ref(), 256);
+    mcrypt
+        .decrypt_base64_to_string(encrypted_gate_pass.payload.as_ref())
+        .ok()
+        .and_then(|json| serde_json::from_str::<SyncGatePass>(&json).ok())
+        .ok_or_else(|| {
+            let message = format!(
+                "failed to decrypt indexed db gate pass: gate_pass_id={}",
+                encrypted_gate_pass.id
+            );
+            error!("{}", message);
+            Error::Deserialization(Cow::Owned(message))
+        })
+}
+
 pub async fn process_gate_passes(
     indexed_db: &Database<Error>,
+    offline_key: &GatePassOfflineKey,
     mut gate_passes: Vec<SyncGatePass>,
 ) -> Result<(), Error> {
     let batch_size: usize = 100;
     while !gate_passes.is_empty() {
         let gate_pass_batch = gate_passes
             .drain(0..batch_size.min(gate_passes.len()))
-            .collect::<Vec<SyncGatePass>>();
+            .map(|gate_pass| {
+                if gate_pass.deleted {
+                    Ok((gate_pass.id, None))
+                } else {
+                    encrypt_gate_pass(offline_key, &gate_pass)
+                        .map(|encrypted_gate_pass| (gate_pass.id, Some(encrypted_gate_pass)))
+                }
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
 
         indexed_db
             .transaction(&[GATE_PASS_INDEXED_DB_STORE])
             .rw()
             .run(|transaction| async move {
                 let store = transaction.object_store(GATE_PASS_INDEXED_DB_STORE)?;
-                for gate_pass in gate_pass_batch {
-                    if gate_pass.deleted == false {
-                        let js_value = serde_wasm_bindgen::to_value(&gate_pass).unwrap();
+                for (gate_pass_id, encrypted_gate_pass_opt) in gate_pass_batch {
+                    if let Some(encrypted_gate_pass) = encrypted_gate_pass_opt {
+                        let js_value = serde_wasm_bindgen::to_value(&encrypted_gate_pass).unwrap();
                         store.put(&js_value).await?;
                     } else {
-                        let js_value = serde_wasm_bindgen::to_value(&gate_pass.id).unwrap();
+                        let js_value = serde_wasm_bindgen::to_value(&gate_pass_id).unwrap();
                         store.delete(&js_value).await?;
                     }
                 }
@@ -62,6 +177,7 @@ pub async fn process_gate_passes(
 
 pub async fn get_gate_pass(
     indexed_db: &Database<Error>,
+    offline_key: &GatePassOfflineKey,
     gate_pass_id: Cow<'static, str>,
 ) -> Result<SyncGatePass, Error> {
     let indexed_db_gate_pass_id = gate_pass_id.clone();
@@ -81,12 +197,90 @@ pub async fn get_gate_pass(
         .await
         .map_err(Error::from)
         .and_then(|gate_pass_js_value| parse(gate_pass_js_value, gate_pass_id))
+        .and_then(|encrypted_gate_pass| decrypt_gate_pass(offline_key, encrypted_gate_pass))
+}
+
+/// Summary of the offline gate passes shown on the scanner maintenance panel.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct GatePassStorageStats {
+    pub number_of_gate_passes: usize,
+    pub number_of_expired: usize,
+    pub number_of_blocked: usize,
+    pub number_of_unreadable: usize,
+}
+
+/// Decrypts every stored gate pass to count the expired and blocked ones at the
+/// given time, entries that fail to decrypt are counted as unreadable.
+pub async fn gate_pass_storage_stats(
+    indexed_db: &Database<Error>,
+    offline_key: &GatePassOfflineKey,
+    now: DateTime<Utc>,
+) -> Result<GatePassStorageStats, Error> {
+    let gate_pass_js_values = indexed_db
+        .transaction(&[GATE_PASS_INDEXED_DB_STORE])
+        .run(|transaction| async move {
+            transaction
+                .object_store(GATE_PASS_INDEXED_DB_STORE)?
+                .get_all(None)
+                .await
+        })
+        .await?;
+
+    let mut stats = GatePassStorageStats {
+        number_of_gate_passes: gate_pass_js_values.len(),
+        ..Default::default()
+    };
+    for gate_pass_js_value in gate_pass_js_values {
+        let gate_pass_result = parse::<EncryptedSyncGatePass>(
+            gate_pass_js_value,
+            Cow::Borrowed(GATE_PASS_INDEXED_DB_STORE),
+        )
+        .and_then(|encrypted_gate_pass| decrypt_gate_pass(offline_key, encrypted_gate_pass));
+        match gate_pass_result {
+            Ok(gate_pass) => {
+                if gate_pass.expired_by(now) {
+                    stats.number_of_expired += 1;
+                } else if gate_pass.blocked_by(now) {
+                    stats.number_of_blocked += 1;
+                }
+            }
+            Err(_) => stats.number_of_unreadable += 1,
+        }
+    }
+    Ok(stats)
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SyncEntry {
     pub id: Cow<'static, str>,
     pub last_synced_at: Cow<'static, str>,
+    /// Server time of the last sync or the creation time of the imported snapshot.
+    #[serde(default)]
+    pub synced_at: Option<Cow<'static, str>>,
+    /// Seconds to add to the device clock to get the server clock, measured on the
+    /// last sync.
+    #[serde(default)]
+    pub clock_offset_seconds: i64,
+}
+
+impl SyncEntry {
+    /// Device time corrected by the clock offset measured on the last sync.
+    pub fn server_now(&self) -> DateTime<Utc> {
+        Utc::now() + Duration::seconds(self.clock_offset_seconds)
+    }
+
+    pub fn clock_skewed(&self) -> bool {
+        self.clock_offset_seconds.abs() > GATE_PASS_CLOCK_SKEW_THRESHOLD_SECONDS
+    }
+
+    pub fn outdated(&self) -> bool {
+        self.synced_at
+            .as_ref()
+            .and_then(|synced_at| synced_at.parse::<DateTime<Utc>>().ok())
+            .is_none_or(|synced_at| {
+                self.server_now() - synced_at > Duration::hours(GATE_PASS_SYNC_MAX_AGE_HOURS)
+            })
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -3,6 +3,7 @@ use super::*;
 pub trait ResponseService {
     fn get_value(self) -> impl std::future::Future<Output=Value> + Sized;
     fn is_ok(self) -> impl std::future::Future<Output=bool> + Sized;
+    fn get_bytes(self) -> impl std::future::Future<Output=Option<Vec<u8>>> + Sized;
 }
 
 impl ResponseService for Result<Response, reqwest::Error> {
@@ -24,10 +25,8 @@ impl ResponseService for Result<Response, reqwest::Error> {
 
         if !response.status().is_success() {
             let value = response.json::<Value>().await.unwrap_or_default();
-            let message: Cow<'static, str> = value
-                .key_string("message")
-                .unwrap_or("error-generic".to_string()).into();
-            error_dialog!(&message);
+            let message = error_message(&value);
+            error_dialog!(message.as_str());
             return Value::Null
         }
 
@@ -57,13 +56,77 @@ impl ResponseService for Result<Response, reqwest::Error> {
 
         if !response.status().is_success() {
             let value = response.json::<Value>().await.unwrap_or_default();
-            let message: Cow<'static, str> = value
-                .key_string("message")
-                .unwrap_or("error-generic".to_string()).into();
-            error_dialog!(&message);
+            let message = error_message(&value);
+            error_dialog!(message.as_str());
             return false
         }
 
         true
     }
+
+    /// If the response is a success (200-299), returns the binary body of the
+    /// response, e.g. a generated report. Otherwise, opens an error dialog the
+    /// same way as [`ResponseService::get_value`] and returns [`None`].
+    async fn get_bytes(self) -> Option<Vec<u8>> {
+        let Ok(response) = self else {
+            error_dialog!("error-connection");
+            return None
+        };
+
+        if !response.status().is_success() {
+            let value = response.json::<Value>().await.unwrap_or_default();
+            let message = error_message(&value);
+            error_dialog!(message.as_str());
+            return None
+        }
+
+        let Ok(bytes) = response.bytes().await else {
+            error_dialog!("error-fetch");
+            return None
+        };
+
+        Some(bytes.to_vec())
+    }
+}
+
+/// Builds the translated error message from the JSON value of a failed response.
+///
+/// Validation errors found under the `field_errors` key are translated and
+/// appended to the message, so that field level violations are visible.
+fn error_message(value: &Value) -> String {
+    let message_key = value
+        .key_string("message")
+        .unwrap_or("error-generic".to_string());
+    let message = t!(message_key.as_str());
+    let mut codes = vec![];
+    if let Some(errors) = value.get("field_errors") {
+        collect_error_codes(errors, &mut codes);
+    }
+    if codes.is_empty() {
+        return message
+    }
+    let details = codes
+        .iter()
+        .map(|code| t!(code.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{message}: {details}")
+}
+
+fn collect_error_codes(value: &Value, codes: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            // the message of a validation error holds the i18n key when it is set
+            let code = object.get("message").filter(|message| message.is_string());
+            if let Some(Value::String(code)) = code.or(object.get("code")) {
+                if !codes.contains(code) {
+                    codes.push(code.clone());
+                }
+                return
+            }
+            object.values().for_each(|value| collect_error_codes(value, codes));
+        }
+        Value::Array(array) => array.iter().for_each(|value| collect_error_codes(value, codes)),
+        _ => {}
+    }
 }
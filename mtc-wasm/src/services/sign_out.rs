@@ -3,8 +3,9 @@ use super::*;
 /// Initiates the sign-out process when triggered by [`Event`].
 ///
 /// This function sends a delete request to the authentication API
-/// to sign the user out. Upon successful sign-out, it refreshes the
-/// application state using the `SyncAction::RefreshState` action.
+/// to sign the user out. Upon successful sign-out, it removes the offline
+/// gate pass key of the device and refreshes the application state using
+/// the `SyncAction::RefreshState` action.
 ///
 /// # Arguments
 ///
@@ -14,6 +15,7 @@ pub fn sign_out_task(event: Event<MouseData>) {
 
     spawn(async move {
         if delete_request!(url!(API_AUTH)) {
+            remove_stored_gate_pass_offline_key();
             sync.send(SyncAction::RefreshState())
         }
     });
@@ -43,6 +43,10 @@ pub async fn sync_service(mut rx: UnboundedReceiver<SyncAction>) {
                 if let Some(groups) = value.key_obj::<Vec<Entry>>("groups") {
                     state!(set_groups, groups)
                 }
+                if let Some(gate_pass_dictionaries) =
+                    value.key_obj::<GatePassDictionaries>("gate_pass_dictionaries") {
+                    state!(set_gate_pass_dictionaries, gate_pass_dictionaries)
+                }
                 if let Some(search) =
                     value.key_obj::<Vec<SearchIdxDto>>("search_idx") {
                     let mut new_search_idx = simsearch::SimSearch::new();
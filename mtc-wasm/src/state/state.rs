@@ -1,6 +1,8 @@
 use super::*;
 use indexed_db::Database;
 
+static MTC_GATE_PASS_DICTIONARIES_KEY: &str = "mtc_gate_pass_dictionaries";
+
 /// Initializes the application state with the provided internationalization (i18n) data.
 ///
 /// This function parses the given i18n string data, expected to be in a key=value CSV format,
@@ -49,6 +51,9 @@ pub fn use_init_state(i18n: &str) -> Signal<Cow<'static, str>> {
         search: Default::default(),
         platform: Signal::new(Cow::Borrowed("web")),
         indexed_db: Signal::new(None),
+        gate_pass_dictionaries: Signal::new(
+            LocalStorage::get(MTC_GATE_PASS_DICTIONARIES_KEY).unwrap_or_default(),
+        ),
     }).session
 }
 
@@ -78,6 +83,7 @@ pub struct UseState {
     search: SearchEngine,
     platform: Signal<Cow<'static, str>>,
     indexed_db: Signal<Option<Database<Error>>>,
+    gate_pass_dictionaries: Signal<GatePassDictionaries>,
 }
 
 impl UseState {
@@ -193,4 +199,17 @@ impl UseState {
     pub fn set_indexed_db(&self, indexed_db: Option<Database<Error>>) {
         *self.indexed_db.write_unchecked() = indexed_db
     }
+
+    pub fn gate_pass_dictionaries(&self) -> Signal<GatePassDictionaries> {
+        self.gate_pass_dictionaries
+    }
+    /// Caches the dictionaries in the local storage for the offline scanner.
+    pub fn set_gate_pass_dictionaries(&self, gate_pass_dictionaries: GatePassDictionaries) {
+        if let Err(error) =
+            LocalStorage::set(MTC_GATE_PASS_DICTIONARIES_KEY, &gate_pass_dictionaries)
+        {
+            error!("failed to cache gate pass dictionaries: error={:?}", error);
+        }
+        *self.gate_pass_dictionaries.write_unchecked() = gate_pass_dictionaries
+    }
 }
\ No newline at end of file